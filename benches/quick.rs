@@ -0,0 +1,30 @@
+//! quick_current_price와 CurrentPriceResponse 전체 역직렬화 비교
+//! cargo bench --features quick --bench quick
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use korea_investment_api_bohyuk::quick::quick_current_price;
+use korea_investment_api_bohyuk::types::response::stock::quote::CurrentPriceResponse;
+
+/// 주식현재가 시세[v1_국내주식-008] 응답
+const CURRENT_PRICE: &str = r#"{"output":{"iscd_stat_cls_code":"55","marg_rate":"20.00","rprs_mrkt_kor_name":"KOSPI200","bstp_kor_isnm":"전기.전자","temp_stop_yn":"N","oprc_rang_cont_yn":"N","clpr_rang_cont_yn":"N","crdt_able_yn":"Y","grmn_rate_cls_code":"40","elw_pblc_yn":"Y","stck_prpr":"71500","prdy_vrss":"500","prdy_vrss_sign":"2","prdy_ctrt":"0.70","acml_tr_pbmn":"1052049975600","acml_vol":"14719459","prdy_vrss_vol_rate":"92.20","stck_oprc":"71200","stck_hgpr":"71800","stck_lwpr":"71000","stck_mxpr":"92300","stck_llam":"49700","stck_sdpr":"71000","wghn_avrg_stck_prc":"71473.64","hts_frgn_ehrt":"55.45","frgn_ntby_qty":"-1040774","pgtr_ntby_qty":"386049","pvt_scnd_dmrs_prc":"72466","pvt_frst_dmrs_prc":"71733","pvt_pont_val":"71266","pvt_frst_dmsp_prc":"70533","pvt_scnd_dmsp_prc":"70066","dmrs_val":"71500","dmsp_val":"70800","cpfn":"7780","rstc_wdth_prc":"21300","stck_fcam":"100","stck_sspr":"54660","aspr_unit":"100","hts_deal_qty_unit_val":"1","lstn_stcn":"5969782550","hts_avls":"4268394","per":"14.51","pbr":"1.37","stac_month":"12","vol_tnrt":"0.25","eps":"4926.00","bps":"52002.00","d250_hgpr":"79800","d250_hgpr_date":"20231120","d250_hgpr_vrss_prpr_rate":"-10.40","d250_lwpr":"58600","d250_lwpr_date":"20240103","d250_lwpr_vrss_prpr_rate":"22.01","stck_dryy_hgpr":"79800","dryy_hgpr_vrss_prpr_rate":"-10.40","dryy_hgpr_date":"20231120","stck_dryy_lwpr":"58600","dryy_lwpr_vrss_prpr_rate":"22.01","dryy_lwpr_date":"20240103","w52_hgpr":"79800","w52_hgpr_vrss_prpr_ctrt":"-10.40","w52_hgpr_date":"20231120","w52_lwpr":"58600","w52_lwpr_vrss_prpr_ctrt":"22.01","w52_lwpr_date":"20240103","whol_loan_rmnd_rate":"0.11","ssts_yn":"Y","stck_shrn_iscd":"005930","fcam_cnnm":"100","cpfn_cnnm":"7,780 억","frgn_hldn_qty":"3310427578","vi_cls_code":"N","ovtm_vi_cls_code":"N","last_ssts_cntg_qty":"84735","invt_caful_yn":"N","mrkt_warn_cls_code":"00","short_over_yn":"N","sltr_yn":"N","mang_issu_cls_code":"N"},"rt_cd":"0","msg_cd":"MCA00000","msg1":"정상처리 되었습니다."}"#;
+
+fn current_price(c: &mut Criterion) {
+    let json = CURRENT_PRICE.as_bytes();
+    let mut group = c.benchmark_group("current_price");
+    group.bench_function("quick_current_price", |b| {
+        b.iter(|| quick_current_price(black_box(json)).unwrap())
+    });
+    group.bench_function("CurrentPriceResponse", |b| {
+        b.iter(|| {
+            let response: CurrentPriceResponse = serde_json::from_slice(black_box(json)).unwrap();
+            response
+                .output()
+                .as_ref()
+                .and_then(|output| output.stck_prpr())
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, current_price);
+criterion_main!(benches);
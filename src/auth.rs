@@ -1,17 +1,63 @@
-use crate::types::{request, response, Environment};
-use crate::Error;
-use reqwest::header::{HeaderMap, HeaderValue};
+use crate::client::{api_error, body_snippet, record_result};
+use crate::transport::{HttpResponse, ReqwestTransport, Transport};
+use crate::types::{request, response, Endpoints, Environment};
+use crate::{CircuitBreaker, Error};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 만료 직전의 토큰으로 요청하지 않도록 두는 여유 시간
+const TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// 접근토큰과 만료 시각
+/// expires_at이 None이면 만료 시각을 알 수 없는 토큰(set_token으로 설정)으로, 유효하다고 간주
+#[derive(Clone, Debug)]
+struct AccessToken {
+    token: String,
+    expires_at: Option<SystemTime>,
+}
+
+/// 토큰 캐시 파일의 항목
+/// 파일은 투자환경(Real/Virtual)별 항목을 가지므로 두 환경이 같은 항목을 공유하지 않음
+#[derive(Debug, Serialize, Deserialize)]
+struct TokenCacheEntry {
+    appkey: String,
+    token: String,
+    expires_at: Option<u64>, // UNIX timestamp(초)
+    #[serde(default)]
+    seq_no: Option<u64>, // 다음에 발급할 법인 주문 일련번호
+}
+
+impl AccessToken {
+    fn is_valid(&self) -> bool {
+        match self.expires_at {
+            Some(expires_at) => SystemTime::now() + TOKEN_EXPIRY_MARGIN < expires_at,
+            None => true,
+        }
+    }
+}
 
 /// Auth
 /// [OAuth 인증 관련](https://apiportal.koreainvestment.com/apiservice/oauth2#L_214b9e22-8f2e-4fba-9688-587279f1061a)
+/// token, approval_key와 법인 주문 일련번호(seq_no)는 clone된 Auth끼리 공유되므로 한 곳에서 갱신하면 모든 서비스에 반영됨
 #[derive(Clone)]
 pub struct Auth {
     client: reqwest::Client,
-    endpoint_url: String,
+    transport: Arc<dyn Transport>,
+    circuit_breaker: Option<CircuitBreaker>,
+    environment: Environment,
+    endpoints: Endpoints,
     appkey: String,
     appsecret: String,
-    token: Option<String>,
-    approval_key: Option<String>,
+    token: Arc<RwLock<Option<AccessToken>>>,
+    token_cache_path: Option<PathBuf>,
+    approval_key: Arc<RwLock<Option<String>>>, // 실시간(웹소켓) 접속키
+    seq_no: Arc<AtomicU64>,                    // 다음에 발급할 법인 주문 일련번호
+    refresh_lock: Arc<tokio::sync::Mutex<()>>, // 만료된 token을 한 번만 재발급하도록 ensure_valid_token을 직렬화
 }
 
 impl Auth {
@@ -24,29 +70,239 @@ impl Auth {
         appkey: &str,
         appsecret: &str,
     ) -> Self {
-        let endpoint_url = match environment {
-            Environment::Real => "https://openapi.koreainvestment.com:9443",
-            Environment::Virtual => "https://openapivts.koreainvestment.com:29443",
-        }
-        .to_string();
+        let endpoints = Endpoints::for_environment(&environment);
         Self {
             client: client.clone(),
-            endpoint_url,
+            transport: Arc::new(ReqwestTransport::new(client)),
+            circuit_breaker: None,
+            environment,
+            endpoints,
             appkey: appkey.to_string(),
             appsecret: appsecret.to_string(),
-            token: None,
-            approval_key: None,
+            token: Arc::new(RwLock::new(None)),
+            token_cache_path: None,
+            approval_key: Arc::new(RwLock::new(None)),
+            seq_no: Arc::new(AtomicU64::new(1)),
+            refresh_lock: Arc::new(tokio::sync::Mutex::new(())),
+        }
+    }
+
+    /// 투자환경 기본 주소 대신 endpoints로 요청(로컬 mock 서버 등)
+    /// 이 Auth로 만든 모든 서비스가 endpoints를 사용하므로 서비스를 만들기 전에 설정
+    pub fn with_endpoints(mut self, endpoints: Endpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    /// token, approval_key, hashkey 발급 요청 전송 계층 교체(테스트용 MockTransport 등)
+    /// 서비스의 with_transport는 서비스가 가진 Auth에도 같은 Transport를 설정함
+    pub fn with_transport(mut self, transport: Arc<dyn Transport>) -> Self {
+        self.set_transport(transport);
+        self
+    }
+
+    pub(crate) fn set_transport(&mut self, transport: Arc<dyn Transport>) {
+        self.transport = transport;
+    }
+
+    pub(crate) fn set_circuit_breaker(&mut self, circuit_breaker: CircuitBreaker) {
+        self.circuit_breaker = Some(circuit_breaker);
+    }
+
+    /// token 캐시 파일 경로 설정
+    /// 캐시에 유효한 token이 있으면 바로 불러오고, 이후 발급받는 token은 캐시에 저장함
+    /// 토큰 발급은 1분에 1회 정도로 제한되므로 프로그램을 자주 재시작할 때 사용
+    pub fn with_token_cache(mut self, path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        if let Err(e) = self.load_cached_token(&path) {
+            warn!("Failed to load token cache {:?}: {}", path, e);
+        }
+        self.token_cache_path = Some(path);
+        self
+    }
+
+    /// 캐시 파일에서 현재 투자환경, appkey에 해당하는 token과 법인 주문 일련번호를 불러옴
+    /// 유효한 token을 불러왔으면 true, 캐시가 없거나 만료되었으면 false
+    pub fn load_cached_token(&self, path: &Path) -> Result<bool, Error> {
+        if !path.exists() {
+            return Ok(false);
+        }
+        let mut cache = read_token_cache(path)?;
+        let entry = match cache.remove(&self.environment.to_string()) {
+            Some(entry) if entry.appkey == self.appkey => entry,
+            _ => return Ok(false),
+        };
+        if let Some(seq_no) = entry.seq_no {
+            self.seq_no.fetch_max(seq_no, Ordering::SeqCst);
+        }
+        let token = AccessToken {
+            token: entry.token,
+            expires_at: entry
+                .expires_at
+                .map(|secs| UNIX_EPOCH + Duration::from_secs(secs)),
+        };
+        if !token.is_valid() {
+            return Ok(false);
+        }
+        *self.token.write().unwrap() = Some(token);
+        Ok(true)
+    }
+
+    /// 현재 token을 캐시 파일에 저장
+    /// 다른 투자환경의 항목은 그대로 유지
+    pub fn save_token_cache(&self, path: &Path) -> Result<(), Error> {
+        let token = match self.token.read().unwrap().clone() {
+            Some(token) => token,
+            None => return Err(Error::AuthInitFailed("token")),
+        };
+        let mut cache = if path.exists() {
+            read_token_cache(path).unwrap_or_default()
+        } else {
+            HashMap::new()
+        };
+        cache.insert(
+            self.environment.to_string(),
+            TokenCacheEntry {
+                appkey: self.appkey.clone(),
+                token: token.token,
+                expires_at: token.expires_at.map(|expires_at| {
+                    expires_at
+                        .duration_since(UNIX_EPOCH)
+                        .unwrap_or_default()
+                        .as_secs()
+                }),
+                seq_no: Some(self.seq_no.load(Ordering::SeqCst)),
+            },
+        );
+        std::fs::write(path, serde_json::to_string_pretty(&cache)?)?;
+        Ok(())
+    }
+
+    /// 법인 주문 요청의 seq_no 헤더로 보낼 일련번호를 발급
+    /// token 캐시 파일을 설정했으면 다음 번호를 함께 저장하여 재시작 후에도 번호가 줄어들지 않음
+    pub(crate) fn next_seq_no(&self) -> u64 {
+        let seq_no = self.seq_no.fetch_add(1, Ordering::SeqCst);
+        self.persist_seq_no();
+        seq_no
+    }
+
+    /// 다음 법인 주문 일련번호를 n으로 설정(중복 번호로 거부되었을 때 복구용)
+    pub fn set_initial_seq_no(&self, n: u64) {
+        self.seq_no.store(n, Ordering::SeqCst);
+        self.persist_seq_no();
+    }
+
+    fn persist_seq_no(&self) {
+        let path = match &self.token_cache_path {
+            Some(path) => path,
+            None => return,
+        };
+        let result = match read_token_cache(path) {
+            Ok(mut cache) => match cache.get_mut(&self.environment.to_string()) {
+                Some(entry) if entry.appkey == self.appkey => {
+                    entry.seq_no = Some(self.seq_no.load(Ordering::SeqCst));
+                    serde_json::to_string_pretty(&cache)
+                        .map_err(Error::from)
+                        .and_then(|contents| Ok(std::fs::write(path, contents)?))
+                }
+                _ => self.save_token_cache(path),
+            },
+            Err(_) => self.save_token_cache(path),
+        };
+        if let Err(e) = result {
+            warn!("Failed to save seq_no to token cache {:?}: {}", path, e);
         }
     }
 
     /// 구조체에 저장되어 있는 token을 반환
     pub fn get_token(&self) -> Option<String> {
-        self.token.clone()
+        self.token
+            .read()
+            .unwrap()
+            .as_ref()
+            .map(|token| token.token.clone())
+    }
+
+    /// 저장된 token이 있고 만료되지 않았는지 확인(새로 발급하지 않음)
+    pub fn has_valid_token(&self) -> bool {
+        self.token
+            .read()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|token| token.is_valid())
+    }
+
+    /// 저장된 token의 만료 시각(만료 시각을 모르거나 token이 없으면 None)
+    pub fn token_expires_at(&self) -> Option<SystemTime> {
+        self.token
+            .read()
+            .unwrap()
+            .as_ref()
+            .and_then(|token| token.expires_at)
+    }
+
+    /// 유효한 token을 반환
+    /// 저장된 token이 없거나 만료되었으면 새로 발급받아 저장한 뒤 반환
+    /// 여러 요청이 동시에 만료를 확인해도 발급은 한 번만 하며, 나머지는 발급이 끝날 때까지 기다렸다가 같은 token을 사용
+    pub async fn ensure_valid_token(&self) -> Result<String, Error> {
+        if let Some(token) = self.valid_token() {
+            return Ok(token);
+        }
+        let _guard = self.refresh_lock.lock().await;
+        // 기다리는 동안 다른 요청이 발급을 마쳤으면 그 token을 사용
+        if let Some(token) = self.valid_token() {
+            return Ok(token);
+        }
+        if let Some(path) = &self.token_cache_path {
+            if self.load_cached_token(path)? {
+                return Ok(self.get_token().unwrap());
+            }
+        }
+        debug!("token is missing or expired. create new token");
+        match self.create_token().await {
+            Err(Error::TokenCooldown { retry_after }) => {
+                // 직전 발급 후 재발급 제한 시간이 지나지 않음. 한 번만 기다렸다가 다시 발급
+                warn!("Token issuance cooldown. retry after {:?}", retry_after);
+                tokio::time::sleep(retry_after).await;
+                self.create_token().await
+            }
+            result => result,
+        }
+    }
+
+    /// 저장된 token이 만료되지 않았으면 반환
+    fn valid_token(&self) -> Option<String> {
+        self.token
+            .read()
+            .unwrap()
+            .as_ref()
+            .filter(|token| token.is_valid())
+            .map(|token| token.token.clone())
     }
 
     /// 구조체에 저장되어 있는 approval_key 반환
     pub fn get_approval_key(&self) -> Option<String> {
-        self.approval_key.clone()
+        self.approval_key.read().unwrap().clone()
+    }
+
+    /// 실시간 (웹소켓) 접속키
+    /// REST 요청의 접근토큰(token)과는 별개의 키로, 저장된 approval_key가 없을 때만 새로 발급받음
+    /// 접속키가 거부되어 다시 발급받아야 하면 create_approval_key를 호출
+    pub async fn approval_key(&self) -> Result<String, Error> {
+        if let Some(approval_key) = self.get_approval_key() {
+            return Ok(approval_key);
+        }
+        self.create_approval_key().await
+    }
+
+    /// 투자환경
+    pub fn environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    /// 투자환경에 따른 접속 주소(REST, websocket, approval key 발급)
+    pub fn endpoints(&self) -> &Endpoints {
+        &self.endpoints
     }
 
     /// 구조체에 저장되어 있는 appkey 반환
@@ -62,10 +318,10 @@ impl Auth {
     /// 실시간 (웹소켓) 접속키 발급[실시간-000]
     /// [Docs](https://apiportal.koreainvestment.com/apiservice/oauth2#L_5c87ba63-740a-4166-93ac-803510bb9c02)
     /// 웹소켓 접속키를 발급받아서 반환함과 동시에 구조체의 approval_key 업데이트
-    pub async fn create_approval_key(&mut self) -> Result<String, Error> {
-        let approval_key = self
+    pub async fn create_approval_key(&self) -> Result<String, Error> {
+        let request = self
             .client
-            .post(format!("{}/oauth2/Approval", self.endpoint_url))
+            .post(self.endpoints.approval_url())
             .header("Content-Type", "application/json")
             .body(
                 serde_json::json!(request::auth::ApprovalKeyCreationBody::new(
@@ -74,50 +330,53 @@ impl Auth {
                 ))
                 .to_string(),
             )
-            .send()
-            .await?
-            .json::<response::auth::Body::ApprovalKeyCreation>()
+            .build()?;
+        let approval_key = self
+            .send::<response::auth::Body::ApprovalKeyCreation>(request)
             .await?
             .get_approval_key();
-        self.approval_key = Some(approval_key.clone());
+        *self.approval_key.write().unwrap() = Some(approval_key.clone());
         Ok(approval_key)
     }
 
-    pub fn set_approval_key(&mut self, approval_key: String) {
-        self.approval_key = Some(approval_key);
+    pub fn set_approval_key(&self, approval_key: String) {
+        *self.approval_key.write().unwrap() = Some(approval_key);
+    }
+
+    /// Hashkey
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/oauth2#L_214b9e22-8f2e-4fba-9688-587279f1061a)
+    /// POST 요청 body를 직렬화하여 hash값을 얻어와서 반환
+    /// 요청에는 serde_json::to_string(body)로 직렬화한 같은 문자열을 body로 보내야 함
+    pub async fn hashkey<T: Serialize>(&self, body: &T) -> Result<String, Error> {
+        self.get_hash(serde_json::to_string(body)?).await
     }
 
     /// Hashkey
     /// [Docs](https://apiportal.koreainvestment.com/apiservice/oauth2#L_214b9e22-8f2e-4fba-9688-587279f1061a)
     /// hash값을 얻어와서 반환
     pub async fn get_hash(&self, json: String) -> Result<String, Error> {
-        let mut headers = HeaderMap::new();
-        headers.insert(
-            "Content-Type",
-            HeaderValue::from_str("application/json").unwrap(),
-        );
-        headers.insert("appkey", HeaderValue::from_str(&self.appkey).unwrap());
-        headers.insert("appsecret", HeaderValue::from_str(&self.appsecret).unwrap());
-        let hash = self
+        let request = self
             .client
-            .post(format!("{}/uapi/hashkey", self.endpoint_url))
-            .headers(headers)
+            .post(format!("{}/uapi/hashkey", self.endpoints.rest))
+            .header("Content-Type", "application/json")
+            .header("appkey", &self.appkey)
+            .header("appsecret", &self.appsecret)
             .body(json)
-            .send()
-            .await?
-            .json::<response::auth::Body::HashKey>()
+            .build()?;
+        Ok(self
+            .send::<response::auth::Body::HashKey>(request)
             .await?
-            .get_hash();
-        Ok(hash)
+            .get_hash())
     }
 
     /// 접근토큰발급(P)[인증-001]
     /// [Docs](https://apiportal.koreainvestment.com/apiservice/oauth2#L_fa778c98-f68d-451e-8fff-b1c6bfe5cd30)
     /// token값을 얻어와서 반환함과 동시에 구조체의 token을 업데이트
-    pub async fn create_token(&mut self) -> Result<String, Error> {
-        let token = self
+    /// 재발급 제한 시간(1분) 안에 다시 요청하면 Error::TokenCooldown
+    pub async fn create_token(&self) -> Result<String, Error> {
+        let request = self
             .client
-            .post(format!("{}/oauth2/tokenP", self.endpoint_url))
+            .post(format!("{}/oauth2/tokenP", self.endpoints.rest))
             .header("Content-Type", "application/json")
             .body(
                 serde_json::json!(request::auth::TokenCreationBody::new(
@@ -126,17 +385,32 @@ impl Auth {
                 ))
                 .to_string(),
             )
-            .send()
-            .await?
-            .json::<response::auth::Body::TokenCreation>()
-            .await?
-            .get_access_token();
-        self.token = Some(token.clone());
+            .build()?;
+        let response = self
+            .send::<response::auth::Body::TokenCreation>(request)
+            .await?;
+        let token = response.get_access_token();
+        *self.token.write().unwrap() = Some(AccessToken {
+            token: token.clone(),
+            expires_at: Some(
+                SystemTime::now() + Duration::from_secs(response.get_expires_in() as u64),
+            ),
+        });
+        if let Some(path) = &self.token_cache_path {
+            if let Err(e) = self.save_token_cache(path) {
+                warn!("Failed to save token cache {:?}: {}", path, e);
+            }
+        }
         Ok(token)
     }
 
+    /// 이미 발급받은 token을 설정
+    /// 만료 시각을 알 수 없으므로 자동 갱신되지 않음
     pub fn set_token(&mut self, token: String) {
-        self.token = Some(token);
+        *self.token.write().unwrap() = Some(AccessToken {
+            token,
+            expires_at: None,
+        });
     }
 
     /// 접근토큰폐기(P)[인증-002]
@@ -147,15 +421,15 @@ impl Auth {
     ///     message: String,
     /// }
     pub async fn revoke_token(&self) -> Result<response::auth::Body::TokenRevoke, Error> {
-        Ok(self
+        let request = self
             .client
-            .post(format!("{}/oauth2/revokeP", &self.endpoint_url))
+            .post(format!("{}/oauth2/revokeP", &self.endpoints.rest))
             .header("Content-Type", "application/json")
             .body(
                 serde_json::json!(request::auth::TokenRevokeBody::new(
                     self.appkey.clone(),
                     self.appsecret.clone(),
-                    match self.token.clone() {
+                    match self.get_token() {
                         Some(token) => token,
                         None => {
                             return Err(Error::AuthInitFailed("token"));
@@ -164,9 +438,230 @@ impl Auth {
                 ))
                 .to_string(),
             )
-            .send()
-            .await?
-            .json::<response::auth::Body::TokenRevoke>()
-            .await?)
+            .build()?;
+        self.send(request).await
+    }
+
+    /// 인증 API 요청을 Transport로 전송하고 결과를 CircuitBreaker에 기록
+    /// 실패 응답(error_code, error_description)과 rt_cd가 "0"이 아닌 응답은 Error::from_api로 변환
+    async fn send<T: DeserializeOwned>(&self, request: reqwest::Request) -> Result<T, Error> {
+        // 결과를 기록하기 전에 이 future가 drop되면 HalfOpen 시험 요청을 되돌림
+        let _probe = self
+            .circuit_breaker
+            .as_ref()
+            .map(CircuitBreaker::check)
+            .transpose()?;
+        let path = request.url().path().to_string();
+        let result = self.transport.send(request).await;
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            record_result(circuit_breaker, &result);
+        }
+        decode_oauth(result?, &path)
+    }
+}
+
+fn decode_oauth<T: DeserializeOwned>(response: HttpResponse, path: &str) -> Result<T, Error> {
+    let body = response.body;
+    if let Ok(error) = serde_json::from_str::<response::auth::Body::OAuthError>(&body) {
+        return Err(Error::from_api(
+            String::new(),
+            error.error_code,
+            error.error_description,
+        ));
+    }
+    let value: serde_json::Value = serde_json::from_str(&body).map_err(|source| Error::Decode {
+        tr_id: path.to_string(),
+        source,
+        body: body_snippet(&body),
+    })?;
+    if let Some(error) = api_error(&value) {
+        return Err(error);
+    }
+    serde_json::from_value(value).map_err(|source| Error::Decode {
+        tr_id: path.to_string(),
+        source,
+        body: body_snippet(&body),
+    })
+}
+
+fn read_token_cache(path: &Path) -> Result<HashMap<String, TokenCacheEntry>, Error> {
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{MockResponse, MockTransport};
+
+    const TOKEN: &str = r#"{"access_token":"issued","token_type":"Bearer","expires_in":86400}"#;
+
+    fn mock_auth(mock: &Arc<MockTransport>) -> Auth {
+        Auth::new(
+            &reqwest::Client::new(),
+            Environment::Virtual,
+            "appkey",
+            "appsecret",
+        )
+        .with_transport(mock.clone())
+    }
+
+    #[tokio::test]
+    async fn create_token_through_transport() {
+        let mock =
+            Arc::new(MockTransport::new().on_path("/oauth2/tokenP", MockResponse::json(TOKEN)));
+        let auth = mock_auth(&mock);
+        assert_eq!(auth.create_token().await.unwrap(), "issued");
+        assert!(auth.has_valid_token());
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        let body: serde_json::Value =
+            serde_json::from_str(requests[0].body.as_deref().unwrap()).unwrap();
+        assert_eq!(body["appkey"], "appkey");
+    }
+
+    #[tokio::test]
+    async fn create_token_cooldown() {
+        let mock = Arc::new(MockTransport::new().on_path(
+            "/oauth2/tokenP",
+            MockResponse::json(
+                r#"{"error_description":"접근토큰 발급 잠시 후 다시 시도하세요(1분당 1회)","error_code":"EGW00133"}"#,
+            )
+            .with_status(reqwest::StatusCode::FORBIDDEN),
+        ));
+        let auth = mock_auth(&mock);
+        assert!(matches!(
+            auth.create_token().await,
+            Err(Error::TokenCooldown { .. })
+        ));
+        assert!(auth.get_token().is_none());
+    }
+
+    #[tokio::test]
+    async fn approval_key_and_hashkey_through_transport() {
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_path(
+                    "/oauth2/Approval",
+                    MockResponse::json(r#"{"approval_key":"approval"}"#),
+                )
+                .on_path(
+                    "/uapi/hashkey",
+                    MockResponse::json(r#"{"JsonBody":{},"HASH":"hash"}"#),
+                ),
+        );
+        let auth = mock_auth(&mock);
+        assert_eq!(auth.approval_key().await.unwrap(), "approval");
+        // 저장된 approval_key는 다시 발급하지 않음
+        assert_eq!(auth.approval_key().await.unwrap(), "approval");
+        assert_eq!(
+            auth.hashkey(&serde_json::json!({"PDNO": "005930"}))
+                .await
+                .unwrap(),
+            "hash"
+        );
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[1].headers.get("appsecret").unwrap(), "appsecret");
+        assert_eq!(requests[1].body.as_deref(), Some(r#"{"PDNO":"005930"}"#));
+    }
+
+    #[tokio::test]
+    async fn open_circuit_blocks_token_request() {
+        let mock = Arc::new(MockTransport::new().on_path(
+            "/oauth2/tokenP",
+            MockResponse::json("").with_status(reqwest::StatusCode::SERVICE_UNAVAILABLE),
+        ));
+        let mut auth = mock_auth(&mock);
+        auth.set_circuit_breaker(CircuitBreaker::new(1, Duration::from_secs(60)));
+        assert!(auth.create_token().await.is_err());
+        assert!(matches!(
+            auth.create_token().await,
+            Err(Error::CircuitOpen { .. })
+        ));
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_ensure_valid_token_issues_once() {
+        let mock =
+            Arc::new(MockTransport::new().on_path("/oauth2/tokenP", MockResponse::json(TOKEN)));
+        let auth = mock_auth(&mock);
+        let barrier = Arc::new(tokio::sync::Barrier::new(50));
+        let tasks: Vec<_> = (0..50)
+            .map(|_| {
+                let auth = auth.clone();
+                let barrier = barrier.clone();
+                tokio::spawn(async move {
+                    barrier.wait().await;
+                    auth.ensure_valid_token().await
+                })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap().unwrap(), "issued");
+        }
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn ensure_valid_token_refreshes_expired_token() {
+        let expired = r#"{"access_token":"expired","token_type":"Bearer","expires_in":0}"#;
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_path("/oauth2/tokenP", MockResponse::json(expired))
+                .on_path("/oauth2/tokenP", MockResponse::json(TOKEN)),
+        );
+        let auth = mock_auth(&mock);
+        assert_eq!(auth.create_token().await.unwrap(), "expired");
+        assert!(!auth.has_valid_token());
+
+        // clone된 Auth에서 갱신해도 원래 Auth에 반영됨
+        let shared = auth.clone();
+        assert_eq!(shared.ensure_valid_token().await.unwrap(), "issued");
+        assert_eq!(auth.get_token().as_deref(), Some("issued"));
+        assert!(auth.has_valid_token());
+        assert_eq!(auth.ensure_valid_token().await.unwrap(), "issued");
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn requests_use_refreshed_token() {
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_path("/oauth2/tokenP", MockResponse::json(TOKEN))
+                .on_tr_id(
+                    crate::types::TrId::PeriodicPrice,
+                    MockResponse::json(
+                        r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":"ok","output2":[]}"#,
+                    ),
+                ),
+        );
+        let quote = crate::stock::quote::Quote::new(
+            &reqwest::Client::new(),
+            Environment::Virtual,
+            mock_auth(&mock),
+            crate::types::Account::new("12345678", "01").unwrap(),
+        )
+        .unwrap()
+        .with_transport(mock.clone());
+        quote
+            .periodic_price(
+                crate::types::MarketCode::Stock,
+                "005930",
+                crate::types::ChartPeriod::Days,
+                "20240102",
+                "20240105",
+                true,
+            )
+            .await
+            .unwrap();
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].url.ends_with("/oauth2/tokenP"));
+        assert_eq!(
+            requests[1].headers.get("authorization").unwrap(),
+            "Bearer issued"
+        );
     }
 }
@@ -0,0 +1,82 @@
+use crate::client::RestClient;
+use crate::types::request::bond::order::body;
+use crate::types::response::bond::order::BondOrderResponse;
+use crate::types::{Account, Direction, Environment, Price, Quantity, TrId};
+use crate::{auth, Error};
+
+/// 장내채권을 주문할 수 있는 계좌의 계좌상품코드(종합위탁)
+const BOND_ACNT_PRDT_CD: &str = "01";
+
+#[derive(Clone)]
+pub struct BondOrder {
+    rest: RestClient,
+    account: Account,
+}
+
+impl BondOrder {
+    /// 장내채권 주문에 관한 API
+    /// [장내채권](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-bond)
+    /// account는 종합위탁 계좌(계좌상품코드 01)여야 하며, 모의투자 미지원
+    pub fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+        account: Account,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            rest: RestClient::new(client, environment, auth),
+            account,
+        })
+    }
+
+    crate::client::rest_builders!("요청 timeout과 재시도 정책 설정(주문은 재시도하지 않음)");
+
+    /// 장내채권 매수주문[국내주식-124]/매도주문[국내주식-123]
+    /// price는 채권 단가(액면 10,000원 기준). 지정가 주문만 지원
+    /// 계좌상품코드가 01이 아니면 요청을 보내지 않고 Error::WrongAccountProduct 반환
+    pub async fn order(
+        &self,
+        order_direction: Direction,
+        bond_code: &str,
+        qty: Quantity,
+        price: Price,
+    ) -> Result<BondOrderResponse, Error> {
+        if self.account.product_code() != BOND_ACNT_PRDT_CD {
+            return Err(Error::WrongAccountProduct(
+                self.account.product_code().to_string(),
+                BOND_ACNT_PRDT_CD,
+            ));
+        }
+        if qty == Quantity::from(0) {
+            return Err(Error::InvalidParam {
+                field: "qty",
+                reason: "must be non-zero",
+            });
+        }
+        if price == Price::from(0) {
+            return Err(Error::InvalidParam {
+                field: "price",
+                reason: "bond order requires a limit price",
+            });
+        }
+        let cano = self.account.cano().to_string();
+        let acnt_prdt_cd = self.account.product_code().to_string();
+        let pdno = bond_code.to_string();
+        match order_direction {
+            Direction::Bid => {
+                let tr_id = self.rest.require_real(TrId::RealBondBuyOrder)?;
+                let request = body::Buy::new(cano, acnt_prdt_cd, pdno, qty, price);
+                self.rest
+                    .post("/uapi/domestic-bond/v1/trading/buy", tr_id, &request)
+                    .await
+            }
+            Direction::Ask => {
+                let tr_id = self.rest.require_real(TrId::RealBondSellOrder)?;
+                let request = body::Sell::new(cano, acnt_prdt_cd, pdno, qty, price);
+                self.rest
+                    .post("/uapi/domestic-bond/v1/trading/sell", tr_id, &request)
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::client::RestClient;
+use crate::types::request::bond::quote::{BondBasicInfoParameter, BondPriceParameter};
+use crate::types::response::bond::quote::{BondBasicInfoResponse, BondPriceResponse};
+use crate::types::{Environment, TrId};
+use crate::{auth, Error};
+
+#[derive(Clone)]
+pub struct BondQuote {
+    rest: RestClient,
+}
+
+impl BondQuote {
+    /// 장내채권 시세에 관한 API
+    /// [장내채권](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-bond)
+    /// 모의투자 미지원
+    pub fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            rest: RestClient::new(client, environment, auth),
+        })
+    }
+
+    crate::client::rest_builders!();
+
+    /// 장내채권현재가(시세)[국내주식-200]
+    /// 현재가, 전일 대비, 수익률
+    pub async fn current_price(&self, bond_code: &str) -> Result<BondPriceResponse, Error> {
+        let tr_id = self.rest.require_real(TrId::BondPrice)?;
+        let param = BondPriceParameter::new(bond_code.to_string());
+        let path = "/uapi/domestic-bond/v1/quotations/inquire-price";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 장내채권 기본조회[국내주식-129]
+    /// 발행일, 만기일, 표면이율, 액면가
+    pub async fn basic_info(&self, bond_code: &str) -> Result<BondBasicInfoResponse, Error> {
+        let tr_id = self.rest.require_real(TrId::BondBasicInfo)?;
+        let param = BondBasicInfoParameter::new(bond_code.to_string());
+        let path = "/uapi/domestic-bond/v1/quotations/search-bond-info";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+}
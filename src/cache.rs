@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// 자주 바뀌지 않는 참조 데이터(휴장일, 종목 기본정보 등)의 응답 캐시
+/// key는 환경, tr_id, 요청 path와 query parameter로 구성되며 value는 응답 본문(JSON)
+/// Redis 등 외부 저장소를 사용하려면 이 trait을 구현하여 with_cache로 전달
+pub trait Cache: Send + Sync {
+    /// 만료되지 않은 값
+    fn get(&self, key: &str) -> Option<String>;
+    /// ttl 동안 유효한 값 저장
+    fn set(&self, key: &str, value: String, ttl: Duration);
+}
+
+/// 프로세스 메모리에 저장하는 기본 Cache
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, (Instant, String)>>,
+}
+
+impl MemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Cache for MemoryCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let mut entries = self.entries.lock().unwrap();
+        match entries.get(key) {
+            Some((expires_at, value)) if Instant::now() < *expires_at => Some(value.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn set(&self, key: &str, value: String, ttl: Duration) {
+        self.entries
+            .lock()
+            .unwrap()
+            .insert(key.to_string(), (Instant::now() + ttl, value));
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct FileCacheEntry {
+    expires_at: u64, // 만료 시각(unix timestamp)
+    value: String,
+}
+
+/// JSON 파일 하나에 저장하는 Cache
+/// 프로그램을 다시 실행해도 ttl 안에서는 다시 조회하지 않음. 파일을 읽거나 쓰지 못하면 캐시하지 않은 것으로 처리
+#[derive(Debug)]
+pub struct FileCache {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileCache {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn read(path: &Path) -> HashMap<String, FileCacheEntry> {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+}
+
+impl Cache for FileCache {
+    fn get(&self, key: &str) -> Option<String> {
+        let _guard = self.lock.lock().unwrap();
+        Self::read(&self.path)
+            .remove(key)
+            .filter(|entry| unix_now() < entry.expires_at)
+            .map(|entry| entry.value)
+    }
+
+    fn set(&self, key: &str, value: String, ttl: Duration) {
+        let _guard = self.lock.lock().unwrap();
+        let now = unix_now();
+        let mut entries = Self::read(&self.path);
+        entries.retain(|_, entry| now < entry.expires_at);
+        entries.insert(
+            key.to_string(),
+            FileCacheEntry {
+                expires_at: now + ttl.as_secs(),
+                value,
+            },
+        );
+        let result = serde_json::to_string(&entries)
+            .map_err(|e| e.to_string())
+            .and_then(|contents| std::fs::write(&self.path, contents).map_err(|e| e.to_string()));
+        if let Err(e) = result {
+            warn!("Failed to write cache {:?}: {}", self.path, e);
+        }
+    }
+}
+
+fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
@@ -0,0 +1,243 @@
+use crate::Error;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// 연속 실패 시 요청 차단(circuit breaker)
+/// 서버 에러(5xx), 네트워크 에러(timeout, 연결 실패 등)가 failure_threshold번 연속되면 열려서(Open)
+/// cool_down 동안 요청을 보내지 않고 Error::CircuitOpen을 반환함
+/// cool_down이 지나면 한 건만 시험 삼아 보내고(HalfOpen), 성공하면 닫히고(Closed) 실패하면 다시 열림
+/// 4xx 응답과 요청 전의 검증 에러는 실패로 세지 않음
+/// clone된 CircuitBreaker끼리 상태를 공유하므로 여러 서비스 구조체에 넘겨서 함께 사용
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker {
+    state: Arc<Mutex<Inner>>,
+    failure_threshold: u32,
+    cool_down: Duration,
+}
+
+/// CircuitBreaker의 현재 상태
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CircuitState {
+    Closed,   // 정상(요청 전송)
+    Open,     // 차단(cool_down 동안 Error::CircuitOpen)
+    HalfOpen, // 시험 요청 한 건만 전송
+}
+
+#[derive(Debug)]
+enum Inner {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen { probing: bool },
+}
+
+impl Default for CircuitBreaker {
+    /// 5번 연속 실패하면 30초 동안 차단
+    fn default() -> Self {
+        Self::new(5, Duration::from_secs(30))
+    }
+}
+
+impl CircuitBreaker {
+    /// failure_threshold번 연속 실패하면 cool_down 동안 차단하는 CircuitBreaker 생성
+    pub fn new(failure_threshold: u32, cool_down: Duration) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(Inner::Closed { failures: 0 })),
+            failure_threshold: failure_threshold.max(1),
+            cool_down,
+        }
+    }
+
+    /// 현재 상태. Open이어도 cool_down이 지났으면 HalfOpen
+    pub fn state(&self) -> CircuitState {
+        match *self.state.lock().unwrap() {
+            Inner::Closed { .. } => CircuitState::Closed,
+            Inner::Open { until } if Instant::now() < until => CircuitState::Open,
+            Inner::Open { .. } | Inner::HalfOpen { .. } => CircuitState::HalfOpen,
+        }
+    }
+
+    /// 요청을 보내도 되는지 확인
+    /// 차단 중이거나 HalfOpen에서 이미 시험 요청을 보냈으면 Error::CircuitOpen
+    /// 반환된 Probe는 결과를 기록할 때까지 가지고 있어야 함
+    pub(crate) fn check(&self) -> Result<Probe, Error> {
+        let mut state = self.state.lock().unwrap();
+        match *state {
+            Inner::Closed { .. } => Ok(Probe { state: None }),
+            Inner::Open { until } => {
+                let now = Instant::now();
+                if now < until {
+                    return Err(Error::CircuitOpen {
+                        retry_after: until - now,
+                    });
+                }
+                *state = Inner::HalfOpen { probing: true };
+                Ok(self.probe())
+            }
+            Inner::HalfOpen { probing: true } => Err(Error::CircuitOpen {
+                retry_after: Duration::ZERO,
+            }),
+            Inner::HalfOpen { probing: false } => {
+                *state = Inner::HalfOpen { probing: true };
+                Ok(self.probe())
+            }
+        }
+    }
+
+    fn probe(&self) -> Probe {
+        Probe {
+            state: Some(self.state.clone()),
+        }
+    }
+
+    /// 요청 성공. 연속 실패 횟수를 초기화하고 닫음
+    /// Open 상태에서는 무시(차단 전에 보낸 요청의 늦은 응답). 다시 닫으려면 HalfOpen 시험 요청이 성공해야 함
+    pub(crate) fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        if let Inner::Open { .. } = *state {
+            return;
+        }
+        *state = Inner::Closed { failures: 0 };
+    }
+
+    /// 요청 실패. failure_threshold번 연속되거나 시험 요청이 실패하면 cool_down 동안 차단
+    pub(crate) fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        let failures = match *state {
+            Inner::Closed { failures } => failures + 1,
+            Inner::HalfOpen { .. } => self.failure_threshold,
+            Inner::Open { .. } => return,
+        };
+        *state = if failures >= self.failure_threshold {
+            warn!(
+                "Circuit opened after {} consecutive failures. cool down for {:?}",
+                failures, self.cool_down
+            );
+            Inner::Open {
+                until: Instant::now() + self.cool_down,
+            }
+        } else {
+            Inner::Closed { failures }
+        };
+    }
+}
+
+/// HalfOpen에서 보낸 시험 요청
+/// 결과를 기록하기 전에 요청 future가 drop되면(timeout, select 등) 다음 요청이 시험 요청을 보낼 수 있도록
+/// HalfOpen { probing: false }로 되돌림. Closed에서 보낸 요청은 아무것도 하지 않음
+#[must_use]
+pub(crate) struct Probe {
+    state: Option<Arc<Mutex<Inner>>>,
+}
+
+impl Drop for Probe {
+    fn drop(&mut self) {
+        let Some(state) = &self.state else {
+            return;
+        };
+        if let Ok(mut state) = state.lock() {
+            if let Inner::HalfOpen { probing: true } = *state {
+                *state = Inner::HalfOpen { probing: false };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COOL_DOWN: Duration = Duration::from_millis(20);
+
+    fn open(circuit_breaker: &CircuitBreaker) {
+        for _ in 0..circuit_breaker.failure_threshold {
+            let _probe = circuit_breaker.check().unwrap();
+            circuit_breaker.record_failure();
+        }
+        assert_eq!(circuit_breaker.state(), CircuitState::Open);
+    }
+
+    #[test]
+    fn closed_open_half_open_closed() {
+        let circuit_breaker = CircuitBreaker::new(2, COOL_DOWN);
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+
+        let _probe = circuit_breaker.check().unwrap();
+        circuit_breaker.record_failure();
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+        let _probe = circuit_breaker.check().unwrap();
+        circuit_breaker.record_failure();
+        assert_eq!(circuit_breaker.state(), CircuitState::Open);
+        assert!(matches!(
+            circuit_breaker.check(),
+            Err(Error::CircuitOpen { .. })
+        ));
+
+        std::thread::sleep(COOL_DOWN);
+        assert_eq!(circuit_breaker.state(), CircuitState::HalfOpen);
+        let probe = circuit_breaker.check().unwrap();
+        // 시험 요청이 끝나기 전의 다른 요청은 차단
+        assert!(matches!(
+            circuit_breaker.check(),
+            Err(Error::CircuitOpen { retry_after }) if retry_after == Duration::ZERO
+        ));
+        circuit_breaker.record_success();
+        drop(probe);
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+        assert!(circuit_breaker.check().is_ok());
+    }
+
+    #[test]
+    fn failed_probe_reopens() {
+        let circuit_breaker = CircuitBreaker::new(3, COOL_DOWN);
+        open(&circuit_breaker);
+        std::thread::sleep(COOL_DOWN);
+        let _probe = circuit_breaker.check().unwrap();
+        circuit_breaker.record_failure();
+        assert_eq!(circuit_breaker.state(), CircuitState::Open);
+    }
+
+    #[test]
+    fn dropped_probe_allows_next_probe() {
+        let circuit_breaker = CircuitBreaker::new(1, COOL_DOWN);
+        open(&circuit_breaker);
+        std::thread::sleep(COOL_DOWN);
+        let probe = circuit_breaker.check().unwrap();
+        assert!(circuit_breaker.check().is_err());
+        // 결과를 기록하지 않고 drop(요청 future가 취소된 경우)
+        drop(probe);
+        assert_eq!(circuit_breaker.state(), CircuitState::HalfOpen);
+        let _probe = circuit_breaker.check().unwrap();
+        circuit_breaker.record_success();
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+    }
+
+    #[tokio::test]
+    async fn cancelled_request_releases_probe() {
+        let circuit_breaker = CircuitBreaker::new(1, COOL_DOWN);
+        open(&circuit_breaker);
+        tokio::time::sleep(COOL_DOWN).await;
+        let request = {
+            let circuit_breaker = circuit_breaker.clone();
+            async move {
+                let _probe = circuit_breaker.check()?;
+                std::future::pending::<()>().await;
+                Ok::<(), Error>(())
+            }
+        };
+        let timed_out = tokio::time::timeout(Duration::from_millis(10), request).await;
+        assert!(timed_out.is_err());
+        assert!(circuit_breaker.check().is_ok());
+    }
+
+    #[test]
+    fn success_while_open_is_ignored() {
+        let circuit_breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+        open(&circuit_breaker);
+        circuit_breaker.record_success();
+        assert_eq!(circuit_breaker.state(), CircuitState::Open);
+        assert!(matches!(
+            circuit_breaker.check(),
+            Err(Error::CircuitOpen { .. })
+        ));
+    }
+}
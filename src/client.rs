@@ -0,0 +1,1244 @@
+use crate::cache::Cache;
+use crate::circuit_breaker::CircuitBreaker;
+use crate::paging::Page;
+use crate::transport::{HttpResponse, ReqwestTransport, Transport};
+use crate::types::response::{Continuation, RawBody};
+use crate::types::{CorporateHeaders, CustomerType, Environment, TrId};
+use crate::{auth, Error, NetworkKind, RateLimiter};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Request, RequestBuilder, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use url::Url;
+
+/// 요청 timeout과 재시도 정책
+/// 연결 실패, timeout, 429, 5xx 응답, 중간에 끊긴(JSON이 아닌) 응답 본문을 지수 backoff(+jitter)로 재시도하며
+/// 주문(POST)은 중복 주문을 막기 위해 재시도하지 않음
+#[derive(Clone, Debug)]
+pub struct RetryConfig {
+    pub max_retries: u32,     // 최대 재시도 횟수
+    pub base_delay: Duration, // 첫 재시도 대기 시간(재시도마다 2배)
+    pub timeout: Duration,    // 요청별 timeout
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(200),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// attempt(0부터)번째 재시도 전 대기 시간. base_delay * 2^attempt에 0.5~1.5배 jitter 적용
+    fn backoff(&self, attempt: u32) -> Duration {
+        let jitter = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos() % 1000)
+            .unwrap_or(500) as f64
+            / 1000.0;
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .mul_f64(0.5 + jitter)
+    }
+}
+
+/// KIS 서버 접속용 reqwest::Client 생성
+/// keep-alive, 연결 풀, crate를 나타내는 User-Agent를 기본으로 설정하고 proxy, 인증서를 추가할 수 있음
+/// (기본 reqwest User-Agent는 차단되는 경우가 있음)
+#[derive(Default)]
+pub struct ClientBuilder {
+    proxy: Option<reqwest::Proxy>,
+    root_certificates: Vec<reqwest::Certificate>,
+    user_agent: Option<String>,
+    pool_max_idle_per_host: Option<usize>,
+}
+
+impl ClientBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 사내 proxy 등 요청을 중계할 proxy
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// 신뢰할 루트 인증서 추가
+    pub fn add_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// User-Agent(기본값: crate 이름/버전)
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// host별 유지할 유휴 연결 수(기본값: 8)
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    pub fn build(self) -> Result<reqwest::Client, Error> {
+        let user_agent = self
+            .user_agent
+            .unwrap_or_else(|| format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")));
+        let mut builder = reqwest::Client::builder()
+            .user_agent(user_agent)
+            .connect_timeout(Duration::from_secs(5))
+            .tcp_keepalive(Duration::from_secs(60))
+            .pool_idle_timeout(Duration::from_secs(90))
+            .pool_max_idle_per_host(self.pool_max_idle_per_host.unwrap_or(8));
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(proxy);
+        }
+        for certificate in self.root_certificates {
+            builder = builder.add_root_certificate(certificate);
+        }
+        Ok(builder.build()?)
+    }
+}
+
+/// 응답 본문과 응답 헤더 정보
+#[derive(Clone, Debug)]
+pub struct Envelope<T> {
+    pub body: T,
+    pub tr_cont: Option<String>, // 연속 거래 여부(F or M: 다음 데이터 있음 / D or E: 마지막 데이터)
+    pub tr_id: String,           // 거래ID
+    pub gt_uid: Option<String>,  // Global UID(거래고유번호, 문의 시 사용)
+}
+
+/// 기본 요청 헤더에 추가하거나 덮어쓸 헤더
+/// 같은 이름의 기본 헤더(custtype, tr_cont, 법인 헤더 등)는 이 값으로 바뀌며, 없는 헤더는 추가됨
+/// 새 TR이나 법인 계좌에서 필요한 헤더를 전송할 때 사용. Authorization, appkey, appsecret도 바뀌므로 주의
+#[derive(Clone, Debug, Default)]
+pub struct HeaderOverrides {
+    headers: Vec<(String, String)>,
+}
+
+impl HeaderOverrides {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// name 헤더를 value로 설정. 같은 name을 다시 설정하면 마지막 값을 사용
+    pub fn header(mut self, name: &str, value: &str) -> Self {
+        let name = name.to_ascii_lowercase();
+        self.headers.retain(|(n, _)| n != &name);
+        self.headers.push((name, value.to_string()));
+        self
+    }
+
+    /// 고객타입(custtype)
+    pub fn custtype(self, custtype: CustomerType) -> Self {
+        self.header("custtype", &String::from(custtype))
+    }
+
+    /// 연속 거래 여부(tr_cont)
+    pub fn tr_cont(self, tr_cont: &str) -> Self {
+        self.header("tr_cont", tr_cont)
+    }
+
+    /// 설정된 헤더 값
+    pub fn get(&self, name: &str) -> Option<&str> {
+        let name = name.to_ascii_lowercase();
+        self.headers
+            .iter()
+            .find(|(n, _)| n == &name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// headers의 같은 이름 헤더를 모두 지우고 설정한 값으로 바꿈
+    /// 헤더 이름이나 값으로 쓸 수 없는 문자가 있으면 Error::InvalidParam
+    pub(crate) fn apply(&self, headers: &mut HeaderMap) -> Result<(), Error> {
+        for (name, value) in self.headers.iter() {
+            let name =
+                HeaderName::from_bytes(name.as_bytes()).map_err(|_| Error::InvalidParam {
+                    field: "headers",
+                    reason: "invalid header name",
+                })?;
+            let value = HeaderValue::from_str(value).map_err(|_| Error::InvalidParam {
+                field: "headers",
+                reason: "invalid header value",
+            })?;
+            headers.insert(name, value);
+        }
+        Ok(())
+    }
+}
+
+/// 서비스 구조체의 RestClient(rest 필드) 설정 builder 메서드
+/// 주문 서비스는 with_retry 설명을 인자로 넘김
+macro_rules! rest_builders {
+    () => {
+        $crate::client::rest_builders!("요청 timeout과 재시도 정책 설정");
+    };
+    ($retry_doc:literal) => {
+        /// 다른 서비스와 공유할 RateLimiter 설정
+        pub fn with_rate_limiter(mut self, rate_limiter: $crate::RateLimiter) -> Self {
+            self.rest.set_rate_limiter(rate_limiter);
+            self
+        }
+
+        /// 다른 서비스와 공유할 CircuitBreaker 설정
+        pub fn with_circuit_breaker(mut self, circuit_breaker: $crate::CircuitBreaker) -> Self {
+            self.rest.set_circuit_breaker(circuit_breaker);
+            self
+        }
+
+        #[doc = $retry_doc]
+        pub fn with_retry(mut self, retry: $crate::RetryConfig) -> Self {
+            self.rest.set_retry(retry);
+            self
+        }
+
+        /// 요청 전송 계층 교체(테스트용 MockTransport 등)
+        pub fn with_transport(mut self, transport: std::sync::Arc<dyn $crate::Transport>) -> Self {
+            self.rest.set_transport(transport);
+            self
+        }
+
+        /// 법인 고객(custtype B)으로 요청. 설정하지 않으면 개인(P)
+        pub fn with_corporate(mut self, corporate: $crate::types::CorporateHeaders) -> Self {
+            self.rest.set_corporate(corporate);
+            self
+        }
+
+        /// 모든 요청에 헤더를 추가하거나 덮어씀(기본 헤더, with_corporate 헤더보다 나중에 적용)
+        pub fn with_headers(mut self, overrides: $crate::HeaderOverrides) -> Self {
+            self.rest.set_header_overrides(overrides);
+            self
+        }
+
+        /// tr_id 헤더로 기본 TR 대신 override_tr_id를 전송(KIS에서 TR ID가 바뀐 경우 등)
+        /// override_tr_id는 검증하지 않으며, 응답 형식이 기존 TR과 같아야 함
+        pub fn with_tr_id(mut self, tr_id: $crate::types::TrId, override_tr_id: &str) -> Self {
+            self.rest.set_tr_id_override(tr_id, override_tr_id);
+            self
+        }
+    };
+}
+pub(crate) use rest_builders;
+
+/// 서비스 구조체(Quote, Korea, Balance 등)가 공유하는 REST 요청 처리
+/// 공통 헤더(Authorization, appkey, appsecret, tr_id, custtype) 설정과 요청 수 제한을 담당
+#[derive(Clone)]
+pub(crate) struct RestClient {
+    client: reqwest::Client,
+    transport: Arc<dyn Transport>,
+    endpoint_url: String,
+    environment: Environment,
+    auth: auth::Auth,
+    rate_limiter: RateLimiter,
+    circuit_breaker: Option<CircuitBreaker>,
+    retry: Option<RetryConfig>,
+    custtype: CustomerType,
+    corporate: CorporateHeaders,
+    tr_id_overrides: HashMap<String, String>, // 기본 tr_id -> 대신 전송할 tr_id
+    header_overrides: HeaderOverrides,        // 모든 요청에 덮어쓸 헤더
+    cache: Option<Arc<dyn Cache>>,
+}
+
+impl RestClient {
+    pub(crate) fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+    ) -> Self {
+        let endpoint_url = auth.endpoints().rest.clone();
+        let rate_limiter = RateLimiter::for_environment(&environment);
+        Self {
+            client: client.clone(),
+            transport: Arc::new(ReqwestTransport::new(client)),
+            endpoint_url,
+            environment,
+            auth,
+            rate_limiter,
+            circuit_breaker: None,
+            retry: None,
+            custtype: CustomerType::Personal,
+            corporate: CorporateHeaders::default(),
+            tr_id_overrides: HashMap::new(),
+            header_overrides: HeaderOverrides::default(),
+            cache: None,
+        }
+    }
+
+    pub(crate) fn environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    /// 모의투자에서 지원하지 않는 TR이면 요청을 보내지 않고 Error::UnsupportedInVirtual 반환
+    pub(crate) fn require_real(&self, tr_id: TrId) -> Result<TrId, Error> {
+        match self.environment {
+            Environment::Real => Ok(tr_id),
+            Environment::Virtual => Err(Error::UnsupportedInVirtual(tr_id)),
+        }
+    }
+
+    pub(crate) fn rate_limiter(&self) -> &RateLimiter {
+        &self.rate_limiter
+    }
+
+    pub(crate) fn set_rate_limiter(&mut self, rate_limiter: RateLimiter) {
+        self.rate_limiter = rate_limiter;
+    }
+
+    /// token, hashkey 발급 요청에도 같은 CircuitBreaker 사용
+    pub(crate) fn set_circuit_breaker(&mut self, circuit_breaker: CircuitBreaker) {
+        self.auth.set_circuit_breaker(circuit_breaker.clone());
+        self.circuit_breaker = Some(circuit_breaker);
+    }
+
+    /// token, hashkey 발급 요청도 같은 Transport로 전송
+    pub(crate) fn set_transport(&mut self, transport: Arc<dyn Transport>) {
+        self.auth.set_transport(transport.clone());
+        self.transport = transport;
+    }
+
+    /// 법인 고객으로 요청(custtype B와 추가 헤더)
+    pub(crate) fn set_corporate(&mut self, corporate: CorporateHeaders) {
+        self.custtype = CustomerType::Business;
+        self.corporate = corporate;
+    }
+
+    pub(crate) fn set_retry(&mut self, retry: RetryConfig) {
+        self.retry = Some(retry);
+    }
+
+    /// tr_id 헤더로 기본값 대신 override를 전송
+    pub(crate) fn set_tr_id_override(&mut self, tr_id: TrId, override_tr_id: &str) {
+        self.tr_id_overrides
+            .insert(tr_id.to_string(), override_tr_id.to_string());
+    }
+
+    /// 모든 요청에 덮어쓸 헤더
+    pub(crate) fn set_header_overrides(&mut self, overrides: HeaderOverrides) {
+        self.header_overrides = overrides;
+    }
+
+    /// get_cached 응답을 저장할 Cache
+    pub(crate) fn set_cache(&mut self, cache: Arc<dyn Cache>) {
+        self.cache = Some(cache);
+    }
+
+    /// endpoint_url + path에 query parameter를 붙인 URL
+    pub(crate) fn url<I, K, V>(&self, path: &str, params: I) -> Result<Url, Error>
+    where
+        I: IntoIterator,
+        I::Item: std::borrow::Borrow<(K, V)>,
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        Ok(Url::parse_with_params(
+            &format!("{}{}", self.endpoint_url, path),
+            params,
+        )?)
+    }
+
+    /// GET 요청(조회)
+    pub(crate) async fn get<T: DeserializeOwned + RawBody>(
+        &self,
+        url: Url,
+        tr_id: TrId,
+    ) -> Result<T, Error> {
+        Ok(self.get_with_meta(url, tr_id).await?.body)
+    }
+
+    /// GET 요청(조회). 응답 헤더(tr_cont, tr_id, gt_uid)를 함께 반환
+    pub(crate) async fn get_with_meta<T: DeserializeOwned + RawBody>(
+        &self,
+        url: Url,
+        tr_id: TrId,
+    ) -> Result<Envelope<T>, Error> {
+        let request = self.client.get(url);
+        self.send(request, tr_id, true).await
+    }
+
+    /// 캐시를 사용하는 GET 요청(참조 데이터 조회)
+    /// 환경, tr_id, path, query parameter가 같은 요청은 ttl 동안 다시 보내지 않고 저장된 응답을 반환
+    /// 정상 응답(rt_cd "0")만 저장하며, Cache가 설정되지 않았으면 get과 같음
+    pub(crate) async fn get_cached<T: DeserializeOwned + RawBody>(
+        &self,
+        url: Url,
+        tr_id: TrId,
+        ttl: Duration,
+    ) -> Result<T, Error> {
+        let cache = match &self.cache {
+            Some(cache) => cache,
+            None => return self.get(url, tr_id).await,
+        };
+        let tr_id = tr_id.to_string();
+        let key = format!(
+            "{}:{}:{}?{}",
+            self.environment,
+            tr_id,
+            url.path(),
+            url.query().unwrap_or_default()
+        );
+        if let Some(body) = cache.get(&key) {
+            return decode(body, &tr_id, None);
+        }
+        let (response, tr_id) = self
+            .execute(self.client.get(url), &tr_id, true, None)
+            .await?;
+        let body = response.body.clone();
+        let gt_uid = header(&response, "gt_uid");
+        let decoded = decode(response.body, &tr_id, gt_uid)?;
+        cache.set(&key, body, ttl);
+        Ok(decoded)
+    }
+
+    /// tr_id 문자열로 보내는 GET 요청. 응답을 타입 없이 JSON으로 반환(raw_get)
+    /// rt_cd가 "0"이 아니면 다른 요청과 같이 Error::from_api로 변환
+    /// overrides의 헤더는 서비스에 설정한 헤더보다 나중에 적용
+    pub(crate) async fn get_raw(
+        &self,
+        url: Url,
+        tr_id: &str,
+        overrides: Option<&HeaderOverrides>,
+    ) -> Result<serde_json::Value, Error> {
+        let (response, tr_id) = self
+            .execute(self.client.get(url), tr_id, true, overrides)
+            .await?;
+        let gt_uid = header(&response, "gt_uid");
+        decode(response.body, &tr_id, gt_uid)
+    }
+
+    /// 연속조회 GET 요청
+    /// 첫 페이지가 아니면 tr_cont "N"을 붙여 요청하고, 응답 헤더의 tr_cont와 연속조회키를 함께 반환
+    pub(crate) async fn get_page<T: DeserializeOwned + RawBody + Continuation>(
+        &self,
+        url: Url,
+        tr_id: TrId,
+        is_continued: bool,
+    ) -> Result<Page<T>, Error> {
+        let mut request = self.client.get(url);
+        if is_continued {
+            request = request.header("tr_cont", "N");
+        }
+        let envelope: Envelope<T> = self.send(request, tr_id, true).await?;
+        let key = envelope.body.continuation_key();
+        Ok(Page {
+            body: envelope.body,
+            tr_cont: envelope.tr_cont.unwrap_or_default(),
+            key,
+        })
+    }
+
+    /// POST 요청(주문)
+    /// body의 hashkey를 발급받아 hashkey 헤더와 함께 전송
+    pub(crate) async fn post<B: Serialize, T: DeserializeOwned + RawBody>(
+        &self,
+        path: &str,
+        tr_id: TrId,
+        body: &B,
+    ) -> Result<T, Error> {
+        Ok(self.post_with_meta(path, tr_id, body).await?.body)
+    }
+
+    /// POST 요청(주문). 응답 헤더(tr_cont, tr_id, gt_uid)를 함께 반환
+    pub(crate) async fn post_with_meta<B: Serialize, T: DeserializeOwned + RawBody>(
+        &self,
+        path: &str,
+        tr_id: TrId,
+        body: &B,
+    ) -> Result<Envelope<T>, Error> {
+        self.rate_limiter.acquire().await;
+        let hash = self.auth.hashkey(body).await?;
+        let request = self
+            .client
+            .post(format!("{}{}", self.endpoint_url, path))
+            .header("hashkey", hash)
+            .body(serde_json::to_string(body)?);
+        self.send(request, tr_id, false).await
+    }
+
+    /// 응답 본문과 응답 헤더(tr_cont, tr_id, gt_uid)를 반환
+    /// retryable이 false이면(주문) RetryConfig가 있어도 재시도하지 않음
+    async fn send<T: DeserializeOwned + RawBody>(
+        &self,
+        request: RequestBuilder,
+        tr_id: TrId,
+        retryable: bool,
+    ) -> Result<Envelope<T>, Error> {
+        let (response, tr_id) = self
+            .execute(request, &tr_id.to_string(), retryable, None)
+            .await?;
+        let tr_cont = header(&response, "tr_cont");
+        let gt_uid = header(&response, "gt_uid");
+        let tr_id = header(&response, "tr_id").unwrap_or(tr_id);
+        Ok(Envelope {
+            body: decode(response.body, &tr_id, gt_uid.clone())?,
+            tr_cont,
+            tr_id,
+            gt_uid,
+        })
+    }
+
+    /// 공통 헤더를 붙여 요청을 전송하고 HTTP 응답과 실제로 전송한 tr_id를 반환
+    async fn execute(
+        &self,
+        request: RequestBuilder,
+        tr_id: &str,
+        retryable: bool,
+        overrides: Option<&HeaderOverrides>,
+    ) -> Result<(HttpResponse, String), Error> {
+        let tr_id = self
+            .tr_id_overrides
+            .get(tr_id)
+            .cloned()
+            .unwrap_or_else(|| tr_id.to_string());
+        let mut request = request
+            .header("Content-Type", "application/json")
+            .header(
+                "Authorization",
+                format!("Bearer {}", self.auth.ensure_valid_token().await?),
+            )
+            .header("appkey", self.auth.get_appkey())
+            .header("appsecret", self.auth.get_appsecret())
+            .header("tr_id", &tr_id)
+            .header("custtype", String::from(self.custtype.clone()));
+        for (name, value) in self.corporate.iter() {
+            request = request.header(name, value);
+        }
+        if self.corporate.gt_uid {
+            request = request.header("gt_uid", next_gt_uid());
+        }
+        if let Some(retry) = &self.retry {
+            request = request.timeout(retry.timeout);
+        }
+        let max_retries = match (&self.retry, retryable) {
+            (Some(retry), true) => retry.max_retries,
+            _ => 0,
+        };
+        let mut request = request.build()?;
+        // 법인 주문(POST)은 요청마다 증가하는 일련번호를 seq_no로 전송(CorporateHeaders의 seq_no 대신)
+        if !retryable && matches!(self.custtype, CustomerType::Business) {
+            if let Ok(seq_no) = HeaderValue::from_str(&format!("{:02}", self.auth.next_seq_no())) {
+                request.headers_mut().insert("seq_no", seq_no);
+            }
+        }
+        // 기본 헤더 위에 서비스의 HeaderOverrides, 요청별 HeaderOverrides 순으로 덮어씀
+        for overrides in std::iter::once(&self.header_overrides).chain(overrides) {
+            overrides.apply(request.headers_mut())?;
+        }
+        #[cfg(feature = "tracing")]
+        let response = trace::instrument(
+            &tr_id,
+            request.method().clone(),
+            request.url().clone(),
+            self.transmit(request, max_retries),
+        )
+        .await?;
+        #[cfg(not(feature = "tracing"))]
+        let response = self.transmit(request, max_retries).await?;
+        Ok((response, tr_id))
+    }
+
+    /// REST 서버에 HEAD 요청을 보내 connection pool에 연결(DNS 조회, TLS handshake)을 미리 만들어 둠
+    /// 응답 상태 코드는 확인하지 않으며 요청 수 제한과 CircuitBreaker를 거치지 않음
+    pub(crate) async fn warm_up(&self) -> Result<(), Error> {
+        let request = self.client.head(&self.endpoint_url).build()?;
+        self.transport.send(request).await?;
+        Ok(())
+    }
+
+    /// CircuitBreaker가 열려 있으면 보내지 않고 Error::CircuitOpen
+    /// 요청 수 제한을 지키며 한 번 전송하고, 결과를 CircuitBreaker에 기록
+    async fn send_once(&self, request: Request) -> Result<HttpResponse, Error> {
+        // 결과를 기록하기 전에 이 future가 drop되면 HalfOpen 시험 요청을 되돌림
+        let _probe = self
+            .circuit_breaker
+            .as_ref()
+            .map(CircuitBreaker::check)
+            .transpose()?;
+        self.rate_limiter.acquire().await;
+        let result = self.transport.send(request).await;
+        if let Some(circuit_breaker) = &self.circuit_breaker {
+            record_result(circuit_breaker, &result);
+        }
+        result
+    }
+
+    /// 요청 수 제한을 지키며 전송. 재시도 가능한 실패는 max_retries번까지 다시 전송
+    async fn transmit(&self, request: Request, max_retries: u32) -> Result<HttpResponse, Error> {
+        let mut attempt = 0;
+        let response = loop {
+            let result = match request.try_clone() {
+                Some(request) if attempt < max_retries => self.send_once(request).await,
+                _ => break self.send_once(request).await?,
+            };
+            let should_retry = match &result {
+                Ok(response) => {
+                    response.status == StatusCode::TOO_MANY_REQUESTS
+                        || response.status.is_server_error()
+                        || (response.status.is_success() && is_incomplete_json(&response.body))
+                }
+                Err(Error::Network(kind, _)) => {
+                    matches!(kind, NetworkKind::Timeout | NetworkKind::Connect)
+                }
+                Err(_) => false,
+            };
+            if !should_retry {
+                break result?;
+            }
+            if let Some(retry) = &self.retry {
+                let delay = retry.backoff(attempt);
+                warn!(
+                    "Request failed({}), retrying in {:?}",
+                    match &result {
+                        Ok(response) if response.status.is_success() => {
+                            "incomplete JSON body".to_string()
+                        }
+                        Ok(response) => response.status.to_string(),
+                        Err(e) => e.to_string(),
+                    },
+                    delay
+                );
+                tokio::time::sleep(delay).await;
+            }
+            attempt += 1;
+        };
+        Ok(response)
+    }
+}
+
+/// 전송 결과를 CircuitBreaker에 기록
+/// 서버 에러(5xx)와 네트워크 에러(timeout, 연결 실패 등)만 실패로 기록
+/// 그 외 응답(2xx, 4xx)은 서버가 응답했으므로 성공으로 기록하고, 전송 전 에러(mock 미등록 등)는 기록하지 않음
+pub(crate) fn record_result(
+    circuit_breaker: &CircuitBreaker,
+    result: &Result<HttpResponse, Error>,
+) {
+    match result {
+        Ok(response) if response.status.is_server_error() => circuit_breaker.record_failure(),
+        Ok(_) => circuit_breaker.record_success(),
+        Err(Error::Network(..)) => circuit_breaker.record_failure(),
+        Err(_) => {}
+    }
+}
+
+/// 요청마다 새로 만드는 거래고유번호(32자리: KST 시각 17자리 + 일련번호 15자리)
+fn next_gt_uid() -> String {
+    static SEQ: AtomicU64 = AtomicU64::new(0);
+    format!(
+        "{}{:015}",
+        crate::types::Time::now().inner().format("%Y%m%d%H%M%S%3f"),
+        SEQ.fetch_add(1, Ordering::Relaxed) % 1_000_000_000_000_000
+    )
+}
+
+/// 응답 헤더 값
+fn header(response: &HttpResponse, name: &str) -> Option<String> {
+    response
+        .headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_string())
+}
+
+/// 응답 본문 역직렬화
+/// rt_cd가 "0"이 아니면 응답 구조체로 변환하지 않고 Error::from_api로 반환하며, Error::Api에는 응답 헤더의 gt_uid를 담음
+/// 역직렬화 실패는 tr_id와 함께 Error::Decode로 반환
+fn decode<T: DeserializeOwned + RawBody>(
+    body: String,
+    tr_id: &str,
+    gt_uid: Option<String>,
+) -> Result<T, Error> {
+    let decode_error = |source| Error::Decode {
+        tr_id: tr_id.to_string(),
+        source,
+        body: body_snippet(&body),
+    };
+    let value: serde_json::Value = serde_json::from_str(&body).map_err(decode_error)?;
+    if let Some(error) = api_error(&value) {
+        return Err(error.with_gt_uid(gt_uid));
+    }
+    let mut response: T = serde_json::from_value(value).map_err(decode_error)?;
+    response.set_raw(body);
+    Ok(response)
+}
+
+/// rt_cd가 "0"이 아닌 응답의 KIS 에러(rt_cd가 없거나 "0"이면 None)
+pub(crate) fn api_error(value: &serde_json::Value) -> Option<Error> {
+    let rt_cd = value.get("rt_cd").and_then(|v| v.as_str())?;
+    if rt_cd == "0" {
+        return None;
+    }
+    let field = |key: &str| {
+        value
+            .get(key)
+            .and_then(|v| v.as_str())
+            .unwrap_or_default()
+            .to_string()
+    };
+    Some(Error::from_api(
+        rt_cd.to_string(),
+        field("msg_cd"),
+        field("msg1"),
+    ))
+}
+
+/// Error::Decode에 담는 응답 본문 길이(문자 수)
+const BODY_SNIPPET_LEN: usize = 200;
+
+/// Error::Decode의 응답 본문에서 값을 가리는 JSON key
+const SNIPPET_REDACTED_KEYS: [&str; 5] = [
+    "access_token",
+    "approval_key",
+    "appkey",
+    "appsecret",
+    "cano",
+];
+
+/// 응답 본문 앞부분. 잘린 JSON일 수 있으므로 문자열로 찾아 민감한 key의 문자열 값을 ***로 바꿈
+pub(crate) fn body_snippet(body: &str) -> String {
+    let mut snippet: String = body.chars().take(BODY_SNIPPET_LEN).collect();
+    for key in SNIPPET_REDACTED_KEYS {
+        let pattern = format!("\"{}\"", key);
+        let mut from = 0;
+        while let Some(pos) = snippet[from..].to_ascii_lowercase().find(&pattern) {
+            let key_end = from + pos + pattern.len();
+            let after = &snippet[key_end..];
+            let value_start = key_end + after.len()
+                - after
+                    .trim_start_matches(|c: char| c == ':' || c.is_whitespace())
+                    .len();
+            from = key_end;
+            if snippet[value_start..].starts_with('"') {
+                let value_end = snippet[value_start + 1..]
+                    .find('"')
+                    .map_or(snippet.len(), |i| value_start + 1 + i);
+                snippet.replace_range(value_start + 1..value_end, "***");
+                from = value_start + 4;
+            }
+        }
+    }
+    if body.chars().count() > BODY_SNIPPET_LEN {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
+/// 응답 본문이 중간에 끊겼거나 JSON이 아님(KIS 과부하 시 잘린 응답 등)
+fn is_incomplete_json(body: &str) -> bool {
+    serde_json::from_str::<serde::de::IgnoredAny>(body).is_err_and(|e| e.is_eof() || e.is_syntax())
+}
+
+/// 요청별 tracing span(tr_id, URL, HTTP 상태, rt_cd, msg1)
+/// 인증 헤더(Authorization, appkey, appsecret)는 기록하지 않으며 URL의 민감한 parameter는 가림
+#[cfg(feature = "tracing")]
+mod trace {
+    use crate::transport::HttpResponse;
+    use crate::Error;
+    use std::future::Future;
+    use tracing::field::Empty;
+    use tracing::Instrument;
+    use url::Url;
+
+    const REDACTED_PARAMS: [&str; 5] =
+        ["appkey", "appsecret", "secretkey", "token", "approval_key"];
+
+    pub(super) async fn instrument<F>(
+        tr_id: &str,
+        method: reqwest::Method,
+        url: Url,
+        request: F,
+    ) -> Result<HttpResponse, Error>
+    where
+        F: Future<Output = Result<HttpResponse, Error>>,
+    {
+        let span = tracing::debug_span!(
+            "kis_request",
+            tr_id,
+            method = %method,
+            url = %sanitize(&url),
+            status = Empty,
+            rt_cd = Empty,
+            msg1 = Empty,
+        );
+        let result = request.instrument(span.clone()).await;
+        span.in_scope(|| match &result {
+            Ok(response) => {
+                let (rt_cd, msg1) = result_code(&response.body);
+                span.record("status", response.status.as_u16());
+                span.record("rt_cd", rt_cd.as_str());
+                span.record("msg1", msg1.as_str());
+                if !response.status.is_success() || (!rt_cd.is_empty() && rt_cd != "0") {
+                    tracing::warn!(status = response.status.as_u16(), %rt_cd, %msg1, "KIS request failed");
+                } else {
+                    tracing::debug!(status = response.status.as_u16(), %rt_cd, "KIS request succeeded");
+                }
+            }
+            Err(e) => tracing::warn!(error = %e, "KIS request error"),
+        });
+        result
+    }
+
+    /// 응답 본문의 rt_cd, msg1(없으면 빈 문자열)
+    fn result_code(body: &str) -> (String, String) {
+        let value: serde_json::Value = serde_json::from_str(body).unwrap_or_default();
+        let field = |key: &str| {
+            value
+                .get(key)
+                .and_then(|v| v.as_str())
+                .unwrap_or_default()
+                .to_string()
+        };
+        (field("rt_cd"), field("msg1"))
+    }
+
+    fn sanitize(url: &Url) -> Url {
+        let mut url = url.clone();
+        if url.query().is_none() {
+            return url;
+        }
+        let pairs: Vec<(String, String)> = url
+            .query_pairs()
+            .map(|(k, v)| {
+                let v = if REDACTED_PARAMS.contains(&k.to_ascii_lowercase().as_str()) {
+                    "***".to_string()
+                } else {
+                    v.to_string()
+                };
+                (k.to_string(), v)
+            })
+            .collect();
+        url.query_pairs_mut().clear().extend_pairs(pairs);
+        url
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{MockResponse, MockTransport};
+    use crate::types::response::stock::quote::AskingPriceResponse;
+    use crate::CircuitState;
+
+    const PATH: &str = "/uapi/domestic-stock/v1/quotations/inquire-asking-price-exp-ccn";
+
+    fn mock_rest(mock: &Arc<MockTransport>) -> RestClient {
+        let client = reqwest::Client::new();
+        let mut auth = auth::Auth::new(&client, Environment::Real, "appkey", "appsecret");
+        auth.set_token("token".to_string());
+        let mut rest = RestClient::new(&client, Environment::Real, auth);
+        rest.set_transport(mock.clone());
+        rest
+    }
+
+    fn error_body(msg_cd: &str, msg1: &str) -> String {
+        format!(r#"{{"rt_cd":"1","msg_cd":"{}","msg1":"{}"}}"#, msg_cd, msg1)
+    }
+
+    async fn asking_price(rest: &RestClient) -> Result<AskingPriceResponse, Error> {
+        let url = rest.url(PATH, [("FID_INPUT_ISCD", "005930")])?;
+        rest.get(url, TrId::AskingPrice).await
+    }
+
+    async fn error_for(msg_cd: &str, msg1: &str) -> Error {
+        let mock = Arc::new(MockTransport::new().on_tr_id(
+            TrId::AskingPrice,
+            MockResponse::json(error_body(msg_cd, msg1)),
+        ));
+        asking_price(&mock_rest(&mock)).await.unwrap_err()
+    }
+
+    #[tokio::test]
+    async fn error_shaped_json_maps_known_msg_cd() {
+        assert!(matches!(
+            error_for("EGW00201", "초당 거래건수를 초과하였습니다.").await,
+            Error::RateLimited(_)
+        ));
+        assert!(matches!(
+            error_for("EGW00123", "기간이 만료된 token 입니다.").await,
+            Error::TokenExpired(_)
+        ));
+        assert!(matches!(
+            error_for("APBK0013", "장운영시간이 아닙니다.").await,
+            Error::MarketClosed(_)
+        ));
+        match error_for(
+            "EGW00133",
+            "접근토큰 발급 잠시 후 다시 시도하세요(1분당 1회)",
+        )
+        .await
+        {
+            Error::TokenCooldown { retry_after } => {
+                assert_eq!(retry_after, Duration::from_secs(60))
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn error_shaped_json_is_not_decoded_as_success() {
+        match error_for("APBK0919", "조회할 자료가 없습니다.").await {
+            Error::Api {
+                rt_cd,
+                msg_cd,
+                msg1,
+                ..
+            } => {
+                assert_eq!(rt_cd, "1");
+                assert_eq!(msg_cd, "APBK0919");
+                assert_eq!(msg1, "조회할 자료가 없습니다.");
+            }
+            e => panic!("unexpected error: {:?}", e),
+        }
+    }
+
+    #[tokio::test]
+    async fn get_raw_returns_structured_error() {
+        let mock = Arc::new(MockTransport::new().on_tr_id(
+            TrId::AskingPrice,
+            MockResponse::json(error_body("APBK0013", "장운영시간이 아닙니다.")),
+        ));
+        let rest = mock_rest(&mock);
+        let url = rest.url(PATH, [("FID_INPUT_ISCD", "005930")]).unwrap();
+        let error = rest.get_raw(url, "FHKST01010200", None).await.unwrap_err();
+        assert!(matches!(error, Error::MarketClosed(_)));
+    }
+
+    const ASKING_PRICE: &str = r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":"정상처리 되었습니다."}"#;
+
+    fn fast_retry() -> RetryConfig {
+        RetryConfig {
+            max_retries: 3,
+            base_delay: Duration::from_millis(1),
+            timeout: Duration::from_secs(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn retry_succeeds_after_two_server_errors() {
+        let failure = MockResponse::json("").with_status(StatusCode::INTERNAL_SERVER_ERROR);
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_tr_id(TrId::AskingPrice, failure.clone())
+                .on_tr_id(TrId::AskingPrice, failure)
+                .on_tr_id(TrId::AskingPrice, MockResponse::json(ASKING_PRICE)),
+        );
+        let mut rest = mock_rest(&mock);
+        rest.set_retry(fast_retry());
+        assert!(asking_price(&rest).await.is_ok());
+        assert_eq!(mock.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn retry_gives_up_after_max_retries() {
+        let mock = Arc::new(MockTransport::new().on_tr_id(
+            TrId::AskingPrice,
+            MockResponse::json("").with_status(StatusCode::TOO_MANY_REQUESTS),
+        ));
+        let mut rest = mock_rest(&mock);
+        rest.set_retry(fast_retry());
+        assert!(asking_price(&rest).await.is_err());
+        assert_eq!(mock.requests().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn no_retry_without_retry_config() {
+        let mock = Arc::new(MockTransport::new().on_tr_id(
+            TrId::AskingPrice,
+            MockResponse::json("").with_status(StatusCode::INTERNAL_SERVER_ERROR),
+        ));
+        assert!(asking_price(&mock_rest(&mock)).await.is_err());
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn post_is_not_retried() {
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_path("/uapi/hashkey", MockResponse::json(r#"{"HASH":"hash"}"#))
+                .on_tr_id(
+                    TrId::RealStockCashBidOrder,
+                    MockResponse::json("").with_status(StatusCode::INTERNAL_SERVER_ERROR),
+                ),
+        );
+        let mut rest = mock_rest(&mock);
+        rest.set_retry(fast_retry());
+        let result: Result<serde_json::Value, Error> = rest
+            .post(
+                "/uapi/domestic-stock/v1/trading/order-cash",
+                TrId::RealStockCashBidOrder,
+                &serde_json::json!({"PDNO": "005930"}),
+            )
+            .await;
+        assert!(result.is_err());
+        let orders = mock
+            .requests()
+            .into_iter()
+            .filter(|r| r.url.ends_with("/order-cash"))
+            .count();
+        assert_eq!(orders, 1);
+    }
+
+    /// ASKING_PRICE가 중간에 끊긴 응답
+    const TRUNCATED: &str = r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":"정상"#;
+
+    #[tokio::test]
+    async fn truncated_body_is_retried_then_succeeds() {
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_tr_id(TrId::AskingPrice, MockResponse::json(TRUNCATED))
+                .on_tr_id(TrId::AskingPrice, MockResponse::json(ASKING_PRICE)),
+        );
+        let mut rest = mock_rest(&mock);
+        rest.set_retry(fast_retry());
+        assert!(asking_price(&rest).await.is_ok());
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn truncated_body_without_retry_is_decode_error() {
+        let mock = Arc::new(MockTransport::new().on_tr_id(
+            TrId::AskingPrice,
+            MockResponse::json(r#"{"rt_cd":"0","approval_key":"secret-key","msg1":"정"#),
+        ));
+        match asking_price(&mock_rest(&mock)).await {
+            Err(Error::Decode {
+                tr_id,
+                source,
+                body,
+            }) => {
+                assert_eq!(tr_id, "FHKST01010200");
+                assert!(source.is_eof());
+                assert_eq!(body, r#"{"rt_cd":"0","approval_key":"***","msg1":"정"#);
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn complete_json_of_wrong_shape_is_not_retried() {
+        let mock = Arc::new(MockTransport::new().on_tr_id(
+            TrId::AskingPrice,
+            MockResponse::json(r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":7}"#),
+        ));
+        let mut rest = mock_rest(&mock);
+        rest.set_retry(fast_retry());
+        assert!(matches!(
+            asking_price(&rest).await,
+            Err(Error::Decode { .. })
+        ));
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[test]
+    fn body_snippet_is_truncated() {
+        let body = format!(r#"{{"msg1":"{}"}}"#, "가".repeat(BODY_SNIPPET_LEN));
+        let snippet = body_snippet(&body);
+        assert!(snippet.ends_with("..."));
+        assert_eq!(snippet.chars().count(), BODY_SNIPPET_LEN + 3);
+        assert_eq!(body_snippet("{}"), "{}");
+    }
+
+    fn asking_price_mock() -> Arc<MockTransport> {
+        Arc::new(MockTransport::new().on_tr_id(TrId::AskingPrice, MockResponse::json(ASKING_PRICE)))
+    }
+
+    fn sent_header(mock: &MockTransport, name: &str) -> Option<String> {
+        mock.requests()
+            .last()?
+            .headers
+            .get(name)
+            .map(|v| v.to_str().unwrap().to_string())
+    }
+
+    #[tokio::test]
+    async fn personal_custtype_header() {
+        let mock = asking_price_mock();
+        asking_price(&mock_rest(&mock)).await.unwrap();
+        assert_eq!(sent_header(&mock, "custtype").as_deref(), Some("P"));
+        assert_eq!(sent_header(&mock, "personalseckey"), None);
+        assert_eq!(sent_header(&mock, "gt_uid"), None);
+    }
+
+    #[tokio::test]
+    async fn corporate_custtype_header() {
+        let mock = asking_price_mock();
+        let mut rest = mock_rest(&mock);
+        rest.set_corporate(CorporateHeaders {
+            personalseckey: Some("seckey".to_string()),
+            phone_number: Some("01012345678".to_string()),
+            ..Default::default()
+        });
+        asking_price(&rest).await.unwrap();
+        assert_eq!(sent_header(&mock, "custtype").as_deref(), Some("B"));
+        assert_eq!(
+            sent_header(&mock, "personalseckey").as_deref(),
+            Some("seckey")
+        );
+        assert_eq!(
+            sent_header(&mock, "phone_number").as_deref(),
+            Some("01012345678")
+        );
+        assert_eq!(sent_header(&mock, "mac_address"), None);
+        assert_eq!(sent_header(&mock, "ip_addr"), None);
+    }
+
+    #[tokio::test]
+    async fn gt_uid_header_round_trips_into_error() {
+        let mock = Arc::new(
+            MockTransport::new().on_tr_id(
+                TrId::AskingPrice,
+                MockResponse::json(error_body("APBK0919", "조회할 자료가 없습니다."))
+                    .with_header("gt_uid", "GTUID0000000001"),
+            ),
+        );
+        let error = asking_price(&mock_rest(&mock)).await.unwrap_err();
+        assert!(matches!(error, Error::Api { .. }));
+        assert_eq!(error.gt_uid(), Some("GTUID0000000001"));
+        assert!(error.to_string().contains("gt_uid=GTUID0000000001"));
+    }
+
+    #[tokio::test]
+    async fn gt_uid_header_in_envelope() {
+        let mock = Arc::new(MockTransport::new().on_tr_id(
+            TrId::AskingPrice,
+            MockResponse::json(ASKING_PRICE).with_header("gt_uid", "GTUID0000000002"),
+        ));
+        let rest = mock_rest(&mock);
+        let url = rest.url(PATH, [("FID_INPUT_ISCD", "005930")]).unwrap();
+        let envelope: Envelope<AskingPriceResponse> =
+            rest.get_with_meta(url, TrId::AskingPrice).await.unwrap();
+        assert_eq!(envelope.gt_uid.as_deref(), Some("GTUID0000000002"));
+    }
+
+    #[tokio::test]
+    async fn client_gt_uid_sent_per_request() {
+        let mock = asking_price_mock();
+        let mut rest = mock_rest(&mock);
+        rest.set_corporate(CorporateHeaders {
+            gt_uid: true,
+            ..Default::default()
+        });
+        asking_price(&rest).await.unwrap();
+        asking_price(&rest).await.unwrap();
+        let sent: Vec<String> = mock
+            .requests()
+            .iter()
+            .map(|r| r.headers["gt_uid"].to_str().unwrap().to_string())
+            .collect();
+        assert_eq!(sent[0].len(), 32);
+        assert_ne!(sent[0], sent[1]);
+    }
+
+    #[test]
+    fn header_overrides_last_value_wins() {
+        let overrides = HeaderOverrides::new()
+            .header("TR_CONT", "N")
+            .tr_cont("M")
+            .custtype(CustomerType::Business);
+        assert_eq!(overrides.get("tr_cont"), Some("M"));
+        assert_eq!(overrides.get("CUSTTYPE"), Some("B"));
+        assert_eq!(overrides.get("mac_address"), None);
+    }
+
+    #[tokio::test]
+    async fn header_overrides_merge_with_defaults() {
+        let mock = asking_price_mock();
+        let mut rest = mock_rest(&mock);
+        rest.set_corporate(CorporateHeaders {
+            personalseckey: Some("seckey".to_string()),
+            mac_address: Some("00-00-00-00-00-00".to_string()),
+            ..Default::default()
+        });
+        rest.set_header_overrides(
+            HeaderOverrides::new()
+                .header("mac_address", "AA-BB-CC-DD-EE-FF")
+                .header("phone_number", "01011112222"),
+        );
+        let url = rest.url(PATH, [("FID_INPUT_ISCD", "005930")]).unwrap();
+        let call = HeaderOverrides::new()
+            .custtype(CustomerType::Personal)
+            .header("phone_number", "01033334444")
+            .tr_cont("N");
+        rest.get_raw(url, "FHKST01010200", Some(&call))
+            .await
+            .unwrap();
+
+        let header = |name| sent_header(&mock, name);
+        // 기본 헤더는 그대로 유지
+        assert_eq!(header("tr_id").as_deref(), Some("FHKST01010200"));
+        assert_eq!(header("appkey").as_deref(), Some("appkey"));
+        assert_eq!(header("authorization").as_deref(), Some("Bearer token"));
+        assert_eq!(header("personalseckey").as_deref(), Some("seckey"));
+        // 서비스 HeaderOverrides가 법인 헤더를 덮어쓰고, 요청별 HeaderOverrides가 다시 덮어씀
+        assert_eq!(header("mac_address").as_deref(), Some("AA-BB-CC-DD-EE-FF"));
+        assert_eq!(header("phone_number").as_deref(), Some("01033334444"));
+        assert_eq!(header("custtype").as_deref(), Some("P"));
+        assert_eq!(header("tr_cont").as_deref(), Some("N"));
+        let requests = mock.requests();
+        assert_eq!(requests[0].headers.get_all("custtype").iter().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn header_overrides_reject_invalid_header() {
+        let mock = asking_price_mock();
+        let rest = mock_rest(&mock);
+        let url = rest.url(PATH, [("FID_INPUT_ISCD", "005930")]).unwrap();
+        let call = HeaderOverrides::new().header("bad header", "value");
+        let result = rest.get_raw(url, "FHKST01010200", Some(&call)).await;
+        assert!(matches!(result, Err(Error::InvalidParam { .. })));
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn warm_up_sends_head_to_rest_host() {
+        let mock = Arc::new(MockTransport::new().on_path("/", MockResponse::json("")));
+        let rest = mock_rest(&mock);
+        rest.warm_up().await.unwrap();
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "HEAD");
+        assert!(requests[0]
+            .url
+            .starts_with("https://openapi.koreainvestment.com:9443"));
+        assert!(requests[0].headers.get("authorization").is_none());
+    }
+
+    #[tokio::test]
+    async fn circuit_breaker_counts_only_server_errors() {
+        let client_error = MockResponse::json(error_body("EGW00001", "bad request"))
+            .with_status(StatusCode::BAD_REQUEST);
+        let server_error = MockResponse::json("").with_status(StatusCode::INTERNAL_SERVER_ERROR);
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_tr_id(TrId::AskingPrice, client_error.clone())
+                .on_tr_id(TrId::AskingPrice, client_error.clone())
+                .on_tr_id(TrId::AskingPrice, client_error)
+                .on_tr_id(TrId::AskingPrice, server_error),
+        );
+        let mut rest = mock_rest(&mock);
+        let circuit_breaker = CircuitBreaker::new(2, Duration::from_secs(60));
+        rest.set_circuit_breaker(circuit_breaker.clone());
+        for _ in 0..3 {
+            assert!(asking_price(&rest).await.is_err());
+        }
+        assert_eq!(circuit_breaker.state(), CircuitState::Closed);
+        for _ in 0..2 {
+            assert!(asking_price(&rest).await.is_err());
+        }
+        assert_eq!(circuit_breaker.state(), CircuitState::Open);
+        assert!(matches!(
+            asking_price(&rest).await,
+            Err(Error::CircuitOpen { .. })
+        ));
+        assert_eq!(mock.requests().len(), 5);
+    }
+}
@@ -0,0 +1,76 @@
+use crate::client::RestClient;
+use crate::types::request::derivatives::order::body;
+use crate::types::response::derivatives::order::FuturesOrderResponse;
+use crate::types::{Account, Direction, Environment, Price, Quantity, TrId};
+use crate::{auth, Error};
+
+/// 선물옵션 계좌의 계좌상품코드
+const FUTURES_ACNT_PRDT_CD: &str = "03";
+
+#[derive(Clone)]
+pub struct FuturesOrder {
+    rest: RestClient,
+    account: Account,
+}
+
+impl FuturesOrder {
+    /// 국내선물옵션 주문에 관한 API
+    /// [국내선물옵션주문](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-futureoption)
+    /// account는 선물옵션 계좌(계좌상품코드 03)여야 함
+    pub fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+        account: Account,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            rest: RestClient::new(client, environment, auth),
+            account,
+        })
+    }
+
+    crate::client::rest_builders!("요청 timeout과 재시도 정책 설정(주문은 재시도하지 않음)");
+
+    /// 선물옵션 주문[v1_국내선물-001]
+    /// 주간 주문. price가 0이면 시장가, 아니면 지정가
+    /// 계좌상품코드가 03이 아니면 요청을 보내지 않고 Error::WrongAccountProduct 반환
+    pub async fn order(
+        &self,
+        order_direction: Direction,
+        code: &str,
+        qty: Quantity,
+        price: Price,
+    ) -> Result<FuturesOrderResponse, Error> {
+        if self.account.product_code() != FUTURES_ACNT_PRDT_CD {
+            return Err(Error::WrongAccountProduct(
+                self.account.product_code().to_string(),
+                FUTURES_ACNT_PRDT_CD,
+            ));
+        }
+        if qty == Quantity::from(0) {
+            return Err(Error::InvalidParam {
+                field: "qty",
+                reason: "must be non-zero",
+            });
+        }
+        let request = body::Order::new(
+            self.account.cano().to_string(),
+            self.account.product_code().to_string(),
+            order_direction,
+            code.to_string(),
+            qty,
+            price,
+        );
+        let tr_id = match self.rest.environment() {
+            Environment::Real => TrId::RealFuturesOrder,
+            Environment::Virtual => TrId::VirtualFuturesOrder,
+        };
+        self.rest
+            .post(
+                "/uapi/domestic-futureoption/v1/trading/order",
+                tr_id,
+                &request,
+            )
+            .await
+    }
+}
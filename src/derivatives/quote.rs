@@ -0,0 +1,40 @@
+use crate::client::RestClient;
+use crate::types::request::derivatives::quote::FuturesPriceParameter;
+use crate::types::response::derivatives::quote::FuturesPriceResponse;
+use crate::types::{Account, Environment, TrId};
+use crate::{auth, Error};
+
+#[derive(Clone)]
+pub struct FuturesQuote {
+    rest: RestClient,
+    #[allow(dead_code)]
+    account: Account,
+}
+
+impl FuturesQuote {
+    /// 국내선물옵션 시세에 관한 API
+    /// [국내선물옵션시세](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-futureoption-quotations)
+    pub fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+        account: Account,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            rest: RestClient::new(client, environment, auth),
+            account,
+        })
+    }
+
+    crate::client::rest_builders!();
+
+    /// 선물옵션 시세[v1_국내선물-006]
+    /// 지수선물 현재가, 미결제약정, 이론가, 괴리율
+    pub async fn current_price(&self, code: &str) -> Result<FuturesPriceResponse, Error> {
+        let tr_id = TrId::FuturesPrice;
+        let param = FuturesPriceParameter::new("F", code.to_string());
+        let path = "/uapi/domestic-futureoption/v1/quotations/inquire-price";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+}
@@ -1,9 +1,34 @@
-mod auth;
-mod stock;
+pub mod auth;
+#[cfg(feature = "decimal")]
+pub mod bond;
+pub mod cache;
+mod circuit_breaker;
+mod client;
+pub mod derivatives;
+#[cfg(feature = "decimal")]
+pub mod overseas;
+pub mod paging;
+/// 자주 사용하는 서비스, 요청 코드, 응답 타입 re-export(`use korea_investment_api::prelude::*;`)
+pub mod prelude;
+/// 응답 전체를 역직렬화하지 않고 필요한 값만 읽는 경량 파서
+#[cfg(feature = "quick")]
+pub mod quick;
+mod rate_limiter;
+pub mod stock;
+pub mod transport;
 pub mod types;
-pub use types::{Account, Environment};
+pub use cache::{Cache, FileCache, MemoryCache};
+pub use circuit_breaker::{CircuitBreaker, CircuitState};
+pub use client::{ClientBuilder, Envelope, HeaderOverrides, RetryConfig};
+pub use paging::PagedStream;
+pub use rate_limiter::RateLimiter;
+#[cfg(feature = "decimal")]
+pub use rust_decimal::Decimal;
+pub use transport::Transport;
+pub use types::{Account, Endpoints, Environment};
 
 pub(crate) mod util;
+pub(crate) mod validate;
 
 #[macro_use]
 extern crate log;
@@ -13,8 +38,16 @@ pub const BUF_SIZE: usize = 4096;
 pub struct KoreaInvestmentApi {
     pub auth: auth::Auth,
     pub order: stock::order::Korea,
+    pub balance: stock::account::Balance,
     pub quote: stock::quote::Quote,
+    #[cfg(feature = "decimal")]
+    pub overseas_quote: overseas::quote::OverseasQuote,
+    #[cfg(feature = "decimal")]
+    pub overseas_order: overseas::order::OverseasOrder,
+    #[cfg(feature = "decimal")]
+    pub overseas_balance: overseas::account::OverseasBalance,
     pub k_data: stock::data::KoreaStockData,
+    pub circuit_breaker: CircuitBreaker,
 }
 
 impl KoreaInvestmentApi {
@@ -27,36 +60,238 @@ impl KoreaInvestmentApi {
         token: Option<String>,
         approval_key: Option<String>,
     ) -> Result<KoreaInvestmentApi, Error> {
-        let client = reqwest::Client::new();
-        let mut auth = auth::Auth::new(&client, acc.clone(), appkey, appsecret);
-        info!(
-            "Authorizing: acc={}, appkey={}, appsecret={}",
-            &acc, &appkey, &appsecret,
-        );
+        let endpoints = Endpoints::for_environment(&acc);
+        Self::new_with_endpoints(
+            acc,
+            endpoints,
+            appkey,
+            appsecret,
+            account,
+            hts_id,
+            token,
+            approval_key,
+        )
+        .await
+    }
+
+    /// 투자환경 기본 주소 대신 endpoints로 접속(로컬 mock 서버에 대한 통합 테스트 등)
+    /// token 발급, REST 요청, 실시간 시세 모두 endpoints를 사용함
+    #[allow(clippy::too_many_arguments)]
+    pub async fn new_with_endpoints(
+        acc: types::Environment,
+        endpoints: Endpoints,
+        appkey: &str,
+        appsecret: &str,
+        account: types::Account,
+        hts_id: &str,
+        token: Option<String>,
+        approval_key: Option<String>,
+    ) -> Result<KoreaInvestmentApi, Error> {
+        let client = ClientBuilder::new().build()?;
+        let mut auth = auth::Auth::new(&client, acc, appkey, appsecret).with_endpoints(endpoints);
         if let Some(token) = token {
             auth.set_token(token);
-        } else {
-            auth.create_token().await?;
         }
-        debug!("token: {:?}", auth.get_token());
         if let Some(approval_key) = approval_key {
             auth.set_approval_key(approval_key);
-        } else {
-            auth.create_approval_key().await?;
         }
-        debug!("approval_key: {:?}", auth.get_approval_key());
-        let order = stock::order::Korea::new(&client, acc.clone(), auth.clone(), account.clone())?;
-        let quote = stock::quote::Quote::new(&client, acc.clone(), auth.clone(), account.clone())?;
+        Self::from_auth(&client, auth, account, hts_id, CircuitBreaker::default()).await
+    }
+
+    /// 설정 파일(Config)로 생성
+    /// token_cache_path가 있으면 캐시 파일의 유효한 token을 사용하고, 없거나 만료되었을 때만 새로 발급받아 저장
+    /// 모든 서비스가 `[circuit_breaker]` 설정으로 만든 CircuitBreaker를 공유함
+    pub async fn from_config(config: &types::config::Config) -> Result<KoreaInvestmentApi, Error> {
+        let client = ClientBuilder::new().build()?;
+        let mut auth = auth::Auth::new(
+            &client,
+            config.environment().clone(),
+            config.app_key(),
+            config.app_secret(),
+        );
+        if let Some(path) = config.token_cache_path() {
+            auth = auth.with_token_cache(path);
+        }
+        let account = Account::new(config.cano(), config.acnt_prdt_cd())?;
+        Self::from_auth(
+            &client,
+            auth,
+            account,
+            config.hts_id(),
+            CircuitBreaker::from(config.circuit_breaker()),
+        )
+        .await
+    }
+
+    /// token과 approval_key가 없으면 발급받고 서비스 구조체를 생성
+    /// token은 ensure_valid_token으로 발급(token cache 확인, 재발급 제한 시 한 번 기다렸다가 재시도)
+    async fn from_auth(
+        client: &reqwest::Client,
+        auth: auth::Auth,
+        account: types::Account,
+        hts_id: &str,
+        circuit_breaker: CircuitBreaker,
+    ) -> Result<KoreaInvestmentApi, Error> {
+        let client = client.clone();
+        let acc = auth.environment().clone();
+        // appsecret, 토큰, 웹소켓 접속키는 로그에 남기지 않음
+        info!("Authorizing: acc={}, appkey={}", &acc, auth.get_appkey());
+        auth.ensure_valid_token().await?;
+        debug!("token issued: {}", auth.get_token().is_some());
+        auth.approval_key().await?;
+        debug!("approval_key issued");
+        let rate_limiter = RateLimiter::for_environment(&acc);
+        let order = stock::order::Korea::new(&client, acc.clone(), auth.clone(), account.clone())?
+            .with_rate_limiter(rate_limiter.clone())
+            .with_circuit_breaker(circuit_breaker.clone());
+        let balance =
+            stock::account::Balance::new(&client, acc.clone(), auth.clone(), account.clone())?
+                .with_rate_limiter(rate_limiter.clone())
+                .with_circuit_breaker(circuit_breaker.clone());
+        let quote = stock::quote::Quote::new(&client, acc.clone(), auth.clone(), account.clone())?
+            .with_rate_limiter(rate_limiter.clone())
+            .with_circuit_breaker(circuit_breaker.clone());
+        #[cfg(feature = "decimal")]
+        let overseas_quote = overseas::quote::OverseasQuote::new(
+            &client,
+            acc.clone(),
+            auth.clone(),
+            account.clone(),
+        )?
+        .with_rate_limiter(rate_limiter.clone())
+        .with_circuit_breaker(circuit_breaker.clone());
+        #[cfg(feature = "decimal")]
+        let overseas_order = overseas::order::OverseasOrder::new(
+            &client,
+            acc.clone(),
+            auth.clone(),
+            account.clone(),
+        )?
+        .with_rate_limiter(rate_limiter.clone())
+        .with_circuit_breaker(circuit_breaker.clone());
+        #[cfg(feature = "decimal")]
+        let overseas_balance = overseas::account::OverseasBalance::new(
+            &client,
+            acc.clone(),
+            auth.clone(),
+            account.clone(),
+        )?
+        .with_rate_limiter(rate_limiter)
+        .with_circuit_breaker(circuit_breaker.clone());
         let k_data =
             stock::data::KoreaStockData::new(acc.clone(), auth.clone(), account.clone(), hts_id)?;
         info!("API Ready");
         Ok(Self {
             auth,
             order,
+            balance,
             quote,
+            #[cfg(feature = "decimal")]
+            overseas_quote,
+            #[cfg(feature = "decimal")]
+            overseas_order,
+            #[cfg(feature = "decimal")]
+            overseas_balance,
             k_data,
+            circuit_breaker,
+        })
+    }
+
+    /// 접속 상태 확인
+    /// 코스피 현재지수를 조회하여 KIS 응답 시간을 측정함(주문, token 발급 등 부수 효과 없음)
+    /// 저장된 token이 없거나 만료되었으면 요청하지 않고 Error::AuthInitFailed,
+    /// 서버가 token을 거부하면 Error::TokenExpired, 서버에 연결하지 못하면 Error::Network 반환
+    pub async fn health_check(&self) -> Result<HealthStatus, Error> {
+        if !self.auth.has_valid_token() {
+            return Err(Error::AuthInitFailed("token"));
+        }
+        let started = std::time::Instant::now();
+        self.quote.index_price(types::IndexCode::Kospi).await?;
+        Ok(HealthStatus {
+            environment: self.auth.environment().clone(),
+            latency: started.elapsed(),
+            token_expires_at: self.auth.token_expires_at(),
         })
     }
+
+    /// 첫 요청 전에 REST 서버에 연결하고 websocket 서버 주소의 DNS를 미리 조회
+    /// 모든 서비스가 같은 connection pool을 사용하므로, 이후 첫 요청(주문 등)이
+    /// DNS 조회와 TLS handshake 시간(보통 수십~수백 ms)을 기다리지 않음
+    /// 실시간 시세 websocket은 연결을 재사용하지 않으므로 DNS 조회만 미리 수행
+    /// token과 요청 수 제한을 사용하지 않으며, 서버에 연결하지 못하면 Error::Network
+    pub async fn warm_up(&self) -> Result<(), Error> {
+        self.order.warm_up().await?;
+        let url = url::Url::parse(&self.auth.endpoints().websocket)?;
+        if let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) {
+            let host = host.to_string();
+            tokio::task::spawn_blocking(move || {
+                std::net::ToSocketAddrs::to_socket_addrs(&(host.as_str(), port)).map(|_| ())
+            })
+            .await
+            .map_err(std::io::Error::other)??;
+        }
+        Ok(())
+    }
+
+    /// 인증(토큰, 웹소켓 접속키). 모든 서비스가 같은 토큰을 공유함
+    pub fn auth(&self) -> &auth::Auth {
+        &self.auth
+    }
+
+    /// 국내 주식 주문
+    pub fn order(&self) -> &stock::order::Korea {
+        &self.order
+    }
+
+    /// 국내 주식 계좌 조회
+    pub fn balance(&self) -> &stock::account::Balance {
+        &self.balance
+    }
+
+    /// 국내 주식 시세
+    pub fn quote(&self) -> &stock::quote::Quote {
+        &self.quote
+    }
+
+    /// 해외 주식 시세
+    #[cfg(feature = "decimal")]
+    pub fn overseas_quote(&self) -> &overseas::quote::OverseasQuote {
+        &self.overseas_quote
+    }
+
+    /// 해외 주식 주문
+    #[cfg(feature = "decimal")]
+    pub fn overseas_order(&self) -> &overseas::order::OverseasOrder {
+        &self.overseas_order
+    }
+
+    /// 해외 주식 계좌 조회
+    #[cfg(feature = "decimal")]
+    pub fn overseas_balance(&self) -> &overseas::account::OverseasBalance {
+        &self.overseas_balance
+    }
+
+    /// 모든 서비스가 공유하는 CircuitBreaker(5번 연속 실패 시 30초 차단)
+    pub fn circuit_breaker(&self) -> &CircuitBreaker {
+        &self.circuit_breaker
+    }
+
+    /// 실시간 시세
+    pub fn k_data(&mut self) -> &mut stock::data::KoreaStockData {
+        &mut self.k_data
+    }
+}
+
+/// health_check 결과
+/// 반환되었다면 token은 유효하고 KIS REST 서버에 접속할 수 있음
+#[derive(Clone, Debug, getset::Getters, getset::CopyGetters)]
+pub struct HealthStatus {
+    #[getset(get = "pub")]
+    environment: Environment, // 투자환경
+    #[getset(get_copy = "pub")]
+    latency: std::time::Duration, // 조회 요청의 응답 시간(rate limit 대기 포함)
+    #[getset(get_copy = "pub")]
+    token_expires_at: Option<std::time::SystemTime>, // token 만료 시각
 }
 
 #[derive(thiserror::Error, Debug)]
@@ -68,13 +303,15 @@ pub enum Error {
     WebSocketParseError(#[from] websocket::url::ParseError),
     #[error(transparent)]
     WebSocketNativeTlsError(#[from] websocket::native_tls::Error),
-    #[error(transparent)]
-    ReqwestError(#[from] reqwest::Error),
+    #[error("Network error ({0:?}): {1}")]
+    Network(NetworkKind, #[source] reqwest::Error),
     #[error(transparent)]
     Utf8Error(#[from] std::str::Utf8Error),
     #[error(transparent)]
     JsonError(#[from] json::JsonError),
     #[error(transparent)]
+    SerdeJsonError(#[from] serde_json::Error),
+    #[error(transparent)]
     ChronoError(#[from] chrono::ParseError),
     #[error(transparent)]
     ParseIntError(#[from] std::num::ParseIntError),
@@ -84,6 +321,10 @@ pub enum Error {
     Base64DecodeError(#[from] base64::DecodeError),
     #[error(transparent)]
     UrlParseError(#[from] url::ParseError),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    ParseCode(#[from] types::ParseCodeError),
 
     // custom
     #[error("Auth init failed - None value in {0}")]
@@ -94,8 +335,342 @@ pub enum Error {
     InvalidData,
     #[error("Wrong TrId: {0:?}. Expect {1}")]
     WrongTrId(crate::types::TrId, &'static str),
+    #[error("Invalid parameter {field}: {reason}")]
+    InvalidParam {
+        field: &'static str,
+        reason: &'static str,
+    },
+    #[error("Too many symbols: {0}. Expect at most {1} per request")]
+    TooManySymbols(usize, usize),
+    #[error("Invalid date range: {0}")]
+    InvalidDateRange(&'static str),
+    #[error("Invalid account number: {0}. Expect 8-digit CANO and 2-digit ACNT_PRDT_CD")]
+    InvalidAccount(String),
+    #[error("Unsupported market: {0:?}")]
+    UnsupportedMarket(crate::types::MarketCode),
+    #[error("Unsupported listed market: {0:?}")]
+    UnsupportedListedMarket(crate::types::ListedMarket),
+    #[error("Period {1} is not supported by {0:?}")]
+    UnsupportedPeriod(crate::types::TrId, crate::types::ChartPeriod),
+    #[error("Fractional quantity is not supported on {0:?}")]
+    FractionalNotSupported(crate::types::OverseasExchange),
+    #[error("Not supported in virtual environment: {0:?}")]
+    UnsupportedInVirtual(crate::types::TrId),
+    #[error("Not an ELW: {0}")]
+    NotAnElw(String),
+    #[error("Wrong account product code: {0}. Expect {1}")]
+    WrongAccountProduct(String, &'static str),
+    #[error("Credit trading is not enabled for this account: {0}")]
+    CreditNotEnabled(String),
+    #[error("Failed to decode response of {tr_id}: {source} (body: {body})")]
+    Decode {
+        tr_id: String,
+        #[source]
+        source: serde_json::Error,
+        body: String, // 응답 본문 앞부분(민감한 값은 가림)
+    },
+    #[error("KIS API error [{msg_cd}] {msg1} (rt_cd={rt_cd}, gt_uid={})", gt_uid.as_deref().unwrap_or("-"))]
+    Api {
+        rt_cd: String,
+        msg_cd: String,
+        msg1: String,
+        gt_uid: Option<String>, // 응답 헤더의 거래고유번호(KIS 문의 시 전달)
+    },
+    #[error("Rate limit exceeded: {0}")]
+    RateLimited(String),
+    #[error("Access token expired: {0}")]
+    TokenExpired(String),
+    #[error("Token issuance cooldown, retry after {retry_after:?}")]
+    TokenCooldown { retry_after: std::time::Duration },
+    #[error("Market closed: {0}")]
+    MarketClosed(String),
+    #[error("Not in single-price auction hours(08:30~09:00, 15:20~15:30)")]
+    NotInAuction,
+    #[error("Realtime subscription limit exceeded: {current}/{max}")]
+    SubscriptionLimitExceeded { current: usize, max: usize },
+    #[error("Circuit open after repeated failures, retry after {retry_after:?}")]
+    CircuitOpen { retry_after: std::time::Duration },
+    #[error("Websocket closed after {0} reconnect attempts")]
+    WebsocketClosed(u32),
+    #[error("Malformed realtime frame: {0}")]
+    MalformedFrame(String),
     #[error("AES cipher length error")]
     AesInvalidLength,
     #[error("AES decrypt error: {0}")]
     AesDecryptError(String),
+    #[error("Failed to decrypt execution notice: {0}")]
+    DecryptFailed(String),
+}
+
+/// 접근토큰 재발급 제한(EGW00133) 메시지의 대기 시간
+/// "(1분당 1회)"처럼 숫자 뒤에 초/분이 오는 첫 번째 값을 사용하며, 없으면 1분
+fn token_cooldown(msg: &str) -> std::time::Duration {
+    let chars: Vec<char> = msg.chars().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        if chars[i].is_ascii_digit() {
+            let start = i;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+            let n: u64 = chars[start..i]
+                .iter()
+                .collect::<String>()
+                .parse()
+                .unwrap_or(0);
+            match chars.get(i) {
+                Some('초') if n > 0 => return std::time::Duration::from_secs(n),
+                Some('분') if n > 0 => return std::time::Duration::from_secs(n * 60),
+                _ => {}
+            }
+        } else {
+            i += 1;
+        }
+    }
+    std::time::Duration::from_secs(60)
+}
+
+/// 네트워크 에러 구분(재시도 여부 판단용)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NetworkKind {
+    Timeout, // 요청 timeout
+    Connect, // 연결 실패(DNS, TLS 포함)
+    Request, // 요청 생성/전송 실패, redirect 등
+    Body,    // 응답 본문 수신 또는 역직렬화 실패
+}
+
+impl NetworkKind {
+    fn of(e: &reqwest::Error) -> Self {
+        if e.is_timeout() {
+            Self::Timeout
+        } else if e.is_connect() {
+            Self::Connect
+        } else if e.is_body() || e.is_decode() {
+            Self::Body
+        } else {
+            Self::Request
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(e: reqwest::Error) -> Self {
+        Self::Network(NetworkKind::of(&e), e)
+    }
+}
+
+impl Error {
+    /// rt_cd가 "0"이 아닌 KIS 응답을 에러로 변환
+    /// 자주 발생하는 msg_cd는 별도 variant로 구분
+    pub(crate) fn from_api(rt_cd: String, msg_cd: String, msg1: String) -> Self {
+        match msg_cd.as_str() {
+            "EGW00201" => Self::RateLimited(msg1),  // 초당 거래건수 초과
+            "EGW00123" => Self::TokenExpired(msg1), // 기간이 만료된 token
+            "APBK0013" => Self::MarketClosed(msg1), // 장운영시간이 아님
+            "EGW00133" => Self::TokenCooldown {
+                retry_after: token_cooldown(&msg1),
+            }, // 접근토큰 발급 잠시 후 다시 시도
+            _ => Self::Api {
+                rt_cd,
+                msg_cd,
+                msg1,
+                gt_uid: None,
+            },
+        }
+    }
+
+    /// Error::Api에 응답 헤더의 gt_uid 설정
+    pub(crate) fn with_gt_uid(mut self, uid: Option<String>) -> Self {
+        if let Self::Api { gt_uid, .. } = &mut self {
+            *gt_uid = uid;
+        }
+        self
+    }
+
+    /// 실패한 요청의 거래고유번호(gt_uid). KIS에 문의할 때 전달
+    pub fn gt_uid(&self) -> Option<&str> {
+        match self {
+            Self::Api { gt_uid, .. } => gt_uid.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    /// 연결 수를 세고, 받은 요청마다 빈 200 응답을 보내는(keep-alive) 로컬 HTTP 서버
+    fn counting_server() -> (String, Arc<AtomicUsize>) {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let counter = connections.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                std::thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    let mut stream = stream;
+                    let mut line = String::new();
+                    loop {
+                        line.clear();
+                        match reader.read_line(&mut line) {
+                            Ok(0) | Err(_) => return,
+                            Ok(_) if line == "\r\n" => {
+                                let response = "HTTP/1.1 200 OK\r\ncontent-length: 0\r\n\r\n";
+                                if stream.write_all(response.as_bytes()).is_err() {
+                                    return;
+                                }
+                            }
+                            Ok(_) => {}
+                        }
+                    }
+                });
+            }
+        });
+        (format!("http://{addr}"), connections)
+    }
+
+    async fn api(rest: &str, websocket: &str) -> KoreaInvestmentApi {
+        KoreaInvestmentApi::new_with_endpoints(
+            Environment::Real,
+            Endpoints::new(rest, websocket),
+            "appkey",
+            "appsecret",
+            Account::new("12345678", "01").unwrap(),
+            "hts_id",
+            Some("token".to_string()),
+            Some("approval_key".to_string()),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn warm_up_opens_pooled_connection() {
+        let (rest, connections) = counting_server();
+        let api = api(&rest, "ws://127.0.0.1:21000").await;
+        assert_eq!(connections.load(Ordering::SeqCst), 0);
+        api.warm_up().await.unwrap();
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+        // 이후 요청은 미리 만든 연결을 재사용
+        api.warm_up().await.unwrap();
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn warm_up_without_server_is_network_error() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let rest = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let api = api(&rest, "ws://127.0.0.1:21000").await;
+        assert!(matches!(api.warm_up().await, Err(Error::Network(..))));
+    }
+
+    const TOKEN: &str = r#"{"access_token":"issued","token_type":"Bearer","expires_in":86400}"#;
+
+    /// 토큰 캐시 테스트용 임시 파일 경로
+    fn cache_path(name: &str) -> std::path::PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("kis-token-{}-{}.json", std::process::id(), name));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    /// approval_key를 설정한 Real 투자환경 Auth
+    fn mock_auth(mock: &Arc<transport::MockTransport>) -> auth::Auth {
+        let auth = auth::Auth::new(
+            &reqwest::Client::new(),
+            Environment::Real,
+            "appkey",
+            "appsecret",
+        )
+        .with_transport(mock.clone());
+        auth.set_approval_key("approval_key".to_string());
+        auth
+    }
+
+    async fn from_auth(auth: auth::Auth) -> Result<KoreaInvestmentApi, Error> {
+        KoreaInvestmentApi::from_auth(
+            &reqwest::Client::new(),
+            auth,
+            Account::new("12345678", "01").unwrap(),
+            "hts_id",
+            CircuitBreaker::default(),
+        )
+        .await
+    }
+
+    fn token_requests(mock: &transport::MockTransport) -> usize {
+        mock.requests()
+            .iter()
+            .filter(|request| request.url.ends_with("/oauth2/tokenP"))
+            .count()
+    }
+
+    #[tokio::test]
+    async fn construction_uses_cached_token() {
+        let path = cache_path("cached");
+        let expires_at = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 3600;
+        std::fs::write(
+            &path,
+            format!(
+                r#"{{"Real":{{"appkey":"appkey","token":"cached","expires_at":{}}}}}"#,
+                expires_at
+            ),
+        )
+        .unwrap();
+        let mock = Arc::new(transport::MockTransport::new());
+        let api = from_auth(mock_auth(&mock).with_token_cache(&path))
+            .await
+            .unwrap();
+        assert_eq!(api.auth().get_token().as_deref(), Some("cached"));
+        assert_eq!(token_requests(&mock), 0);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn construction_issues_and_caches_token_when_cache_is_missing() {
+        let path = cache_path("missing");
+        let mock = Arc::new(
+            transport::MockTransport::new()
+                .on_path("/oauth2/tokenP", transport::MockResponse::json(TOKEN)),
+        );
+        let api = from_auth(mock_auth(&mock).with_token_cache(&path))
+            .await
+            .unwrap();
+        assert_eq!(api.auth().get_token().as_deref(), Some("issued"));
+        assert_eq!(token_requests(&mock), 1);
+        // 다음 실행은 캐시의 token을 사용
+        let mock = Arc::new(transport::MockTransport::new());
+        let api = from_auth(mock_auth(&mock).with_token_cache(&path))
+            .await
+            .unwrap();
+        assert_eq!(api.auth().get_token().as_deref(), Some("issued"));
+        assert_eq!(token_requests(&mock), 0);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn construction_waits_out_token_cooldown() {
+        let cooldown = r#"{"error_description":"접근토큰 발급 잠시 후 다시 시도하세요(1초당 1회)","error_code":"EGW00133"}"#;
+        let mock = Arc::new(
+            transport::MockTransport::new()
+                .on_path("/oauth2/tokenP", transport::MockResponse::json(cooldown))
+                .on_path("/oauth2/tokenP", transport::MockResponse::json(TOKEN)),
+        );
+        let started = std::time::Instant::now();
+        let api = from_auth(mock_auth(&mock)).await.unwrap();
+        assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+        assert_eq!(api.auth().get_token().as_deref(), Some("issued"));
+        assert_eq!(token_requests(&mock), 2);
+    }
 }
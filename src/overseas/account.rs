@@ -0,0 +1,116 @@
+use crate::client::RestClient;
+use crate::paging::PagedStream;
+use crate::types::request::overseas::account::{
+    OverseasBalanceParameter, OverseasBuyableParameter,
+};
+use crate::types::response::overseas::account::{OverseasBalanceResponse, OverseasBuyableResponse};
+use crate::types::{Account, Currency, Environment, FxRate, OverseasExchange, TrId};
+use crate::{auth, Error};
+use futures::TryStreamExt;
+use rust_decimal::Decimal;
+
+#[derive(Clone)]
+pub struct OverseasBalance {
+    rest: RestClient,
+    account: Account,
+}
+
+impl OverseasBalance {
+    /// 해외주식 계좌 조회에 관한 API
+    /// [해외주식주문](https://apiportal.koreainvestment.com/apiservice/apiservice-oversea-stock)
+    pub fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+        account: Account,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            rest: RestClient::new(client, environment, auth),
+            account,
+        })
+    }
+
+    crate::client::rest_builders!();
+
+    /// 해외주식 잔고[v1_해외주식-006]
+    /// exchange의 보유 종목을 연속조회 결과까지 모두 조회하여 합침. 계좌 합계는 currency 기준 외화 금액
+    /// 미국은 NASD로 조회하면 NYSE, AMEX 종목도 함께 조회됨
+    pub async fn inquire_balance(
+        &self,
+        exchange: OverseasExchange,
+        currency: Currency,
+    ) -> Result<OverseasBalanceResponse, Error> {
+        let pages: Vec<OverseasBalanceResponse> = self
+            .inquire_balance_all(exchange, currency)
+            .try_collect()
+            .await?;
+        OverseasBalanceResponse::from_pages(pages).ok_or(Error::BrokenProtocol(
+            "no page in overseas balance",
+            String::new(),
+        ))
+    }
+
+    /// 해외주식 잔고[v1_해외주식-006] 연속조회
+    /// 보유 종목이 한 번에 조회되지 않는 경우 마지막 페이지까지 차례로 조회
+    pub fn inquire_balance_all(
+        &self,
+        exchange: OverseasExchange,
+        currency: Currency,
+    ) -> PagedStream<OverseasBalanceResponse> {
+        let tr_id = match self.rest.environment() {
+            Environment::Real => TrId::RealOverseasBalance,
+            Environment::Virtual => TrId::VirtualOverseasBalance,
+        };
+        let rest = self.rest.clone();
+        let param = OverseasBalanceParameter::new(&self.account, exchange, currency);
+        PagedStream::new(move |key| {
+            let rest = rest.clone();
+            let tr_id = tr_id.clone();
+            let mut param = param.clone();
+            if let Some(key) = &key {
+                param = param.with_continuation(key);
+            }
+            async move {
+                let url = rest.url(
+                    "/uapi/overseas-stock/v1/trading/inquire-balance",
+                    &param.into_iter(),
+                )?;
+                rest.get_page(url, tr_id, key.is_some()).await
+            }
+        })
+    }
+
+    /// 해외주식 매수가능금액조회[v1_해외주식-014]
+    /// price는 해외주문단가(거래통화 기준)
+    pub async fn buyable(
+        &self,
+        exchange: OverseasExchange,
+        symbol: &str,
+        price: Decimal,
+    ) -> Result<OverseasBuyableResponse, Error> {
+        let tr_id = match self.rest.environment() {
+            Environment::Real => TrId::RealOverseasBuyable,
+            Environment::Virtual => TrId::VirtualOverseasBuyable,
+        };
+        let param = OverseasBuyableParameter::new(&self.account, exchange, symbol, price);
+        let url = self.rest.url(
+            "/uapi/overseas-stock/v1/trading/inquire-psamount",
+            &param.into_iter(),
+        )?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 매수가능금액조회 응답의 환율로 (exchange 거래통화, KRW) FxRate 조회
+    /// 환율은 종목과 무관하므로 symbol은 exchange에 상장된 아무 종목이어도 됨
+    pub async fn fx_rate(&self, exchange: OverseasExchange, symbol: &str) -> Result<FxRate, Error> {
+        let response = self.buyable(exchange, symbol, Decimal::ONE).await?;
+        response
+            .output()
+            .as_ref()
+            .and_then(|output| output.fx_rate())
+            .ok_or(Error::BrokenProtocol(
+                "no exchange rate in overseas buyable",
+                response.msg1().clone(),
+            ))
+    }
+}
@@ -0,0 +1,248 @@
+use crate::client::RestClient;
+use crate::types::{request, response, Account, Direction, Environment, OverseasExchange, TrId};
+use crate::{auth, Decimal, Error};
+
+#[derive(Clone)]
+pub struct OverseasOrder {
+    rest: RestClient,
+    account: Account,
+}
+
+impl OverseasOrder {
+    /// 해외주식 주문에 관한 API
+    /// [해외주식주문](https://apiportal.koreainvestment.com/apiservice/apiservice-oversea-stock)
+    pub fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+        account: Account,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            rest: RestClient::new(client, environment, auth),
+            account,
+        })
+    }
+
+    crate::client::rest_builders!("요청 timeout과 재시도 정책 설정(주문은 재시도하지 않음)");
+
+    /// 해외주식 주문[v1_해외주식-001]
+    /// 지정가 주문만 지원. 주문번호는 응답의 order_no()로 확인
+    pub async fn order(
+        &self,
+        exchange: OverseasExchange,
+        order_direction: Direction,
+        symbol: &str,
+        qty: u32,
+        price: Decimal,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        self.send_order(exchange, order_direction, symbol, Decimal::from(qty), price)
+            .await
+    }
+
+    /// 해외주식 소수점(1주 미만) 주문[v1_해외주식-001]
+    /// qty는 0.5처럼 Decimal로 전달하며, 소수점 주문을 허용하지 않는 거래소(OverseasExchange::allows_fractional이 false)에
+    /// 정수가 아닌 수량을 주문하면 전송하지 않고 Error::FractionalNotSupported
+    /// 미국 주식만 허용되며, 계좌에 소수점 거래 서비스가 신청되어 있지 않으면 KIS가 주문을 거부함
+    pub async fn order_fractional(
+        &self,
+        exchange: OverseasExchange,
+        order_direction: Direction,
+        symbol: &str,
+        qty: Decimal,
+        price: Decimal,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        if !qty.fract().is_zero() && !exchange.allows_fractional() {
+            return Err(Error::FractionalNotSupported(exchange));
+        }
+        self.send_order(exchange, order_direction, symbol, qty, price)
+            .await
+    }
+
+    async fn send_order(
+        &self,
+        exchange: OverseasExchange,
+        order_direction: Direction,
+        symbol: &str,
+        qty: Decimal,
+        price: Decimal,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        if qty <= Decimal::ZERO {
+            return Err(Error::InvalidParam {
+                field: "qty",
+                reason: "must be positive",
+            });
+        }
+        if price <= Decimal::ZERO {
+            return Err(Error::InvalidParam {
+                field: "price",
+                reason: "overseas orders require a positive limit price",
+            });
+        }
+        let is_sell = matches!(order_direction, Direction::Ask);
+        let tr_id = overseas_order_tr_id(self.rest.environment(), exchange, order_direction);
+        let request = request::overseas::order::body::Order::new(
+            self.account.cano().to_string(),
+            self.account.product_code().to_string(),
+            exchange.order_code().to_string(),
+            symbol.to_string(),
+            qty,
+            price,
+            is_sell,
+        );
+        self.rest
+            .post("/uapi/overseas-stock/v1/trading/order", tr_id, &request)
+            .await
+    }
+}
+
+/// 거래소와 매수/매도 구분에 따른 해외주식 주문 tr_id
+pub fn overseas_order_tr_id(
+    environment: &Environment,
+    exchange: OverseasExchange,
+    order_direction: Direction,
+) -> TrId {
+    use OverseasExchange::*;
+    match (environment, exchange, order_direction) {
+        // 미국
+        (Environment::Real, Nasdaq | Nyse | Amex, Direction::Bid) => TrId::RealOverseasUsBidOrder,
+        (Environment::Real, Nasdaq | Nyse | Amex, Direction::Ask) => TrId::RealOverseasUsAskOrder,
+        (Environment::Virtual, Nasdaq | Nyse | Amex, Direction::Bid) => {
+            TrId::VirtualOverseasUsBidOrder
+        }
+        (Environment::Virtual, Nasdaq | Nyse | Amex, Direction::Ask) => {
+            TrId::VirtualOverseasUsAskOrder
+        }
+        // 홍콩
+        (Environment::Real, HongKong, Direction::Bid) => TrId::RealOverseasHongKongBidOrder,
+        (Environment::Real, HongKong, Direction::Ask) => TrId::RealOverseasHongKongAskOrder,
+        (Environment::Virtual, HongKong, Direction::Bid) => TrId::VirtualOverseasHongKongBidOrder,
+        (Environment::Virtual, HongKong, Direction::Ask) => TrId::VirtualOverseasHongKongAskOrder,
+        // 중국 상해
+        (Environment::Real, Shanghai, Direction::Bid) => TrId::RealOverseasShanghaiBidOrder,
+        (Environment::Real, Shanghai, Direction::Ask) => TrId::RealOverseasShanghaiAskOrder,
+        (Environment::Virtual, Shanghai, Direction::Bid) => TrId::VirtualOverseasShanghaiBidOrder,
+        (Environment::Virtual, Shanghai, Direction::Ask) => TrId::VirtualOverseasShanghaiAskOrder,
+        // 중국 심천
+        (Environment::Real, Shenzhen, Direction::Bid) => TrId::RealOverseasShenzhenBidOrder,
+        (Environment::Real, Shenzhen, Direction::Ask) => TrId::RealOverseasShenzhenAskOrder,
+        (Environment::Virtual, Shenzhen, Direction::Bid) => TrId::VirtualOverseasShenzhenBidOrder,
+        (Environment::Virtual, Shenzhen, Direction::Ask) => TrId::VirtualOverseasShenzhenAskOrder,
+        // 일본
+        (Environment::Real, Tokyo, Direction::Bid) => TrId::RealOverseasJapanBidOrder,
+        (Environment::Real, Tokyo, Direction::Ask) => TrId::RealOverseasJapanAskOrder,
+        (Environment::Virtual, Tokyo, Direction::Bid) => TrId::VirtualOverseasJapanBidOrder,
+        (Environment::Virtual, Tokyo, Direction::Ask) => TrId::VirtualOverseasJapanAskOrder,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{MockResponse, MockTransport};
+    use std::sync::Arc;
+
+    const ORDER: &str = r#"{"rt_cd":"0","msg_cd":"APBK0013","msg1":"주문 전송 완료 되었습니다.","output":{"KRX_FWDG_ORD_ORGNO":"01790","ODNO":"0030138295","ORD_TMD":"223015"}}"#;
+
+    fn mock_order(mock: &Arc<MockTransport>) -> OverseasOrder {
+        let client = reqwest::Client::new();
+        let mut auth = auth::Auth::new(&client, Environment::Real, "appkey", "appsecret");
+        auth.set_token("token".to_string());
+        OverseasOrder::new(
+            &client,
+            Environment::Real,
+            auth,
+            Account::new("12345678", "01").unwrap(),
+        )
+        .unwrap()
+        .with_transport(mock.clone())
+    }
+
+    fn order_mock() -> Arc<MockTransport> {
+        Arc::new(
+            MockTransport::new()
+                .on_path("/uapi/hashkey", MockResponse::json(r#"{"HASH":"hash"}"#))
+                .on_path(
+                    "/uapi/overseas-stock/v1/trading/order",
+                    MockResponse::json(ORDER),
+                ),
+        )
+    }
+
+    /// 마지막 주문 요청 본문
+    fn sent_order(mock: &MockTransport) -> serde_json::Value {
+        let request = mock
+            .requests()
+            .into_iter()
+            .rev()
+            .find(|request| request.url.ends_with("/trading/order"))
+            .unwrap();
+        serde_json::from_str(request.body.as_deref().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn fractional_order_on_us_exchange() {
+        let mock = order_mock();
+        let order = mock_order(&mock);
+        order
+            .order_fractional(
+                OverseasExchange::Nasdaq,
+                Direction::Bid,
+                "AAPL",
+                Decimal::new(25, 2),
+                Decimal::new(18950, 2),
+            )
+            .await
+            .unwrap();
+        let body = sent_order(&mock);
+        assert_eq!(body["OVRS_EXCG_CD"], "NASD");
+        assert_eq!(body["ORD_QTY"], "0.25");
+        assert_eq!(body["OVRS_ORD_UNPR"], "189.50");
+    }
+
+    #[tokio::test]
+    async fn fractional_order_rejected_on_whole_share_exchange() {
+        let mock = order_mock();
+        let order = mock_order(&mock);
+        for exchange in [
+            OverseasExchange::HongKong,
+            OverseasExchange::Tokyo,
+            OverseasExchange::Shanghai,
+            OverseasExchange::Shenzhen,
+        ] {
+            let result = order
+                .order_fractional(
+                    exchange,
+                    Direction::Ask,
+                    "7203",
+                    Decimal::new(5, 1),
+                    Decimal::from(3000),
+                )
+                .await;
+            assert!(
+                matches!(result, Err(Error::FractionalNotSupported(e)) if e == exchange),
+                "{:?}",
+                exchange
+            );
+        }
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn whole_share_decimal_quantity_on_whole_share_exchange() {
+        let mock = order_mock();
+        let order = mock_order(&mock);
+        order
+            .order_fractional(
+                OverseasExchange::Tokyo,
+                Direction::Ask,
+                "7203",
+                Decimal::new(1000, 1),
+                Decimal::from(3000),
+            )
+            .await
+            .unwrap();
+        let body = sent_order(&mock);
+        assert_eq!(body["ORD_QTY"], "100");
+        assert_eq!(body["SLL_TYPE"], "00");
+    }
+}
@@ -0,0 +1,107 @@
+use crate::client::RestClient;
+use crate::types::request::overseas::quote::{CurrentPriceParameter, DailyChartParameter};
+use crate::types::response::overseas::quote::output::OverseasCandle;
+use crate::types::response::overseas::quote::{OverseasChartResponse, OverseasPriceResponse};
+use crate::types::{Account, DateRange, Environment, OverseasExchange, OverseasPeriod, TrId};
+use crate::{auth, validate, Error};
+use std::collections::BTreeMap;
+
+#[derive(Clone)]
+pub struct OverseasQuote {
+    rest: RestClient,
+    #[allow(dead_code)]
+    account: Account,
+}
+
+impl OverseasQuote {
+    /// 해외주식시세에 관한 API
+    /// [해외주식시세](https://apiportal.koreainvestment.com/apiservice/apiservice-oversea-stock-quotations)
+    pub fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+        account: Account,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            rest: RestClient::new(client, environment, auth),
+            account,
+        })
+    }
+
+    crate::client::rest_builders!();
+
+    /// 해외주식 현재체결가[v1_해외주식-009]
+    /// 실시간시세를 신청하지 않은 경우 지연시세로 응답함(output의 is_delayed로 확인)
+    pub async fn current_price(
+        &self,
+        exchange: OverseasExchange,
+        symbol: &str,
+    ) -> Result<OverseasPriceResponse, Error> {
+        validate::overseas_symbol("symbol", &exchange, symbol)?;
+        let tr_id = TrId::OverseasCurrentPrice;
+        let param = CurrentPriceParameter::new(exchange, symbol.to_string());
+        let path = "/uapi/overseas-price/v1/quotations/price";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 해외주식 기간별시세[v1_해외주식-010]
+    /// end_date(거래소 현지 일자)부터 과거 순으로 최대 100건의 일/주/월봉(수정주가 반영). 가격은 Decimal
+    pub async fn daily_chart(
+        &self,
+        exchange: OverseasExchange,
+        symbol: &str,
+        period: OverseasPeriod,
+        end_date: chrono::NaiveDate,
+    ) -> Result<OverseasChartResponse, Error> {
+        validate::overseas_symbol("symbol", &exchange, symbol)?;
+        let tr_id = TrId::OverseasDailyChart;
+        let param = DailyChartParameter::new(
+            exchange,
+            symbol.to_string(),
+            period,
+            format!("{}", end_date.format("%Y%m%d")),
+            true,
+        );
+        let path = "/uapi/overseas-price/v1/quotations/dailyprice";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 해외주식 기간별시세[v1_해외주식-010] 전체 기간
+    /// 직전 응답의 가장 이른 일자 전날로 기준일자를 옮기며 range 시작일까지 반복 조회
+    /// range는 거래소 현지 일자이며, 결과는 오래된 일자부터 정렬되고 range 밖의 봉은 제외됨
+    pub async fn daily_chart_full(
+        &self,
+        exchange: OverseasExchange,
+        symbol: &str,
+        period: OverseasPeriod,
+        range: DateRange,
+    ) -> Result<Vec<OverseasCandle>, Error> {
+        let mut end = range.end();
+        let mut candles: BTreeMap<chrono::NaiveDate, OverseasCandle> = BTreeMap::new();
+        loop {
+            let response = self.daily_chart(exchange, symbol, period, end).await?;
+            let page: Vec<(chrono::NaiveDate, OverseasCandle)> = response
+                .output2()
+                .iter()
+                .filter_map(|c| c.date().map(|date| (date, c.clone())))
+                .collect();
+            let earliest = match page.iter().map(|(date, _)| *date).min() {
+                Some(earliest) => earliest,
+                None => break,
+            };
+            for (date, candle) in page {
+                if range.start() <= date && date <= range.end() {
+                    candles.entry(date).or_insert(candle);
+                }
+            }
+            let next = match earliest.pred_opt() {
+                Some(next) if next >= range.start() && next < end => next,
+                _ => break,
+            };
+            end = next;
+        }
+        Ok(candles.into_values().collect())
+    }
+}
@@ -0,0 +1,77 @@
+use crate::Error;
+use futures::stream::{BoxStream, Stream, StreamExt};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+/// 연속조회키(CTX_AREA_FK100, CTX_AREA_NK100)
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ContinuationKey {
+    pub fk100: String, // 연속조회검색조건100
+    pub nk100: String, // 연속조회키100
+}
+
+/// 연속조회 응답 한 페이지
+pub(crate) struct Page<T> {
+    pub(crate) body: T,
+    pub(crate) tr_cont: String, // 응답 헤더의 tr_cont(F or M: 다음 데이터 있음 / D or E or 공백: 마지막 데이터)
+    pub(crate) key: ContinuationKey,
+}
+
+impl<T> Page<T> {
+    fn has_next(&self) -> bool {
+        matches!(self.tr_cont.trim(), "F" | "M")
+            && !(self.key.fk100.trim().is_empty() && self.key.nk100.trim().is_empty())
+    }
+}
+
+enum State {
+    Start,
+    Next(ContinuationKey),
+    Done,
+}
+
+/// 연속조회가 필요한 조회 API의 응답을 마지막 페이지까지 차례로 내보내는 Stream
+/// 다음 페이지는 이전 응답의 연속조회키와 tr_cont "N"으로 다시 요청함
+pub struct PagedStream<T> {
+    inner: BoxStream<'static, Result<T, Error>>,
+}
+
+impl<T: Send + 'static> PagedStream<T> {
+    /// fetch는 첫 페이지에는 None, 이후에는 이전 페이지의 연속조회키를 받아 요청
+    pub(crate) fn new<F, Fut>(fetch: F) -> Self
+    where
+        F: FnMut(Option<ContinuationKey>) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<Page<T>, Error>> + Send + 'static,
+    {
+        let inner =
+            futures::stream::unfold((State::Start, fetch), |(state, mut fetch)| async move {
+                let key = match state {
+                    State::Start => None,
+                    State::Next(key) => Some(key),
+                    State::Done => return None,
+                };
+                match fetch(key).await {
+                    Ok(page) => {
+                        let next = if page.has_next() {
+                            State::Next(page.key)
+                        } else {
+                            State::Done
+                        };
+                        Some((Ok(page.body), (next, fetch)))
+                    }
+                    Err(e) => Some((Err(e), (State::Done, fetch))),
+                }
+            })
+            .boxed();
+        Self { inner }
+    }
+}
+
+impl<T> Stream for PagedStream<T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.poll_next_unpin(cx)
+    }
+}
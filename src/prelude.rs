@@ -0,0 +1,47 @@
+pub use crate::auth::Auth;
+#[cfg(feature = "decimal")]
+pub use crate::bond::{order::BondOrder, quote::BondQuote};
+pub use crate::derivatives::{order::FuturesOrder, quote::FuturesQuote};
+#[cfg(feature = "decimal")]
+pub use crate::overseas::{account::OverseasBalance, order::OverseasOrder, quote::OverseasQuote};
+pub use crate::stock::account::Balance;
+pub use crate::stock::calendar::MarketCalendar;
+pub use crate::stock::data::{
+    ConnectionEvent, KoreaStockData, MaintenanceWindow, RealtimeEvent, ReconnectConfig,
+    Subscription,
+};
+pub use crate::stock::order::Korea;
+pub use crate::stock::profile::VolumeProfile;
+pub use crate::stock::quote::{CandleOptions, Quote};
+pub use crate::stock::ranking::Ranking;
+pub use crate::stock::search::Search;
+pub use crate::{
+    Cache, CircuitBreaker, CircuitState, ClientBuilder, Envelope, Error, FileCache,
+    HeaderOverrides, HealthStatus, KoreaInvestmentApi, MemoryCache, NetworkKind, PagedStream,
+    RateLimiter, RetryConfig, Transport,
+};
+
+#[cfg(feature = "decimal")]
+pub use crate::types::response::overseas::account::{
+    OverseasBalanceResponse, OverseasBuyableResponse,
+};
+pub use crate::types::response::stock::account::{BalanceResponse, BuyableResponse, DepositDetail};
+pub use crate::types::response::stock::order::Body::Order as OrderResponse;
+#[cfg(feature = "decimal")]
+pub use crate::types::response::stock::quote::DividendEvent;
+pub use crate::types::response::stock::quote::{
+    AskingPriceResponse, Candle, ConclusionStrengthResponse, CurrentPriceResponse,
+    DailyPriceResponse, ExpectedConclusion, IndexPriceResponse, LimitKind, LimitStock,
+    MinuteChartResponse, PeriodicPriceResponse, StockInfo, StockStatus, WatchlistPrices,
+};
+pub use crate::types::stream::stock::{Exec, MyExec, Ordb};
+#[cfg(feature = "decimal")]
+pub use crate::types::FxRate;
+pub use crate::types::{
+    Account, ChartPeriod, CorporateHeaders, CorrectionClass, Currency, DailyPeriod, DateRange,
+    Direction, Endpoints, Environment, Exchange, Granularity, IndexCode, Investor, KisDate,
+    KisTime, ListedMarket, MarketCode, MarketDivision, OrderClass, OverseasExchange,
+    OverseasPeriod, ParseCodeError, Price, Quantity, SectorCode, Time, TrId,
+};
+#[cfg(feature = "decimal")]
+pub use crate::Decimal;
@@ -0,0 +1,116 @@
+use crate::Error;
+
+/// 주식현재가 시세 응답에서 현재가(stck_prpr)만 읽음
+pub fn quick_current_price(json: &[u8]) -> Result<f64, Error> {
+    Ok(quick_field(json, "stck_prpr")?.parse::<f64>()?)
+}
+
+/// 국내업종 현재지수 응답에서 업종 지수 현재가(bstp_nmix_prpr)만 읽음
+pub fn quick_index_price(json: &[u8]) -> Result<f64, Error> {
+    Ok(quick_field(json, "bstp_nmix_prpr")?.parse::<f64>()?)
+}
+
+/// 해외주식 현재체결가 응답에서 현재가(last)만 읽음
+pub fn quick_overseas_price(json: &[u8]) -> Result<f64, Error> {
+    Ok(quick_field(json, "last")?.parse::<f64>()?)
+}
+
+/// 응답 원문에서 key의 값을 문자열 그대로 반환(따옴표 제외, escape 처리 없음)
+/// rt_cd가 "0"이 아니면 응답 구조체와 같은 에러(Error::from_api)를 반환
+/// 같은 이름의 key가 여러 번 나오면 처음 값을 사용하므로, 응답 안에서 유일한 key에만 사용
+pub fn quick_field<'a>(json: &'a [u8], key: &str) -> Result<&'a str, Error> {
+    if let Some(rt_cd) = find_value(json, "rt_cd") {
+        if rt_cd != "0" {
+            return Err(Error::from_api(
+                rt_cd.to_string(),
+                find_value(json, "msg_cd").unwrap_or_default().to_string(),
+                find_value(json, "msg1").unwrap_or_default().to_string(),
+            ));
+        }
+    }
+    find_value(json, key)
+        .map(str::trim)
+        .ok_or_else(|| Error::BrokenProtocol("quick: field not found", key.to_string()))
+}
+
+/// "key": 뒤의 문자열 또는 숫자 값
+fn find_value<'a>(json: &'a [u8], key: &str) -> Option<&'a str> {
+    let pattern = format!("\"{}\"", key);
+    let pattern = pattern.as_bytes();
+    let mut from = 0;
+    while let Some(pos) = find(&json[from..], pattern) {
+        let mut i = from + pos + pattern.len();
+        from = i;
+        i = skip_whitespace(json, i);
+        if json.get(i) != Some(&b':') {
+            continue; // key가 아니라 값으로 나온 문자열
+        }
+        i = skip_whitespace(json, i + 1);
+        let (start, end) = if json.get(i) == Some(&b'"') {
+            let start = i + 1;
+            let mut end = start;
+            while end < json.len() && !(json[end] == b'"' && json[end - 1] != b'\\') {
+                end += 1;
+            }
+            (start, end)
+        } else {
+            let start = i;
+            let mut end = start;
+            while end < json.len() && !matches!(json[end], b',' | b'}' | b']') {
+                end += 1;
+            }
+            (start, end)
+        };
+        return std::str::from_utf8(json.get(start..end)?).ok();
+    }
+    None
+}
+
+/// needle의 첫 바이트 위치에서만 나머지를 비교
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let (first, rest) = needle.split_first()?;
+    let mut from = 0;
+    while let Some(pos) = haystack.get(from..)?.iter().position(|b| b == first) {
+        let start = from + pos;
+        if haystack[start + 1..].starts_with(rest) {
+            return Some(start);
+        }
+        from = start + 1;
+    }
+    None
+}
+
+fn skip_whitespace(json: &[u8], mut i: usize) -> usize {
+    while i < json.len() && json[i].is_ascii_whitespace() {
+        i += 1;
+    }
+    i
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_field_without_full_deserialize() {
+        let json = br#"{"output":{"stck_shrn_iscd":"005930","stck_prpr": "71500"},"rt_cd":"0","msg_cd":"MCA00000","msg1":"ok"}"#;
+        assert_eq!(quick_current_price(json).unwrap(), 71500.0);
+        assert_eq!(quick_field(json, "stck_shrn_iscd").unwrap(), "005930");
+        assert!(matches!(
+            quick_field(json, "bstp_nmix_prpr"),
+            Err(Error::BrokenProtocol(..))
+        ));
+    }
+
+    #[test]
+    fn skips_value_that_looks_like_key() {
+        let json = br#"{"msg1":"last","last":"187.5","rt_cd":"0"}"#;
+        assert_eq!(quick_overseas_price(json).unwrap(), 187.5);
+    }
+
+    #[test]
+    fn returns_api_error_when_rt_cd_is_not_zero() {
+        let json = r#"{"rt_cd":"1","msg_cd":"EGW00201","msg1":"초당 거래건수를 초과하였습니다."}"#;
+        assert!(quick_current_price(json.as_bytes()).is_err());
+    }
+}
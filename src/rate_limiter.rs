@@ -0,0 +1,117 @@
+use crate::types::Environment;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+
+/// 초당 요청 수 제한(token bucket)
+/// 실전투자는 초당 20건, 모의투자는 초당 2건으로 제한되며 초과하면 EGW00201 에러를 반환함
+/// clone된 RateLimiter끼리 같은 bucket을 공유하므로 여러 서비스 구조체에 넘겨서 함께 사용
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<Bucket>>,
+    per_second: u32,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    per_second: f64,
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl Bucket {
+    fn refill(&mut self) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_second).min(self.per_second);
+        self.last_refill = now;
+    }
+}
+
+impl RateLimiter {
+    /// 초당 per_second건까지 허용하는 RateLimiter 생성
+    pub fn new(per_second: u32) -> Self {
+        let per_second = per_second.max(1);
+        Self {
+            bucket: Arc::new(Mutex::new(Bucket {
+                per_second: per_second as f64,
+                tokens: per_second as f64,
+                last_refill: Instant::now(),
+            })),
+            per_second,
+        }
+    }
+
+    /// 초당 허용 요청 수
+    pub fn per_second(&self) -> u32 {
+        self.per_second
+    }
+
+    /// 투자환경별 기본 제한(실전투자: 초당 20건, 모의투자: 초당 2건)
+    pub fn for_environment(environment: &Environment) -> Self {
+        match environment {
+            Environment::Real => Self::new(20),
+            Environment::Virtual => Self::new(2),
+        }
+    }
+
+    /// 요청을 보낼 수 있을 때까지 대기
+    /// 대기 중인 요청들은 도착 순서대로 처리됨
+    pub async fn acquire(&self) {
+        let mut bucket = self.bucket.lock().await;
+        bucket.refill();
+        if bucket.tokens < 1.0 {
+            let wait = (1.0 - bucket.tokens) / bucket.per_second;
+            tokio::time::sleep(Duration::from_secs_f64(wait)).await;
+            bucket.refill();
+        }
+        bucket.tokens -= 1.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_acquire_respects_rate() {
+        let rate_limiter = RateLimiter::new(20);
+        let start = Instant::now();
+        let tasks: Vec<_> = (0..30)
+            .map(|_| {
+                let rate_limiter = rate_limiter.clone();
+                tokio::spawn(async move {
+                    rate_limiter.acquire().await;
+                    Instant::now()
+                })
+            })
+            .collect();
+        let mut times = Vec::new();
+        for task in tasks {
+            times.push(task.await.unwrap().duration_since(start).as_secs_f64());
+        }
+        times.sort_by(f64::total_cmp);
+        // 처음 20건 이후 나머지 10건은 초당 20건씩 채워지는 token을 기다림
+        assert!(times[29] >= 0.45, "{:?}", times);
+        // 어느 구간에서도 (구간 길이 x 20 + 20)건을 넘지 않음
+        for i in 0..times.len() {
+            for j in i..times.len() {
+                let allowed = (times[j] - times[i]) * 20.0 + 20.0 + 1e-6;
+                assert!(((j - i + 1) as f64) <= allowed, "{:?}", times);
+            }
+        }
+    }
+
+    #[test]
+    fn environment_rates() {
+        assert_eq!(
+            RateLimiter::for_environment(&Environment::Real).per_second(),
+            20
+        );
+        assert_eq!(
+            RateLimiter::for_environment(&Environment::Virtual).per_second(),
+            2
+        );
+        assert_eq!(RateLimiter::new(0).per_second(), 1);
+    }
+}
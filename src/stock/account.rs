@@ -0,0 +1,261 @@
+use crate::client::{Envelope, RestClient};
+use crate::paging::PagedStream;
+use crate::types::request::stock::account::{
+    BalanceParameter, PeriodProfitParameter, PsblOrderParameter,
+};
+use crate::types::response::stock::account::output::HoldingItem;
+use crate::types::response::stock::account::{
+    BalanceResponse, BuyableResponse, DepositDetail, PeriodProfitPage, PeriodProfitResponse,
+};
+use crate::types::{Account, DateRange, Environment, OrderClass, TrId};
+use crate::{auth, Error};
+use futures::TryStreamExt;
+
+#[derive(Clone)]
+pub struct Balance {
+    rest: RestClient,
+    account: Account,
+}
+
+impl Balance {
+    /// 국내 주식 계좌 조회에 관한 API
+    /// [국내주식주문](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_66c61080-674f-4c91-a0cc-db5e64e9a5e6)
+    pub fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+        account: Account,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            rest: RestClient::new(client, environment, auth),
+            account,
+        })
+    }
+
+    crate::client::rest_builders!();
+
+    /// 주식잔고조회[v1_국내주식-006]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_66c61080-674f-4c91-a0cc-db5e64e9a5e6)
+    /// 보유 종목(output1)과 계좌 합계(output2)를 조회
+    pub async fn inquire_balance(&self) -> Result<BalanceResponse, Error> {
+        Ok(self.inquire_balance_with_meta().await?.body)
+    }
+
+    /// 주식잔고조회[v1_국내주식-006]
+    /// 응답 헤더(tr_cont, tr_id, gt_uid)를 함께 반환. tr_cont가 F 또는 M이면 다음 페이지가 있음
+    pub async fn inquire_balance_with_meta(&self) -> Result<Envelope<BalanceResponse>, Error> {
+        let tr_id = match self.rest.environment() {
+            Environment::Real => TrId::RealStockBalance,
+            Environment::Virtual => TrId::VirtualStockBalance,
+        };
+        let param = BalanceParameter::new(&self.account);
+        let url = self.rest.url(
+            "/uapi/domestic-stock/v1/trading/inquire-balance",
+            &param.into_iter(),
+        )?;
+        self.rest.get_with_meta(url, tr_id).await
+    }
+
+    /// 주식잔고조회[v1_국내주식-006] 연속조회
+    /// 보유 종목이 한 번에 조회되지 않는 경우 마지막 페이지까지 차례로 조회
+    pub fn inquire_balance_all(&self) -> PagedStream<BalanceResponse> {
+        self.balance_pages(BalanceParameter::new(&self.account))
+    }
+
+    /// 예수금(D+0/D+1/D+2), 출금가능금액, 주문가능현금
+    /// 주식잔고조회 첫 페이지의 계좌 합계와 종목을 지정하지 않은 매수가능조회(시장가)로 계산
+    /// 출금가능금액은 D+2 예수금 기준이며 미수금, 대출 상환액은 반영하지 않음
+    pub async fn deposit_detail(&self) -> Result<DepositDetail, Error> {
+        let balance = self.inquire_balance().await?;
+        let tr_id = match self.rest.environment() {
+            Environment::Real => TrId::RealStockPsblOrder,
+            Environment::Virtual => TrId::VirtualStockPsblOrder,
+        };
+        let param = PsblOrderParameter::new(&self.account, "".to_string(), 0, OrderClass::Market);
+        let url = self.rest.url(
+            "/uapi/domestic-stock/v1/trading/inquire-psbl-order",
+            &param.into_iter(),
+        )?;
+        let buyable: BuyableResponse = self.rest.get(url, tr_id).await?;
+        let output = buyable.output().as_ref().ok_or(Error::BrokenProtocol(
+            "no output in buyable",
+            buyable.msg1().clone(),
+        ))?;
+        Ok(DepositDetail::new(balance.summary(), output))
+    }
+
+    /// 신용 잔고
+    /// 주식잔고조회를 대출일별로 조회하여 대출금액이 있는 보유 내역만 반환
+    /// 대출일자(loan_date), 대출금액(loan_amt), 상환 기한(expiry_date)으로 상환 일정을 확인
+    pub async fn credit_balance(&self) -> Result<Vec<HoldingItem>, Error> {
+        let pages: Vec<BalanceResponse> = self
+            .balance_pages(BalanceParameter::new(&self.account).by_loan_date())
+            .try_collect()
+            .await
+            .map_err(credit_error)?;
+        Ok(pages
+            .into_iter()
+            .flat_map(|page| page.into_items())
+            .filter(|item| item.is_credit())
+            .collect())
+    }
+
+    /// 신용매수가능조회[v1_국내주식-042]
+    /// 자기융자신규 기준 최대 매수 가능 수량/금액. price가 0이면 현재가 기준(시장가). 모의투자 미지원
+    pub async fn credit_buyable(
+        &self,
+        shortcode: &str,
+        price: u32,
+    ) -> Result<BuyableResponse, Error> {
+        let tr_id = self.rest.require_real(TrId::RealStockCreditPsblOrder)?;
+        let order_class = if price == 0 {
+            OrderClass::Market
+        } else {
+            OrderClass::Limit
+        };
+        let param =
+            PsblOrderParameter::new(&self.account, shortcode.to_string(), price, order_class)
+                .credit();
+        let url = self.rest.url(
+            "/uapi/domestic-stock/v1/trading/inquire-credit-psamount",
+            &param.into_iter(),
+        )?;
+        self.rest.get(url, tr_id).await.map_err(credit_error)
+    }
+
+    /// 기간별매매손익현황조회[v1_국내주식-060]
+    /// 기간 내 종목별 실현손익을 연속조회 결과까지 모두 조회하고 합계를 합산. 모의투자 미지원
+    pub async fn period_profit(
+        &self,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+    ) -> Result<PeriodProfitResponse, Error> {
+        let tr_id = self.rest.require_real(TrId::RealStockPeriodProfit)?;
+        let range = DateRange::new(start, end)?;
+        let rest = self.rest.clone();
+        let account = self.account.clone();
+        let pages: Vec<PeriodProfitPage> = PagedStream::new(move |key| {
+            let rest = rest.clone();
+            let tr_id = tr_id.clone();
+            let mut param = PeriodProfitParameter::new(&account, &range);
+            if let Some(key) = &key {
+                param = param.with_continuation(key);
+            }
+            async move {
+                let url = rest.url(
+                    "/uapi/domestic-stock/v1/trading/inquire-period-profit",
+                    &param.into_iter(),
+                )?;
+                rest.get_page(url, tr_id, key.is_some()).await
+            }
+        })
+        .try_collect()
+        .await?;
+        Ok(PeriodProfitResponse::from_pages(pages))
+    }
+
+    fn balance_pages(&self, param: BalanceParameter) -> PagedStream<BalanceResponse> {
+        let tr_id = match self.rest.environment() {
+            Environment::Real => TrId::RealStockBalance,
+            Environment::Virtual => TrId::VirtualStockBalance,
+        };
+        let rest = self.rest.clone();
+        PagedStream::new(move |key| {
+            let rest = rest.clone();
+            let tr_id = tr_id.clone();
+            let mut param = param.clone();
+            if let Some(key) = &key {
+                param = param.with_continuation(key);
+            }
+            async move {
+                let url = rest.url(
+                    "/uapi/domestic-stock/v1/trading/inquire-balance",
+                    &param.into_iter(),
+                )?;
+                rest.get_page(url, tr_id, key.is_some()).await
+            }
+        })
+    }
+}
+
+/// 신용 약정이 없는 계좌의 KIS 에러를 Error::CreditNotEnabled로 변환
+/// msg_cd가 문서화되어 있지 않아 응답메시지(msg1)로 판별
+fn credit_error(e: Error) -> Error {
+    match e {
+        Error::Api { msg1, .. } if msg1.contains("신용") && msg1.contains("약정") => {
+            Error::CreditNotEnabled(msg1)
+        }
+        e => e,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{MockResponse, MockTransport};
+    use std::sync::Arc;
+
+    const BALANCE: &str = include_str!("../../tests/fixtures/inquire_balance.json");
+    const PSBL_ORDER: &str = include_str!("../../tests/fixtures/inquire_psbl_order.json");
+
+    fn balance(mock: &Arc<MockTransport>) -> Balance {
+        let client = reqwest::Client::new();
+        let mut auth = auth::Auth::new(&client, Environment::Real, "appkey", "appsecret");
+        auth.set_token("token".to_string());
+        Balance::new(
+            &client,
+            Environment::Real,
+            auth,
+            Account::new("12345678", "01").unwrap(),
+        )
+        .unwrap()
+        .with_transport(mock.clone())
+    }
+
+    #[tokio::test]
+    async fn deposit_detail_from_fixture() {
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_tr_id(TrId::RealStockBalance, MockResponse::json(BALANCE))
+                .on_tr_id(TrId::RealStockPsblOrder, MockResponse::json(PSBL_ORDER)),
+        );
+        let detail = balance(&mock).deposit_detail().await.unwrap();
+        assert_eq!(detail.d0(), 1_500_000);
+        assert_eq!(detail.d1(), 1_200_000);
+        assert_eq!(detail.d2(), 789_550);
+        assert_eq!(detail.withdrawable(), 789_550);
+        assert_eq!(detail.orderable_cash(), 789_100);
+        let tr_ids: Vec<_> = mock.requests().into_iter().map(|r| r.tr_id).collect();
+        assert_eq!(tr_ids, ["TTTC8434R", "TTTC8908R"]);
+    }
+
+    #[tokio::test]
+    async fn deposit_detail_negative_d2_is_not_withdrawable() {
+        let balance_body = BALANCE.replace(
+            r#""prvs_rcdl_excc_amt": "789550""#,
+            r#""prvs_rcdl_excc_amt": "-120000""#,
+        );
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_tr_id(TrId::RealStockBalance, MockResponse::json(&balance_body))
+                .on_tr_id(TrId::RealStockPsblOrder, MockResponse::json(PSBL_ORDER)),
+        );
+        let detail = balance(&mock).deposit_detail().await.unwrap();
+        assert_eq!(detail.d2(), -120_000);
+        assert_eq!(detail.withdrawable(), 0);
+    }
+
+    #[tokio::test]
+    async fn deposit_detail_without_buyable_output_is_broken_protocol() {
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_tr_id(TrId::RealStockBalance, MockResponse::json(BALANCE))
+                .on_tr_id(
+                    TrId::RealStockPsblOrder,
+                    MockResponse::json(r#"{"rt_cd":"0","msg_cd":"APBK0000","msg1":"no output"}"#),
+                ),
+        );
+        let result = balance(&mock).deposit_detail().await;
+        assert!(matches!(result, Err(Error::BrokenProtocol(..))));
+    }
+}
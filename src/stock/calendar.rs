@@ -0,0 +1,263 @@
+use crate::client::RestClient;
+use crate::types::request::stock::quote::HolidayParameter;
+use crate::types::response::stock::quote::HolidayResponse;
+use crate::types::{Environment, OrderClass, Time, TrId};
+use crate::{auth, Cache, Error, MemoryCache};
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+/// 주문 가능 시간대(KST, 시작 포함/종료 미포함)
+/// 08:30~15:30: 장전 시간외, 장 시작 동시호가, 정규장, 장 마감 동시호가
+/// 15:40~18:00: 장후 시간외, 시간외 단일가
+const TRADING_WINDOWS: [((u32, u32), (u32, u32)); 2] = [((8, 30), (15, 30)), ((15, 40), (18, 0))];
+
+/// 단일가 매매(동시호가) 시간대(KST, 시작 포함/종료 미포함)
+/// 08:30~09:00: 장 시작 동시호가, 15:20~15:30: 장 마감 동시호가
+const AUCTION_WINDOWS: [((u32, u32), (u32, u32)); 2] = [((8, 30), (9, 0)), ((15, 20), (15, 30))];
+
+/// 휴장일 조회 응답 캐시 유효 기간
+const HOLIDAY_CACHE_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// 국내 주식시장 개장 여부 확인
+/// 휴장일은 국내휴장일조회 API로 조회하며, 한 번 조회한 날짜는 clone된 MarketCalendar끼리 공유하여 재사용
+#[derive(Clone)]
+pub struct MarketCalendar {
+    rest: RestClient,
+    opened: Arc<Mutex<HashMap<NaiveDate, bool>>>,
+}
+
+impl MarketCalendar {
+    /// 국내휴장일조회에 관한 API
+    /// 모의투자는 휴장일 조회를 지원하지 않아 주말만 휴장일로 처리함
+    pub fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+    ) -> Result<Self, Error> {
+        let mut rest = RestClient::new(client, environment, auth);
+        rest.set_cache(Arc::new(MemoryCache::new()));
+        Ok(Self {
+            rest,
+            opened: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    crate::client::rest_builders!();
+
+    /// 휴장일 조회 응답을 저장할 Cache(기본값은 MemoryCache, 파일에 저장하려면 FileCache)
+    pub fn with_cache(mut self, cache: Arc<dyn Cache>) -> Self {
+        self.rest.set_cache(cache);
+        self
+    }
+
+    /// 주문 가능 시간대인지 확인(휴장일 여부는 확인하지 않음)
+    pub fn is_trading_hours(time: NaiveTime) -> bool {
+        TRADING_WINDOWS.iter().any(|((sh, sm), (eh, em))| {
+            let start = NaiveTime::from_hms_opt(*sh, *sm, 0).unwrap_or(NaiveTime::MIN);
+            let end = NaiveTime::from_hms_opt(*eh, *em, 0).unwrap_or(NaiveTime::MIN);
+            start <= time && time < end
+        })
+    }
+
+    /// 현재(KST) 주문 가능 여부
+    pub async fn is_open_now(&self) -> Result<bool, Error> {
+        let now = Time::now().inner();
+        if !Self::is_trading_hours(now.time()) {
+            return Ok(false);
+        }
+        self.is_open_day(now.date_naive()).await
+    }
+
+    /// 장 시작/마감 동시호가(예상체결가가 제공되는) 시간대인지 확인(휴장일 여부는 확인하지 않음)
+    pub fn is_auction_hours(time: NaiveTime) -> bool {
+        AUCTION_WINDOWS.iter().any(|((sh, sm), (eh, em))| {
+            let start = NaiveTime::from_hms_opt(*sh, *sm, 0).unwrap_or(NaiveTime::MIN);
+            let end = NaiveTime::from_hms_opt(*eh, *em, 0).unwrap_or(NaiveTime::MIN);
+            start <= time && time < end
+        })
+    }
+
+    /// 현재(KST) 개장일의 동시호가 시간대인지 여부
+    pub async fn is_auction_now(&self) -> Result<bool, Error> {
+        let now = Time::now().inner();
+        if !Self::is_auction_hours(now.time()) {
+            return Ok(false);
+        }
+        self.is_open_day(now.date_naive()).await
+    }
+
+    /// 주문구분별 주문 가능 시간대인지 확인(휴장일 여부는 확인하지 않음)
+    /// 장전 시간외(05) 08:30~08:40, 장후 시간외(06) 15:40~16:00, 시간외 단일가(07) 16:00~18:00,
+    /// 그 외 주문구분은 is_trading_hours와 같음
+    pub fn is_session_hours(order_class: &OrderClass, time: NaiveTime) -> bool {
+        let window = match order_class {
+            OrderClass::PreMarket => ((8, 30), (8, 40)),
+            OrderClass::PostMarket => ((15, 40), (16, 0)),
+            OrderClass::OutMarketSinglePrice => ((16, 0), (18, 0)),
+            _ => return Self::is_trading_hours(time),
+        };
+        let ((sh, sm), (eh, em)) = window;
+        let start = NaiveTime::from_hms_opt(sh, sm, 0).unwrap_or(NaiveTime::MIN);
+        let end = NaiveTime::from_hms_opt(eh, em, 0).unwrap_or(NaiveTime::MIN);
+        start <= time && time < end
+    }
+
+    /// 현재(KST) 해당 주문구분으로 주문 가능 여부
+    pub async fn is_session_open_now(&self, order_class: &OrderClass) -> Result<bool, Error> {
+        let now = Time::now().inner();
+        if !Self::is_session_hours(order_class, now.time()) {
+            return Ok(false);
+        }
+        self.is_open_day(now.date_naive()).await
+    }
+
+    /// 개장일 여부
+    pub async fn is_open_day(&self, date: NaiveDate) -> Result<bool, Error> {
+        if matches!(date.weekday(), Weekday::Sat | Weekday::Sun) {
+            return Ok(false);
+        }
+        if let Environment::Virtual = self.rest.environment() {
+            return Ok(true);
+        }
+        let mut opened = self.opened.lock().await;
+        if let Some(is_open) = opened.get(&date) {
+            return Ok(*is_open);
+        }
+        let response = self.holidays(date).await?;
+        for day in response.output().iter().flatten() {
+            if let Ok(d) = NaiveDate::parse_from_str(day.bass_dt(), "%Y%m%d") {
+                opened.insert(d, day.opnd_yn() == "Y");
+            }
+        }
+        Ok(*opened.get(&date).unwrap_or(&true))
+    }
+
+    /// 국내휴장일조회[국내주식-040]
+    /// bass_dt부터의 영업일/거래일/개장일/결제일 여부
+    /// 원장 서비스와 연관되어 있어 1일 1회 호출을 권장함. 같은 bass_dt는 하루 동안 캐시된 응답을 반환
+    pub async fn holidays(&self, bass_dt: NaiveDate) -> Result<HolidayResponse, Error> {
+        let tr_id = TrId::HolidayCheck;
+        let param = HolidayParameter::new(format!("{}", bass_dt.format("%Y%m%d")));
+        let path = "/uapi/domestic-stock/v1/quotations/chk-holiday";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get_cached(url, tr_id, HOLIDAY_CACHE_TTL).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{MockResponse, MockTransport};
+
+    fn at(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    #[test]
+    fn out_of_market_session_hours() {
+        let cases = [
+            (OrderClass::PreMarket, at(8, 29), at(8, 30), at(8, 40)),
+            (OrderClass::PostMarket, at(15, 39), at(15, 40), at(16, 0)),
+            (
+                OrderClass::OutMarketSinglePrice,
+                at(15, 59),
+                at(16, 0),
+                at(18, 0),
+            ),
+        ];
+        for (order_class, before, start, end) in cases {
+            assert!(!MarketCalendar::is_session_hours(&order_class, before));
+            assert!(MarketCalendar::is_session_hours(&order_class, start));
+            assert!(!MarketCalendar::is_session_hours(&order_class, end));
+            assert!(!MarketCalendar::is_session_hours(&order_class, at(10, 0)));
+        }
+        assert!(MarketCalendar::is_session_hours(
+            &OrderClass::Limit,
+            at(10, 0)
+        ));
+        assert!(!MarketCalendar::is_session_hours(
+            &OrderClass::Limit,
+            at(19, 0)
+        ));
+    }
+
+    #[test]
+    fn auction_window_boundaries() {
+        let at_sec = |h, m, s| NaiveTime::from_hms_opt(h, m, s).unwrap();
+        for time in [
+            at(8, 30),
+            at(8, 59),
+            at_sec(8, 59, 59),
+            at(15, 20),
+            at_sec(15, 29, 59),
+        ] {
+            assert!(MarketCalendar::is_auction_hours(time), "{}", time);
+        }
+        for time in [
+            at_sec(8, 29, 59),
+            at(9, 0),
+            at(12, 0),
+            at_sec(15, 19, 59),
+            at(15, 30),
+            at(16, 0),
+        ] {
+            assert!(!MarketCalendar::is_auction_hours(time), "{}", time);
+        }
+    }
+
+    const HOLIDAYS: &str = r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":"ok","output":[{"bass_dt":"20240102","wday_dvsn_cd":"03","bzdy_yn":"Y","tr_day_yn":"Y","opnd_yn":"Y","sttl_day_yn":"Y"}]}"#;
+
+    fn calendar(
+        environment: Environment,
+        mock: &Arc<MockTransport>,
+        cache: &Arc<MemoryCache>,
+    ) -> MarketCalendar {
+        let client = reqwest::Client::new();
+        let mut auth = auth::Auth::new(&client, environment.clone(), "appkey", "appsecret");
+        auth.set_token("token".to_string());
+        MarketCalendar::new(&client, environment, auth)
+            .unwrap()
+            .with_transport(mock.clone())
+            .with_cache(cache.clone())
+    }
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[tokio::test]
+    async fn holidays_within_ttl_hit_cache() {
+        let mock = Arc::new(
+            MockTransport::new().on_tr_id(TrId::HolidayCheck, MockResponse::json(HOLIDAYS)),
+        );
+        let cache = Arc::new(MemoryCache::new());
+        let first = calendar(Environment::Real, &mock, &cache);
+        first.holidays(date(2024, 1, 2)).await.unwrap();
+        assert_eq!(mock.requests().len(), 1);
+
+        // 같은 Cache를 쓰는 다른 MarketCalendar도 다시 요청하지 않음
+        let second = calendar(Environment::Real, &mock, &cache);
+        let cached = second.holidays(date(2024, 1, 2)).await.unwrap();
+        assert_eq!(cached.output().as_ref().unwrap()[0].bass_dt(), "20240102");
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn holidays_cache_key_includes_params_and_environment() {
+        let mock = Arc::new(
+            MockTransport::new().on_tr_id(TrId::HolidayCheck, MockResponse::json(HOLIDAYS)),
+        );
+        let cache = Arc::new(MemoryCache::new());
+        let real = calendar(Environment::Real, &mock, &cache);
+        real.holidays(date(2024, 1, 2)).await.unwrap();
+        real.holidays(date(2024, 1, 3)).await.unwrap();
+        assert_eq!(mock.requests().len(), 2);
+
+        let virtual_calendar = calendar(Environment::Virtual, &mock, &cache);
+        virtual_calendar.holidays(date(2024, 1, 2)).await.unwrap();
+        assert_eq!(mock.requests().len(), 3);
+    }
+}
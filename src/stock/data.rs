@@ -1,21 +1,181 @@
-use crate::types::request::stock::subscribe::SubscribeRequest;
+use crate::types::request::stock::subscribe::{SubscribeRequest, TrType};
 use crate::types::response::stock::subscribe::SubscribeResponse;
-use crate::types::stream::stock::{MyExec, StreamParser};
-use crate::types::{Account, CustomerType, Environment, TrId};
+use crate::types::stream::stock::{
+    split_records, Exec, ExecutionNotice, MyExec, Ordb, StreamParser,
+};
+use crate::types::{Account, CustomerType, Environment, Time, TrId};
 use crate::{auth, Error};
+use chrono::NaiveTime;
+use futures::stream::{BoxStream, StreamExt};
 use std::collections::HashMap;
+use std::net::TcpStream;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use websocket::sync::{Client, Reader, Writer};
 use websocket::{Message, OwnedMessage};
 
+type SharedWriter = Arc<Mutex<Writer<TcpStream>>>;
+type EventSender = Arc<Mutex<Option<UnboundedSender<ConnectionEvent>>>>;
+
+/// 실시간 연결이 끊겼을 때의 재연결 정책
+#[derive(Clone, Debug)]
+pub struct ReconnectConfig {
+    pub max_retries: u32,     // 연속 재연결 시도 횟수(모두 실패하면 스트림 종료)
+    pub base_delay: Duration, // 첫 재연결 대기 시간(시도마다 2배)
+    pub max_delay: Duration,  // 최대 대기 시간
+    pub maintenance: Option<MaintenanceWindow>, // 서버 점검 시간대(None이면 구분하지 않음)
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            maintenance: Some(MaintenanceWindow::default()),
+        }
+    }
+}
+
+/// KIS 웹소켓 서버 점검(재시작) 시간대(KST)
+/// 이 시간대에는 재연결이 실패하므로 재연결 시도 횟수를 쓰지 않고 backoff 간격으로 기다림
+#[derive(Clone, Debug)]
+pub struct MaintenanceWindow {
+    pub start: NaiveTime,  // 시작 시각
+    pub end: NaiveTime,    // 종료 시각(start보다 이르면 다음 날 종료)
+    pub backoff: Duration, // 점검 중 재연결 대기 간격
+}
+
+impl Default for MaintenanceWindow {
+    /// 매일 05:00~05:30, 1분 간격
+    fn default() -> Self {
+        Self {
+            start: NaiveTime::from_hms_opt(5, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(5, 30, 0).unwrap(),
+            backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+impl MaintenanceWindow {
+    /// time이 점검 시간대인지 확인
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.start <= self.end {
+            self.start <= time && time < self.end
+        } else {
+            self.start <= time || time < self.end
+        }
+    }
+
+    /// time에 점검 중이면 다음 재연결까지 기다릴 시간(backoff와 종료까지 남은 시간 중 짧은 쪽)
+    pub fn backoff_at(&self, time: NaiveTime) -> Option<Duration> {
+        if !self.contains(time) {
+            return None;
+        }
+        let remaining = self
+            .end
+            .signed_duration_since(time)
+            .num_seconds()
+            .rem_euclid(24 * 60 * 60) as u64;
+        Some(self.backoff.min(Duration::from_secs(remaining.max(1))))
+    }
+}
+
+impl ReconnectConfig {
+    fn delay(&self, attempt: u32) -> Duration {
+        self.base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay)
+    }
+}
+
+/// close에서 서버의 close frame을 기다리는 최대 시간
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 웹소켓 접속키 하나로 등록할 수 있는 실시간 구독 수
+pub const MAX_SUBSCRIPTIONS: usize = 41;
+
+/// 등록되어 있는 실시간 구독
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Subscription {
+    pub tr_id: TrId,
+    pub tr_key: String, // 종목코드 또는 HTS ID
+}
+
+/// 실시간 연결 상태(KoreaStockData::connection_events)
+#[derive(Debug)]
+pub enum ConnectionEvent {
+    Connected(TrId), // 연결 및 구독 등록(재연결 시 구독 복구) 완료
+    Reconnecting { tr_id: TrId, attempt: u32 }, // 연결이 끊겨 재연결 시도 중
+    Maintenance { tr_id: TrId, until: NaiveTime }, // 서버 점검 시간대라 until(KST)까지 긴 간격으로 재연결 대기
+    Disconnected(TrId, Error), // 재연결에 실패하여 스트림 종료(Error::WebsocketClosed)
+}
+
+/// subscribe_batch로 구독한 실시간 데이터
+#[derive(Clone, Debug)]
+pub enum RealtimeEvent {
+    Trade(Exec),       // 실시간 체결가(H0STCNT0)
+    Orderbook(Ordb),   // 실시간 호가(H0STASP0)
+    Execution(MyExec), // 체결통보(H0STCNI0 / 모의투자 H0STCNI9)
+}
+
+impl RealtimeEvent {
+    /// 웹소켓 프레임 하나를 레코드 단위로 나누어 파싱
+    /// 데이터건수만큼 레코드가 묶여 오는 프레임도 레코드마다 하나의 RealtimeEvent를 반환하며 PINGPONG은 제외
+    /// 데이터건수나 tr_id별 필드 수가 맞지 않으면 Error::MalformedFrame
+    pub fn parse_frame(s: &str, response: &SubscribeResponse) -> Result<Vec<Self>, Error> {
+        let mut events = Vec::new();
+        for record in split_records(s)? {
+            if let Some(event) = Self::parse(record, response)? {
+                events.push(event);
+            }
+        }
+        Ok(events)
+    }
+
+    /// 레코드 하나(데이터건수 001)를 tr_id에 맞게 파싱. PINGPONG이면 None
+    fn parse(s: String, response: &SubscribeResponse) -> Result<Option<Self>, Error> {
+        let tr_id = if s.starts_with('{') {
+            Exec::parse(s.clone())?.header().tr_id().clone() // JSON은 header만 읽음
+        } else {
+            s.split('|').nth(1).unwrap_or_default().into()
+        };
+        let event = match tr_id {
+            TrId::PingPong => return Ok(None),
+            TrId::RealtimeExec => Self::Trade(Exec::parse(s)?),
+            TrId::RealtimeOrdb => Self::Orderbook(Ordb::parse(s)?),
+            TrId::RealRealtimeMyExec | TrId::VirtualRealtimeMyExec => {
+                Self::Execution(MyExec::parse(
+                    s,
+                    response.iv().clone().unwrap_or_default(),
+                    response.key().clone().unwrap_or_default(),
+                )?)
+            }
+            tr_id => return Err(Error::WrongTrId(tr_id, "H0STCNT0, H0STASP0 or H0STCNI0")),
+        };
+        Ok(Some(event))
+    }
+}
+
 pub struct KoreaStockData {
     exec_client: websocket::ClientBuilder<'static>,
     ordb_client: websocket::ClientBuilder<'static>,
     my_exec_client: websocket::ClientBuilder<'static>,
+    #[allow(dead_code)]
     endpoint_url: String,
     environment: Environment,
     auth: auth::Auth,
+    #[allow(dead_code)]
     account: Account,
     hts_id: String,
     handles: HashMap<TrId, tokio::task::JoinHandle<()>>,
+    writers: HashMap<TrId, SharedWriter>,
+    subscriptions: HashMap<TrId, Arc<Mutex<Vec<String>>>>, // 재연결 시 다시 등록할 tr_key
+    reconnect: ReconnectConfig,
+    events: EventSender,
+    closing: Arc<AtomicBool>, // close 또는 drop 이후 재연결하지 않음
 }
 
 impl KoreaStockData {
@@ -27,28 +187,24 @@ impl KoreaStockData {
         account: Account,
         hts_id: &str,
     ) -> Result<Self, Error> {
-        let endpoint_url = match environment {
-            Environment::Real => "ws://ops.koreainvestment.com:21000",
-            Environment::Virtual => "ws://ops.koreainvestment.com:31000",
-        }
-        .to_string();
+        let endpoint_url = auth.endpoints().websocket.clone();
         let exec_client = websocket::ClientBuilder::new(&format!(
             "{}/tryitout/{}",
             endpoint_url,
-            Into::<String>::into(TrId::RealtimeExec),
+            TrId::RealtimeExec,
         ))?;
         let ordb_client = websocket::ClientBuilder::new(&format!(
             "{}/tryitout/{}",
             endpoint_url,
-            Into::<String>::into(TrId::RealtimeOrdb),
+            TrId::RealtimeOrdb,
         ))?;
         let my_exec_client = websocket::ClientBuilder::new(&format!(
             "{}/tryitout/{}",
             endpoint_url,
-            Into::<String>::into(match environment {
+            match environment {
                 Environment::Real => TrId::RealRealtimeMyExec,
                 Environment::Virtual => TrId::VirtualRealtimeMyExec,
-            })
+            }
         ))?;
 
         Ok(Self {
@@ -61,9 +217,30 @@ impl KoreaStockData {
             account,
             hts_id: hts_id.to_string(),
             handles: HashMap::new(),
+            writers: HashMap::new(),
+            subscriptions: HashMap::new(),
+            reconnect: ReconnectConfig::default(),
+            events: Arc::new(Mutex::new(None)),
+            closing: Arc::new(AtomicBool::new(false)),
         })
     }
 
+    /// 연결이 끊겼을 때의 재연결 정책 설정
+    pub fn with_reconnect(mut self, reconnect: ReconnectConfig) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
+    /// 실시간 연결 상태 수신
+    /// 다시 호출하면 이전 receiver에는 더 이상 전달되지 않음
+    pub fn connection_events(&mut self) -> UnboundedReceiver<ConnectionEvent> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        if let Ok(mut events) = self.events.lock() {
+            *events = Some(tx);
+        }
+        rx
+    }
+
     /// 종목 시세 구독
     pub fn subscribe_market<T: StreamParser<R> + Send, R: Clone + Send>(
         &mut self,
@@ -76,106 +253,49 @@ impl KoreaStockData {
         ),
         Error,
     > {
-        let app_key = self.auth.get_appkey();
-        let app_secret = self.auth.get_appsecret();
-        let personalseckey = self.auth.get_approval_key().unwrap();
-        let msg = SubscribeRequest::new(
-            app_key,
-            app_secret,
-            personalseckey,
-            CustomerType::Personal,
-            isin.to_string(),
-            tr_id.clone(),
-        )
-        .get_json_string();
-        let msg = Message::text(msg);
-        let mut result = SubscribeResponse::new(false, "".to_string(), None, None);
-        let mut conn = match tr_id {
-            TrId::RealtimeExec => self.exec_client.connect_insecure().unwrap(),
-            TrId::RealtimeOrdb => self.ordb_client.connect_insecure().unwrap(),
+        self.check_quota(&tr_id, isin)?;
+        let client = match tr_id {
+            TrId::RealtimeExec => self.exec_client.clone(),
+            TrId::RealtimeOrdb => self.ordb_client.clone(),
             _ => {
                 return Err(Error::WrongTrId(tr_id, "RealtimeExec or RealtimeOrdb"));
             }
         };
-        loop {
-            if let Ok(msg) = {
-                let _ = conn.send_message(&msg);
-                conn.recv_message()
-            } {
-                match msg {
-                    OwnedMessage::Text(s) => {
-                        let json_value = json::parse(&s)?;
-                        match json_value {
-                            json::JsonValue::Object(obj) => {
-                                if let Some(header) = obj.get("header") {
-                                    if let json::JsonValue::Object(o) = header {
-                                        if let Some(result_tr) = o.get("tr_id") {
-                                            if &result_tr.to_string() == "PINGPONG" {
-                                                continue;
-                                            }
-                                        }
-                                    }
-                                }
-                                if let Some(v) = obj.get("body") {
-                                    match v {
-                                        json::JsonValue::Object(o) => {
-                                            if let Some(s) = o.get("msg1") {
-                                                let s = s.to_string();
-                                                if &s == "SUBSCRIBE SUCCESS" {
-                                                    result.set_success(true);
-                                                }
-                                                result.set_msg(s);
-                                            }
-                                            if let Some(json::JsonValue::Object(o)) =
-                                                o.get("output")
-                                            {
-                                                if let Some(s) = o.get("iv") {
-                                                    result.set_iv(Some(s.to_string()));
-                                                }
-                                                if let Some(s) = o.get("key") {
-                                                    result.set_key(Some(s.to_string()));
-                                                }
-                                            }
-                                        }
-                                        _ => {}
-                                    }
-                                }
-                            }
-                            _ => {}
-                        }
-                    }
-                    _ => {}
-                }
+        let mut conn = client.clone().connect_insecure()?;
+        let result = subscribe_on(&mut conn, &self.auth, isin, tr_id.clone())?;
+        let keys = self.subscriptions.entry(tr_id.clone()).or_default().clone();
+        if let Ok(mut keys) = keys.lock() {
+            if !keys.iter().any(|k| k == isin) {
+                keys.push(isin.to_string());
             }
-            break;
         }
         let handle_ref = self.handles.get(&tr_id);
         if handle_ref.is_none() || handle_ref.unwrap().is_finished() {
+            let (reader, writer) = conn.split()?;
+            let writer = Arc::new(Mutex::new(writer));
+            self.writers.insert(tr_id.clone(), writer.clone());
             let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-            let handle = tokio::spawn(async move {
-                loop {
-                    if let Ok(msg) = conn.recv_message() {
-                        let tmp_msg = msg.clone();
-                        match msg {
-                            OwnedMessage::Text(s) => {
-                                let data = T::parse(s).expect("Failed to parse message");
-                                if *data.header().tr_id() == TrId::PingPong {
-                                    let _ = conn.send_message(&tmp_msg);
-                                } else {
-                                    let _ = tx.send(data);
-                                }
-                            }
-                            _ => {
-                                error!("Get wrong data from stream={:?}", msg);
-                                panic!();
-                            }
-                        }
-                    } else {
-                        error!("Failed to get message from stream");
-                        panic!();
-                    }
-                }
-            });
+            let supervisor = Supervisor {
+                client,
+                tr_id: tr_id.clone(),
+                auth: self.auth.clone(),
+                keys,
+                writer,
+                events: self.events.clone(),
+                config: self.reconnect.clone(),
+                closing: self.closing.clone(),
+            };
+            supervisor.emit(ConnectionEvent::Connected(tr_id.clone()));
+            let handle = tokio::spawn(run_stream(
+                reader,
+                supervisor,
+                result.clone(),
+                tx,
+                |s: String, _: &SubscribeResponse| {
+                    let data = T::parse(s)?;
+                    Ok((*data.header().tr_id() != TrId::PingPong).then_some(data))
+                },
+            ));
             self.handles.insert(tr_id, handle);
 
             return Ok((Some(rx), result));
@@ -183,130 +303,1105 @@ impl KoreaStockData {
         Ok((None, result))
     }
 
-    /// 체결통보 구독
-    pub fn subscribe_my_exec(
+    /// 실시간 체결가 구독(H0STCNT0)
+    /// 이미 체결가 스트림이 동작 중이면 receiver로 None을 반환
+    pub fn subscribe_trade(
         &mut self,
+        shortcode: &str,
     ) -> Result<
         (
-            tokio::sync::mpsc::UnboundedReceiver<MyExec>,
+            Option<tokio::sync::mpsc::UnboundedReceiver<Exec>>,
             SubscribeResponse,
         ),
         Error,
     > {
-        let app_key = self.auth.get_appkey();
-        let app_secret = self.auth.get_appsecret();
-        let personalseckey = self.auth.get_approval_key().expect("No approval key");
-        let tr_id = match self.environment {
-            Environment::Real => TrId::RealRealtimeMyExec,
-            Environment::Virtual => TrId::VirtualRealtimeMyExec,
-        };
+        self.subscribe_market::<Exec, _>(shortcode, TrId::RealtimeExec)
+    }
+
+    /// 실시간 호가 구독(H0STASP0)
+    /// 이미 호가 스트림이 동작 중이면 receiver로 None을 반환
+    pub fn subscribe_orderbook(
+        &mut self,
+        shortcode: &str,
+    ) -> Result<
+        (
+            Option<tokio::sync::mpsc::UnboundedReceiver<Ordb>>,
+            SubscribeResponse,
+        ),
+        Error,
+    > {
+        self.subscribe_market::<Ordb, _>(shortcode, TrId::RealtimeOrdb)
+    }
+
+    /// 등록되어 있는 실시간 구독 목록
+    pub fn active_subscriptions(&self) -> Vec<Subscription> {
+        self.subscriptions
+            .iter()
+            .flat_map(|(tr_id, keys)| {
+                keys.lock()
+                    .map(|keys| keys.clone())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|tr_key| Subscription {
+                        tr_id: tr_id.clone(),
+                        tr_key,
+                    })
+            })
+            .collect()
+    }
+
+    /// 등록되어 있는 모든 실시간 구독 해제
+    pub fn unsubscribe_all(&mut self) -> Result<(), Error> {
+        for subscription in self.active_subscriptions() {
+            self.unsubscribe(&subscription.tr_key, subscription.tr_id)?;
+        }
+        Ok(())
+    }
+
+    /// 실시간 연결 종료
+    /// 모든 구독을 해제하고 close frame을 보낸 뒤 서버가 연결을 닫을 때까지(최대 5초) 기다림
+    /// drop만 하면 KIS에 구독이 남아 다음 프로세스의 구독 수(MAX_SUBSCRIPTIONS)를 차지할 수 있으므로 종료 시 호출 권장
+    /// 구독 해제에 실패해도 연결은 모두 닫으며, 첫 번째 에러를 반환
+    pub async fn close(mut self) -> Result<(), Error> {
+        let result = self.shutdown();
+        for (tr_id, handle) in self.handles.drain() {
+            let abort = handle.abort_handle();
+            if tokio::time::timeout(CLOSE_TIMEOUT, handle).await.is_err() {
+                warn!("Timed out waiting for {:?} stream to close", tr_id);
+                abort.abort();
+            }
+        }
+        result
+    }
+
+    /// 구독 해제 및 close frame 전송. 이후 스트림은 재연결하지 않음
+    fn shutdown(&mut self) -> Result<(), Error> {
+        if self.closing.swap(true, Ordering::SeqCst) {
+            return Ok(());
+        }
+        let mut result = Ok(());
+        for subscription in self.active_subscriptions() {
+            if let Err(e) = self.unsubscribe(&subscription.tr_key, subscription.tr_id) {
+                error!("Failed to unsubscribe {}: {:?}", subscription.tr_key, e);
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        for (tr_id, writer) in self.writers.iter() {
+            let sent = match writer.lock() {
+                Ok(mut writer) => writer
+                    .send_message(&OwnedMessage::Close(None))
+                    .map_err(Error::from),
+                Err(_) => Err(Error::BrokenProtocol(
+                    "writer lock poisoned",
+                    tr_id.to_string(),
+                )),
+            };
+            if let Err(e) = sent {
+                error!("Failed to send close frame {:?}: {:?}", tr_id, e);
+                if result.is_ok() {
+                    result = Err(e);
+                }
+            }
+        }
+        result
+    }
+
+    /// 실시간 호가 구독 해제(H0STASP0)
+    pub fn unsubscribe_orderbook(&mut self, shortcode: &str) -> Result<(), Error> {
+        self.unsubscribe(shortcode, TrId::RealtimeOrdb)
+    }
+
+    /// 구독 해제(tr_type: "2") 메시지 전송
+    fn unsubscribe(&mut self, tr_key: &str, tr_id: TrId) -> Result<(), Error> {
+        let personalseckey = self
+            .auth
+            .get_approval_key()
+            .ok_or(Error::AuthInitFailed("approval_key"))?;
+        let writer = self
+            .writers
+            .get(&tr_id)
+            .ok_or(Error::BrokenProtocol("not subscribed", tr_id.to_string()))?;
         let msg = SubscribeRequest::new(
-            app_key,
-            app_secret,
+            self.auth.get_appkey(),
+            self.auth.get_appsecret(),
             personalseckey,
             CustomerType::Personal,
-            self.hts_id.clone(),
+            tr_key.to_string(),
             tr_id.clone(),
         )
+        .with_tr_type(TrType::Unregister)
         .get_json_string();
-        let msg = Message::text(msg);
-        let mut conn = match tr_id {
-            TrId::RealRealtimeMyExec => self.my_exec_client.connect_insecure().unwrap(),
-            TrId::VirtualRealtimeMyExec => self.my_exec_client.connect_insecure().unwrap(),
-            _ => {
+        writer
+            .lock()
+            .map_err(|_| Error::BrokenProtocol("writer lock poisoned", msg.clone()))?
+            .send_message(&Message::text(msg))?;
+        if let Some(Ok(mut keys)) = self.subscriptions.get(&tr_id).map(|keys| keys.lock()) {
+            keys.retain(|k| k != tr_key);
+        }
+        Ok(())
+    }
+
+    /// 체결통보 구독(생성 시 설정한 HTS ID)
+    /// 파싱에 실패한 메시지는 로그를 남기고 건너뜀
+    pub fn subscribe_my_exec(
+        &mut self,
+    ) -> Result<
+        (
+            tokio::sync::mpsc::UnboundedReceiver<MyExec>,
+            SubscribeResponse,
+        ),
+        Error,
+    > {
+        let hts_id = self.hts_id.clone();
+        self.subscribe_my_exec_with(&hts_id, |s: String, response: &SubscribeResponse| {
+            let data = MyExec::parse(
+                s,
+                response.iv().clone().unwrap_or_default(),
+                response.key().clone().unwrap_or_default(),
+            )?;
+            Ok((data.header().tr_id() != &TrId::PingPong).then_some(data))
+        })
+    }
+
+    /// 체결통보 구독(H0STCNI0 / 모의투자 H0STCNI9)
+    /// 체결통보는 구독 응답으로 받은 key/iv로 AES-256-CBC 복호화하여 ExecutionNotice로 파싱하며,
+    /// 복호화에 실패한 메시지는 Err(Error::DecryptFailed), 필드 파싱에 실패한 메시지는 해당 Err로 수신
+    pub fn subscribe_executions(
+        &mut self,
+        hts_id: &str,
+    ) -> Result<
+        (
+            tokio::sync::mpsc::UnboundedReceiver<Result<ExecutionNotice, Error>>,
+            SubscribeResponse,
+        ),
+        Error,
+    > {
+        self.subscribe_my_exec_with(hts_id, parse_execution_notice)
+    }
+
+    /// 체결통보 TR에 hts_id를 등록하고 수신한 레코드를 parse로 변환하여 전달하는 스트림 시작
+    fn subscribe_my_exec_with<T, F>(
+        &mut self,
+        hts_id: &str,
+        parse: F,
+    ) -> Result<(UnboundedReceiver<T>, SubscribeResponse), Error>
+    where
+        T: Send + 'static,
+        F: FnMut(String, &SubscribeResponse) -> Result<Option<T>, Error> + Send + 'static,
+    {
+        let tr_id = match self.environment {
+            Environment::Real => TrId::RealRealtimeMyExec,
+            Environment::Virtual => TrId::VirtualRealtimeMyExec,
+        };
+        self.check_quota(&tr_id, hts_id)?;
+        let client = self.my_exec_client.clone();
+        let mut conn = client.clone().connect_insecure()?;
+        let result = subscribe_on(&mut conn, &self.auth, hts_id, tr_id.clone())?;
+        if result.iv().is_none() || result.key().is_none() {
+            return Err(Error::BrokenProtocol(
+                "no iv/key in subscribe response",
+                result.msg().clone(),
+            ));
+        }
+        if let Some(handle) = self.handles.get(&tr_id) {
+            handle.abort();
+        }
+        let keys = Arc::new(Mutex::new(vec![hts_id.to_string()]));
+        self.subscriptions.insert(tr_id.clone(), keys.clone());
+        let (reader, writer) = conn.split()?;
+        let writer = Arc::new(Mutex::new(writer));
+        self.writers.insert(tr_id.clone(), writer.clone());
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        let supervisor = Supervisor {
+            client,
+            tr_id: tr_id.clone(),
+            auth: self.auth.clone(),
+            keys,
+            writer,
+            events: self.events.clone(),
+            config: self.reconnect.clone(),
+            closing: self.closing.clone(),
+        };
+        supervisor.emit(ConnectionEvent::Connected(tr_id.clone()));
+        // 재연결하면 구독 응답의 key/iv가 바뀌므로 최신 응답으로 복호화
+        let handle = tokio::spawn(run_stream(reader, supervisor, result.clone(), tx, parse));
+        self.handles.insert(tr_id, handle);
+        Ok((rx, result))
+    }
+
+    /// 여러 실시간 구독을 한 번에 등록하고 하나의 스트림으로 수신
+    /// tr_id별로 한 번만 연결하여 등록 메시지를 차례로 보내며, 전체 구독 수는 등록 전에 확인
+    /// 체결통보는 tr_key로 HTS ID를 전달. 이미 스트림이 동작 중인 tr_id는 Error::BrokenProtocol
+    pub fn subscribe_batch(
+        &mut self,
+        subs: Vec<Subscription>,
+    ) -> Result<BoxStream<'static, RealtimeEvent>, Error> {
+        let my_exec_tr_id = match self.environment {
+            Environment::Real => TrId::RealRealtimeMyExec,
+            Environment::Virtual => TrId::VirtualRealtimeMyExec,
+        };
+        let mut batches: Vec<(TrId, Vec<String>)> = Vec::new();
+        for sub in subs {
+            if sub.tr_id != TrId::RealtimeExec
+                && sub.tr_id != TrId::RealtimeOrdb
+                && sub.tr_id != my_exec_tr_id
+            {
                 return Err(Error::WrongTrId(
-                    tr_id,
-                    "RealRealtimeMyExec or VirtualRealtimeMyExec",
+                    sub.tr_id,
+                    "RealtimeExec, RealtimeOrdb or RealtimeMyExec",
                 ));
             }
-        };
-        let _ = conn.send_message(&msg);
+            match batches.iter_mut().find(|(tr_id, _)| tr_id == &sub.tr_id) {
+                Some((_, keys)) if keys.contains(&sub.tr_key) => {}
+                Some((_, keys)) => keys.push(sub.tr_key),
+                None => batches.push((sub.tr_id, vec![sub.tr_key])),
+            }
+        }
+        for (tr_id, _) in batches.iter() {
+            if self.handles.get(tr_id).is_some_and(|h| !h.is_finished()) {
+                return Err(Error::BrokenProtocol(
+                    "already streaming",
+                    tr_id.to_string(),
+                ));
+            }
+        }
+        if let Some((_, keys)) = batches.iter().find(|(tr_id, _)| tr_id == &my_exec_tr_id) {
+            if keys.len() > 1 {
+                return Err(Error::InvalidParam {
+                    field: "keys",
+                    reason: "only one HTS ID per execution stream",
+                });
+            }
+        }
+        let active = self.active_subscriptions();
+        let current = active
+            .iter()
+            .filter(|s| !batches.iter().any(|(tr_id, _)| tr_id == &s.tr_id))
+            .count();
+        let requested: usize = batches.iter().map(|(_, keys)| keys.len()).sum();
+        if current + requested > MAX_SUBSCRIPTIONS {
+            return Err(Error::SubscriptionLimitExceeded {
+                current,
+                max: MAX_SUBSCRIPTIONS,
+            });
+        }
+
+        let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+        for (tr_id, tr_keys) in batches {
+            let client = match tr_id {
+                TrId::RealtimeExec => self.exec_client.clone(),
+                TrId::RealtimeOrdb => self.ordb_client.clone(),
+                _ => self.my_exec_client.clone(),
+            };
+            let mut conn = client.clone().connect_insecure()?;
+            let mut result = SubscribeResponse::new(false, "".to_string(), None, None);
+            for tr_key in tr_keys.iter() {
+                result = subscribe_on(&mut conn, &self.auth, tr_key, tr_id.clone())?;
+                if !result.success() {
+                    return Err(Error::BrokenProtocol(
+                        "subscribe rejected",
+                        result.msg().clone(),
+                    ));
+                }
+            }
+            if tr_id == my_exec_tr_id && (result.iv().is_none() || result.key().is_none()) {
+                return Err(Error::BrokenProtocol(
+                    "no iv/key in subscribe response",
+                    result.msg().clone(),
+                ));
+            }
+            let keys = Arc::new(Mutex::new(tr_keys));
+            self.subscriptions.insert(tr_id.clone(), keys.clone());
+            let (reader, writer) = conn.split()?;
+            let writer = Arc::new(Mutex::new(writer));
+            self.writers.insert(tr_id.clone(), writer.clone());
+            let supervisor = Supervisor {
+                client,
+                tr_id: tr_id.clone(),
+                auth: self.auth.clone(),
+                keys,
+                writer,
+                events: self.events.clone(),
+                config: self.reconnect.clone(),
+                closing: self.closing.clone(),
+            };
+            supervisor.emit(ConnectionEvent::Connected(tr_id.clone()));
+            let handle = tokio::spawn(run_stream(
+                reader,
+                supervisor,
+                result,
+                tx.clone(),
+                |s: String, response: &SubscribeResponse| RealtimeEvent::parse(s, response),
+            ));
+            self.handles.insert(tr_id, handle);
+        }
+        Ok(futures::stream::poll_fn(move |cx| rx.poll_recv(cx)).boxed())
+    }
+
+    /// 새 구독이 MAX_SUBSCRIPTIONS를 넘으면 Error::SubscriptionLimitExceeded
+    /// KIS는 초과 등록을 거부하거나 기존 구독을 해제하므로 요청 전에 확인
+    /// 체결통보는 tr_id별로 하나만 유지하므로 같은 tr_id의 기존 등록은 제외하고 셈
+    fn check_quota(&self, tr_id: &TrId, tr_key: &str) -> Result<(), Error> {
+        let subscriptions = self.active_subscriptions();
+        if subscriptions
+            .iter()
+            .any(|s| &s.tr_id == tr_id && s.tr_key == tr_key)
+        {
+            return Ok(());
+        }
+        let current = subscriptions
+            .iter()
+            .filter(|s| {
+                !(&s.tr_id == tr_id
+                    && matches!(
+                        tr_id,
+                        TrId::RealRealtimeMyExec | TrId::VirtualRealtimeMyExec
+                    ))
+            })
+            .count();
+        if current >= MAX_SUBSCRIPTIONS {
+            return Err(Error::SubscriptionLimitExceeded {
+                current,
+                max: MAX_SUBSCRIPTIONS,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// close를 호출하지 않고 drop하면 구독 해제와 close frame 전송만 시도(서버 응답은 기다리지 않음)
+impl Drop for KoreaStockData {
+    fn drop(&mut self) {
+        if let Err(e) = self.shutdown() {
+            warn!("Failed to close realtime connections on drop: {:?}", e);
+        }
+    }
+}
+
+/// 연결이 끊긴 스트림의 재연결과 구독 복구
+struct Supervisor {
+    client: websocket::ClientBuilder<'static>,
+    tr_id: TrId,
+    auth: auth::Auth,
+    keys: Arc<Mutex<Vec<String>>>,
+    writer: SharedWriter,
+    events: EventSender,
+    config: ReconnectConfig,
+    closing: Arc<AtomicBool>,
+}
+
+impl Supervisor {
+    fn is_closing(&self) -> bool {
+        self.closing.load(Ordering::SeqCst)
+    }
+
+    fn emit(&self, event: ConnectionEvent) {
+        if let Ok(events) = self.events.lock() {
+            if let Some(tx) = events.as_ref() {
+                let _ = tx.send(event);
+            }
+        }
+    }
+
+    /// backoff 후 재연결을 반복하고, max_retries번 모두 실패하면 Error::WebsocketClosed
+    /// 점검 시간대에는 MaintenanceWindow의 backoff 간격으로 재연결하며 시도 횟수를 세지 않음
+    async fn reconnect(&mut self) -> Result<(Reader<TcpStream>, SubscribeResponse), Error> {
+        let mut attempt = 0;
+        while attempt < self.config.max_retries {
+            let now = Time::now().inner().time();
+            let maintenance = self
+                .config
+                .maintenance
+                .as_ref()
+                .and_then(|window| window.backoff_at(now).map(|backoff| (window.end, backoff)));
+            if let Some((until, backoff)) = maintenance {
+                self.emit(ConnectionEvent::Maintenance {
+                    tr_id: self.tr_id.clone(),
+                    until,
+                });
+                tokio::time::sleep(backoff).await;
+            } else {
+                attempt += 1;
+                self.emit(ConnectionEvent::Reconnecting {
+                    tr_id: self.tr_id.clone(),
+                    attempt,
+                });
+                tokio::time::sleep(self.config.delay(attempt)).await;
+            }
+            match self.resubscribe().await {
+                Ok(connected) => {
+                    self.emit(ConnectionEvent::Connected(self.tr_id.clone()));
+                    return Ok(connected);
+                }
+                Err(e) => error!("Failed to reconnect {:?}: {:?}", self.tr_id, e),
+            }
+        }
+        Err(Error::WebsocketClosed(self.config.max_retries))
+    }
+
+    /// 새로 연결하여 등록되어 있던 모든 tr_key를 다시 구독
+    /// 구독이 거부되면 웹소켓 접속키를 새로 발급받고 다음 시도에서 사용
+    async fn resubscribe(&mut self) -> Result<(Reader<TcpStream>, SubscribeResponse), Error> {
+        let keys = self.keys.lock().map(|k| k.clone()).unwrap_or_default();
+        let mut conn = self.client.clone().connect_insecure()?;
         let mut result = SubscribeResponse::new(false, "".to_string(), None, None);
+        for key in keys.iter() {
+            result = subscribe_on(&mut conn, &self.auth, key, self.tr_id.clone())?;
+            if !result.success() {
+                break;
+            }
+        }
+        if !keys.is_empty() && !result.success() {
+            drop(conn);
+            self.auth.create_approval_key().await?;
+            return Err(Error::BrokenProtocol(
+                "resubscribe rejected",
+                result.msg().clone(),
+            ));
+        }
+        let (reader, writer) = conn.split()?;
+        *self
+            .writer
+            .lock()
+            .map_err(|_| Error::BrokenProtocol("writer lock poisoned", String::new()))? = writer;
+        Ok((reader, result))
+    }
+}
+
+/// 체결통보 레코드를 ExecutionNotice로 파싱(subscribe_executions)
+/// PINGPONG은 None, 파싱 실패는 수신측에 Err로 전달
+fn parse_execution_notice(
+    s: String,
+    response: &SubscribeResponse,
+) -> Result<Option<Result<ExecutionNotice, Error>>, Error> {
+    let data = match MyExec::parse(
+        s,
+        response.iv().clone().unwrap_or_default(),
+        response.key().clone().unwrap_or_default(),
+    ) {
+        Ok(data) => data,
+        Err(e) => return Ok(Some(Err(e))),
+    };
+    if data.header().tr_id() == &TrId::PingPong {
+        return Ok(None);
+    }
+    match data.execution_notice() {
+        Some(notice) => Ok(Some(Ok(notice))),
+        None => Err(Error::BrokenProtocol(
+            "execution notice without body",
+            data.header().tr_id().to_string(),
+        )),
+    }
+}
 
-        loop {
-            if let Ok(msg) = conn.recv_message() {
-                match msg {
-                    OwnedMessage::Text(s) => {
-                        let json_value = json::parse(&s)?;
-                        match json_value {
-                            json::JsonValue::Object(obj) => {
-                                if let Some(header) = obj.get("header") {
-                                    if let json::JsonValue::Object(o) = header {
-                                        if let Some(result_tr) = o.get("tr_id") {
-                                            if &result_tr.to_string() == "PINGPONG" {
-                                                continue;
-                                            }
-                                        }
-                                    }
-                                }
-                                if let Some(v) = obj.get("body") {
-                                    match v {
-                                        json::JsonValue::Object(o) => {
-                                            if let Some(s) = o.get("msg1") {
-                                                let s = s.to_string();
-                                                if &s == "SUBSCRIBE SUCCESS" {
-                                                    result.set_success(true);
-                                                }
-                                                result.set_msg(s);
-                                            }
-                                            if let Some(json::JsonValue::Object(o)) =
-                                                o.get("output")
-                                            {
-                                                if let Some(s) = o.get("iv") {
-                                                    result.set_iv(Some(s.to_string()));
-                                                }
-                                                if let Some(s) = o.get("key") {
-                                                    result.set_key(Some(s.to_string()));
-                                                }
-                                            }
-                                        }
-                                        _ => {}
-                                    }
-                                }
+/// 실시간 메시지를 파싱하여 tx로 전달
+/// parse가 None을 반환하면 PINGPONG으로 보고 그대로 돌려보냄
+/// 연결이 끊기면 Supervisor로 재연결하며, 재연결에 실패하거나 수신측이 drop되면 종료
+async fn run_stream<T, F>(
+    mut reader: Reader<TcpStream>,
+    mut supervisor: Supervisor,
+    mut response: SubscribeResponse,
+    tx: UnboundedSender<T>,
+    mut parse: F,
+) where
+    F: FnMut(String, &SubscribeResponse) -> Result<Option<T>, Error>,
+{
+    loop {
+        match reader.recv_message() {
+            Ok(OwnedMessage::Text(s)) => {
+                let records = match split_records(&s) {
+                    Ok(records) => records,
+                    Err(e) => {
+                        error!("Failed to parse message: {:?}", e);
+                        continue;
+                    }
+                };
+                let mut dropped = false;
+                for record in records {
+                    match parse(record.clone(), &response) {
+                        Ok(Some(data)) => {
+                            if tx.send(data).is_err() {
+                                dropped = true; // 수신측이 drop됨
+                                break;
+                            }
+                        }
+                        Ok(None) => {
+                            if let Ok(mut writer) = supervisor.writer.lock() {
+                                let _ = writer.send_message(&OwnedMessage::Text(record));
                             }
-                            _ => {}
                         }
+                        Err(e) => error!("Failed to parse message: {:?}", e),
                     }
-                    _ => {}
                 }
+                if dropped {
+                    break;
+                }
+                continue;
+            }
+            Ok(OwnedMessage::Close(_)) if supervisor.is_closing() => break,
+            Ok(OwnedMessage::Close(_)) => error!("Stream closed by remote"),
+            Ok(msg) => {
+                error!("Get wrong data from stream={:?}", msg);
+                continue;
             }
+            Err(_) if supervisor.is_closing() => break,
+            Err(e) => error!("Failed to get message from stream: {:?}", e),
+        }
+        if tx.is_closed() || supervisor.is_closing() {
             break;
         }
-        let handle_ref = self.handles.get(&tr_id);
-        if handle_ref.is_some() {
-            handle_ref.unwrap().abort();
+        match supervisor.reconnect().await {
+            Ok((new_reader, new_response)) => {
+                reader = new_reader;
+                response = new_response;
+            }
+            Err(e) => {
+                let tr_id = supervisor.tr_id.clone();
+                supervisor.emit(ConnectionEvent::Disconnected(tr_id, e));
+                break;
+            }
+        }
+    }
+}
+
+/// 연결에 구독 등록(tr_type: "1") 메시지를 보내고 구독 응답을 반환
+fn subscribe_on(
+    conn: &mut Client<TcpStream>,
+    auth: &auth::Auth,
+    tr_key: &str,
+    tr_id: TrId,
+) -> Result<SubscribeResponse, Error> {
+    let personalseckey = auth
+        .get_approval_key()
+        .ok_or(Error::AuthInitFailed("approval_key"))?;
+    let msg = SubscribeRequest::new(
+        auth.get_appkey(),
+        auth.get_appsecret(),
+        personalseckey,
+        CustomerType::Personal,
+        tr_key.to_string(),
+        tr_id,
+    )
+    .get_json_string();
+    conn.send_message(&Message::text(msg))?;
+    let mut result = SubscribeResponse::new(false, "".to_string(), None, None);
+    loop {
+        if let Ok(OwnedMessage::Text(s)) = conn.recv_message() {
+            if parse_subscribe_response(&s, &mut result)? {
+                continue;
+            }
+        }
+        break;
+    }
+    Ok(result)
+}
+
+/// 구독 응답 메시지를 `result`에 반영
+/// PINGPONG 메시지인 경우 true를 반환
+fn parse_subscribe_response(s: &str, result: &mut SubscribeResponse) -> Result<bool, Error> {
+    if let json::JsonValue::Object(obj) = json::parse(s)? {
+        if let Some(json::JsonValue::Object(o)) = obj.get("header") {
+            if let Some(result_tr) = o.get("tr_id") {
+                if &result_tr.to_string() == "PINGPONG" {
+                    return Ok(true);
+                }
+            }
+        }
+        if let Some(json::JsonValue::Object(o)) = obj.get("body") {
+            if let Some(s) = o.get("msg1") {
+                let s = s.to_string();
+                if &s == "SUBSCRIBE SUCCESS" {
+                    result.set_success(true);
+                }
+                result.set_msg(s);
+            }
+            if let Some(json::JsonValue::Object(o)) = o.get("output") {
+                if let Some(s) = o.get("iv") {
+                    result.set_iv(Some(s.to_string()));
+                }
+                if let Some(s) = o.get("key") {
+                    result.set_key(Some(s.to_string()));
+                }
+            }
         }
-        let (iv, key) = (
-            result.iv().clone().expect("no iv"),
-            result.key().clone().expect("no key"),
+    }
+    Ok(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stock_data() -> KoreaStockData {
+        let auth = auth::Auth::new(
+            &reqwest::Client::new(),
+            Environment::Virtual,
+            "appkey",
+            "appsecret",
         );
-        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-        let handle = tokio::spawn(async move {
-            loop {
-                if let Ok(msg) = conn.recv_message() {
-                    let tmp_msg = msg.clone();
-                    match msg {
-                        OwnedMessage::Text(s) => {
-                            let data = MyExec::parse(s, iv.clone(), key.clone())
-                                .expect("Failed to parse message");
-                            if data.header().tr_id() == &TrId::PingPong {
-                                let _ = conn.send_message(&tmp_msg);
-                            } else {
-                                let _ = tx.send(data);
-                            }
-                        }
-                        _ => {
-                            error!("Get wrong data from stream={:?}", msg);
-                            panic!();
-                        }
+        KoreaStockData::new(
+            Environment::Virtual,
+            auth,
+            Account::new("12345678", "01").unwrap(),
+            "htsid",
+        )
+        .unwrap()
+    }
+
+    /// tr_id에 tr_keys가 등록되어 있는 것으로 설정
+    fn register(data: &mut KoreaStockData, tr_id: TrId, tr_keys: &[String]) {
+        data.subscriptions
+            .insert(tr_id, Arc::new(Mutex::new(tr_keys.to_vec())));
+    }
+
+    fn shortcodes(range: std::ops::Range<usize>) -> Vec<String> {
+        range.map(|i| format!("{:06}", i)).collect()
+    }
+
+    #[tokio::test]
+    async fn forty_second_subscription_errors() {
+        let mut data = stock_data();
+        register(&mut data, TrId::RealtimeExec, &shortcodes(0..21));
+        register(&mut data, TrId::RealtimeOrdb, &shortcodes(0..20));
+        assert_eq!(data.active_subscriptions().len(), MAX_SUBSCRIPTIONS);
+
+        let result = data.subscribe_orderbook("000020");
+        assert!(matches!(
+            result,
+            Err(Error::SubscriptionLimitExceeded {
+                current: 41,
+                max: 41
+            })
+        ));
+        // 이미 등록된 종목은 구독 수가 늘지 않으므로 허용
+        assert!(data.check_quota(&TrId::RealtimeOrdb, "000019").is_ok());
+    }
+
+    #[tokio::test]
+    async fn forty_first_subscription_is_allowed() {
+        let mut data = stock_data();
+        register(&mut data, TrId::RealtimeExec, &shortcodes(0..40));
+        assert!(data.check_quota(&TrId::RealtimeOrdb, "005930").is_ok());
+    }
+
+    #[tokio::test]
+    async fn batch_over_limit_errors() {
+        let mut data = stock_data();
+        register(&mut data, TrId::RealtimeOrdb, &shortcodes(0..40));
+        let subs = shortcodes(100..102)
+            .into_iter()
+            .map(|tr_key| Subscription {
+                tr_id: TrId::RealtimeExec,
+                tr_key,
+            })
+            .collect();
+        assert!(matches!(
+            data.subscribe_batch(subs),
+            Err(Error::SubscriptionLimitExceeded {
+                current: 40,
+                max: 41
+            })
+        ));
+    }
+
+    /// 실시간 체결가(H0STCNT0) 레코드 하나의 필드
+    fn exec_record(shortcode: &str, price: u32) -> String {
+        format!(
+            "{}^093354^{}^5^-100^-0.14^72023.83^72100^72400^71700^71900^71800^1^3052507^219853241700^5105^6937^1832^84.90^1366314^1159996^1^0.39^20.28^090020^5^-200^090820^5^-500^092619^2^200^20230612^20^N^65945^216924^1118750^2199206^0.05^2424142^125.92^0^^72100",
+            shortcode, price
+        )
+    }
+
+    fn trades(events: &[RealtimeEvent]) -> Vec<(String, u32)> {
+        events
+            .iter()
+            .map(|event| match event {
+                RealtimeEvent::Trade(exec) => {
+                    let body = exec.body().as_ref().unwrap();
+                    (body.shortcode.clone(), body.current_price)
+                }
+                other => panic!("unexpected event: {:?}", other),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_frame_yields_event_per_exec_record() {
+        let record = exec_record;
+        let frame = format!(
+            "0|H0STCNT0|002|{}^{}",
+            record("005930", 71900),
+            record("000660", 120500)
+        );
+        let response = SubscribeResponse::new(true, "SUBSCRIBE SUCCESS".to_string(), None, None);
+        let events = RealtimeEvent::parse_frame(&frame, &response).unwrap();
+        assert_eq!(
+            trades(&events),
+            [
+                ("005930".to_string(), 71900),
+                ("000660".to_string(), 120500)
+            ]
+        );
+
+        let truncated = format!("0|H0STCNT0|003|{}", record("005930", 71900));
+        assert!(matches!(
+            RealtimeEvent::parse_frame(&truncated, &response),
+            Err(Error::MalformedFrame(_))
+        ));
+    }
+
+    fn at(h: u32, m: u32, s: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, s).unwrap()
+    }
+
+    #[test]
+    fn maintenance_backoff_during_window() {
+        let window = MaintenanceWindow::default();
+        assert_eq!(window.backoff_at(at(4, 59, 59)), None);
+        assert_eq!(
+            window.backoff_at(at(5, 0, 0)),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(
+            window.backoff_at(at(5, 15, 0)),
+            Some(Duration::from_secs(60))
+        );
+        // 종료 직전에는 종료 시각까지만 기다림
+        assert_eq!(
+            window.backoff_at(at(5, 29, 30)),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(window.backoff_at(at(5, 30, 0)), None);
+    }
+
+    #[test]
+    fn maintenance_window_across_midnight() {
+        let window = MaintenanceWindow {
+            start: at(23, 50, 0),
+            end: at(0, 10, 0),
+            backoff: Duration::from_secs(120),
+        };
+        assert!(!window.contains(at(23, 49, 59)));
+        assert_eq!(
+            window.backoff_at(at(23, 55, 0)),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            window.backoff_at(at(0, 9, 0)),
+            Some(Duration::from_secs(60))
+        );
+        assert_eq!(window.backoff_at(at(0, 10, 0)), None);
+    }
+
+    #[test]
+    fn reconnect_backoff_outside_window() {
+        let config = ReconnectConfig::default();
+        let delays: Vec<u64> = (1..=7)
+            .map(|attempt| config.delay(attempt).as_secs())
+            .collect();
+        assert_eq!(delays, [1, 2, 4, 8, 16, 30, 30]);
+    }
+
+    /// 연결 하나를 받아 close frame까지 받은 메시지를 반환하는 웹소켓 서버
+    fn recording_server() -> (String, std::thread::JoinHandle<Vec<OwnedMessage>>) {
+        let mut server = websocket::sync::Server::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}", server.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut client = server.accept().ok().unwrap().accept().unwrap();
+            let mut messages = Vec::new();
+            while let Ok(message) = client.recv_message() {
+                let closed = matches!(message, OwnedMessage::Close(_));
+                messages.push(message);
+                if closed {
+                    let _ = client.send_message(&OwnedMessage::Close(None));
+                    break;
+                }
+            }
+            messages
+        });
+        (url, handle)
+    }
+
+    /// tr_id의 연결을 url의 서버로 열고 tr_keys가 등록되어 있는 것으로 설정
+    fn connect(data: &mut KoreaStockData, tr_id: TrId, url: &str, tr_keys: &[String]) {
+        let conn = websocket::ClientBuilder::new(url)
+            .unwrap()
+            .connect_insecure()
+            .unwrap();
+        let (_, writer) = conn.split().unwrap();
+        data.writers
+            .insert(tr_id.clone(), Arc::new(Mutex::new(writer)));
+        register(data, tr_id, tr_keys);
+    }
+
+    /// 구독 해제 메시지의 (tr_type, tr_id, tr_key)와 close frame 수신 여부
+    fn unregistered(messages: &[OwnedMessage]) -> (Vec<(String, String, String)>, bool) {
+        let texts = messages
+            .iter()
+            .filter_map(|message| match message {
+                OwnedMessage::Text(text) => {
+                    let v: serde_json::Value = serde_json::from_str(text).unwrap();
+                    Some((
+                        v["header"]["tr_type"].as_str().unwrap().to_string(),
+                        v["body"]["input"]["tr_id"].as_str().unwrap().to_string(),
+                        v["body"]["input"]["tr_key"].as_str().unwrap().to_string(),
+                    ))
+                }
+                _ => None,
+            })
+            .collect();
+        let closed = matches!(messages.last(), Some(OwnedMessage::Close(_)));
+        (texts, closed)
+    }
+
+    #[test]
+    fn execution_notice_errors_reach_receiver() {
+        let response = SubscribeResponse::new(
+            true,
+            "SUBSCRIBE SUCCESS".to_string(),
+            Some("fedcba9876543210".to_string()),
+            Some("0123456789abcdef0123456789abcdef".to_string()),
+        );
+        let ping = r#"{"header":{"tr_id":"PINGPONG","datetime":"20240102093000"}}"#;
+        assert!(parse_execution_notice(ping.to_string(), &response)
+            .unwrap()
+            .is_none());
+        let broken = "1|H0STCNI0|001|not base64!".to_string();
+        assert!(matches!(
+            parse_execution_notice(broken, &response),
+            Ok(Some(Err(Error::DecryptFailed(_))))
+        ));
+    }
+
+    #[tokio::test]
+    async fn close_unsubscribes_all_active_subscriptions() {
+        let (exec_url, exec_server) = recording_server();
+        let (ordb_url, ordb_server) = recording_server();
+        let mut data = stock_data();
+        data.auth.set_approval_key("approval".to_string());
+        connect(&mut data, TrId::RealtimeExec, &exec_url, &shortcodes(1..3));
+        connect(&mut data, TrId::RealtimeOrdb, &ordb_url, &shortcodes(5..6));
+
+        data.close().await.unwrap();
+
+        let (exec, exec_closed) = unregistered(&exec_server.join().unwrap());
+        let unregister =
+            |tr_id: &str, tr_key: &str| ("2".to_string(), tr_id.to_string(), tr_key.to_string());
+        assert_eq!(
+            exec,
+            [
+                unregister("H0STCNT0", "000001"),
+                unregister("H0STCNT0", "000002")
+            ]
+        );
+        assert!(exec_closed);
+        let (ordb, ordb_closed) = unregistered(&ordb_server.join().unwrap());
+        assert_eq!(ordb, [unregister("H0STASP0", "000005")]);
+        assert!(ordb_closed);
+    }
+
+    #[tokio::test]
+    async fn drop_unsubscribes_best_effort() {
+        let (url, server) = recording_server();
+        let mut data = stock_data();
+        data.auth.set_approval_key("approval".to_string());
+        connect(&mut data, TrId::RealtimeExec, &url, &shortcodes(1..2));
+
+        drop(data);
+
+        let (messages, closed) = unregistered(&server.join().unwrap());
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].0, "2");
+        assert!(closed);
+    }
+
+    /// 연결 하나를 받아 구독 등록마다 SUBSCRIBE SUCCESS로 응답하고 받은 (tr_type, tr_key)를 반환하는 웹소켓 서버
+    /// registrations번째 등록에 응답한 뒤 frames를 차례로 보냄
+    fn subscribe_server(
+        registrations: usize,
+        frames: Vec<String>,
+    ) -> (String, std::thread::JoinHandle<Vec<(String, String)>>) {
+        let mut server = websocket::sync::Server::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}", server.local_addr().unwrap());
+        let handle = std::thread::spawn(move || {
+            let mut client = server.accept().ok().unwrap().accept().unwrap();
+            let mut keys = Vec::new();
+            while let Ok(OwnedMessage::Text(text)) = client.recv_message() {
+                let v: serde_json::Value = serde_json::from_str(&text).unwrap();
+                keys.push((
+                    v["header"]["tr_type"].as_str().unwrap().to_string(),
+                    v["body"]["input"]["tr_key"].as_str().unwrap().to_string(),
+                ));
+                let success = r#"{"header":{"tr_id":"H0STCNT0"},"body":{"rt_cd":"0","msg1":"SUBSCRIBE SUCCESS"}}"#;
+                let _ = client.send_message(&OwnedMessage::Text(success.to_string()));
+                if keys.len() == registrations {
+                    for frame in frames.iter() {
+                        let _ = client.send_message(&OwnedMessage::Text(frame.clone()));
                     }
-                } else {
-                    error!("Failed to get message from stream");
-                    panic!();
                 }
             }
+            keys
         });
-        self.handles.insert(tr_id, handle);
-        Ok((rx, result))
+        (url, handle)
+    }
+
+    /// url로 재연결하는 Supervisor(기존 연결은 writer_url의 서버)
+    fn supervisor(
+        data: &mut KoreaStockData,
+        url: &str,
+        writer_url: &str,
+        tr_keys: &[String],
+    ) -> Supervisor {
+        connect(data, TrId::RealtimeExec, writer_url, tr_keys);
+        Supervisor {
+            client: websocket::ClientBuilder::new(url).unwrap(),
+            tr_id: TrId::RealtimeExec,
+            auth: data.auth.clone(),
+            keys: data.subscriptions[&TrId::RealtimeExec].clone(),
+            writer: data.writers[&TrId::RealtimeExec].clone(),
+            events: data.events.clone(),
+            config: ReconnectConfig {
+                max_retries: 3,
+                base_delay: Duration::from_millis(1),
+                max_delay: Duration::from_millis(1),
+                maintenance: None,
+            },
+            closing: data.closing.clone(),
+        }
+    }
+
+    #[tokio::test]
+    async fn reconnect_replays_every_subscription() {
+        let (url, server) = subscribe_server(3, Vec::new());
+        let (writer_url, _) = recording_server();
+        let mut data = stock_data();
+        data.auth.set_approval_key("approval".to_string());
+        let mut events = data.connection_events();
+        let mut supervisor = supervisor(&mut data, &url, &writer_url, &shortcodes(1..4));
+
+        let (reader, response) = supervisor.reconnect().await.unwrap();
+        assert!(response.success());
+        drop((reader, supervisor, data));
+
+        // 재등록(1) 후 drop 시 새 연결에서 구독 해제(2)
+        let messages = server.join().unwrap();
+        let keys = |tr_type: &str| -> Vec<String> {
+            messages
+                .iter()
+                .filter(|(t, _)| t == tr_type)
+                .map(|(_, key)| key.clone())
+                .collect()
+        };
+        assert_eq!(keys("1"), shortcodes(1..4));
+        assert_eq!(keys("2"), shortcodes(1..4));
+        assert!(messages[..3].iter().all(|(t, _)| t == "1"));
+        assert!(matches!(
+            events.try_recv(),
+            Ok(ConnectionEvent::Reconnecting { attempt: 1, .. })
+        ));
+        assert!(matches!(
+            events.try_recv(),
+            Ok(ConnectionEvent::Connected(TrId::RealtimeExec))
+        ));
+    }
+
+    #[tokio::test]
+    async fn reconnect_gives_up_after_max_retries() {
+        // 바로 닫은 포트로 재연결하여 매번 실패하도록 함
+        let closed = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("ws://{}", closed.local_addr().unwrap());
+        drop(closed);
+        let (writer_url, _) = recording_server();
+        let mut data = stock_data();
+        data.auth.set_approval_key("approval".to_string());
+        let mut events = data.connection_events();
+        let mut supervisor = supervisor(&mut data, &url, &writer_url, &shortcodes(1..2));
+
+        let result = supervisor.reconnect().await;
+        assert!(matches!(result, Err(Error::WebsocketClosed(3))));
+        let attempts: Vec<u32> = std::iter::from_fn(|| events.try_recv().ok())
+            .map(|event| match event {
+                ConnectionEvent::Reconnecting { attempt, .. } => attempt,
+                other => panic!("unexpected event: {:?}", other),
+            })
+            .collect();
+        assert_eq!(attempts, [1, 2, 3]);
+    }
+
+    // run_stream이 동기 웹소켓 수신으로 worker thread를 점유하므로 multi_thread runtime 사용
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn batch_registers_keys_per_tr_id_and_merges_streams() {
+        let frame = format!(
+            "0|H0STCNT0|002|{}^{}",
+            exec_record("005930", 71900),
+            exec_record("000660", 120500)
+        );
+        let (exec_url, exec_server) = subscribe_server(2, vec![frame]);
+        let (ordb_url, ordb_server) = subscribe_server(1, Vec::new());
+        let mut data = stock_data();
+        data.auth.set_approval_key("approval".to_string());
+        data.exec_client = websocket::ClientBuilder::new(&exec_url).unwrap();
+        data.ordb_client = websocket::ClientBuilder::new(&ordb_url).unwrap();
+        let sub = |tr_id: TrId, tr_key: &str| Subscription {
+            tr_id,
+            tr_key: tr_key.to_string(),
+        };
+
+        let stream = data
+            .subscribe_batch(vec![
+                sub(TrId::RealtimeExec, "005930"),
+                sub(TrId::RealtimeOrdb, "005930"),
+                sub(TrId::RealtimeExec, "000660"),
+                sub(TrId::RealtimeExec, "005930"),
+            ])
+            .unwrap();
+        let events: Vec<RealtimeEvent> = stream.take(2).collect().await;
+        assert_eq!(
+            trades(&events),
+            [
+                ("005930".to_string(), 71900),
+                ("000660".to_string(), 120500)
+            ]
+        );
+        assert_eq!(data.active_subscriptions().len(), 3);
+        drop(data);
+
+        let registered = |messages: Vec<(String, String)>| -> Vec<String> {
+            messages
+                .into_iter()
+                .filter(|(tr_type, _)| tr_type == "1")
+                .map(|(_, key)| key)
+                .collect()
+        };
+        assert_eq!(
+            registered(exec_server.join().unwrap()),
+            ["005930", "000660"]
+        );
+        assert_eq!(registered(ordb_server.join().unwrap()), ["005930"]);
+    }
+
+    #[tokio::test]
+    async fn batch_rejects_unsupported_or_streaming_tr_id() {
+        let mut data = stock_data();
+        let sub = |tr_id: TrId| Subscription {
+            tr_id,
+            tr_key: "005930".to_string(),
+        };
+        assert!(matches!(
+            data.subscribe_batch(vec![sub(TrId::CurrentPrice)]),
+            Err(Error::WrongTrId(TrId::CurrentPrice, _))
+        ));
+
+        data.handles.insert(
+            TrId::RealtimeOrdb,
+            tokio::spawn(futures::future::pending::<()>()),
+        );
+        assert!(matches!(
+            data.subscribe_batch(vec![sub(TrId::RealtimeExec), sub(TrId::RealtimeOrdb)]),
+            Err(Error::BrokenProtocol("already streaming", _))
+        ));
+        assert!(data.active_subscriptions().is_empty());
     }
 }
@@ -1,3 +1,8 @@
+pub mod account;
+pub mod calendar;
 pub mod data;
 pub mod order;
+pub mod profile;
 pub mod quote;
+pub mod ranking;
+pub mod search;
@@ -1,16 +1,37 @@
+use crate::client::{Envelope, RestClient};
+use crate::paging::PagedStream;
+use crate::stock::calendar::MarketCalendar;
+use crate::types::request::stock::account::{
+    DailyCcldParameter, PsblOrderParameter, PsblRvsecnclParameter, ReserveOrderListParameter,
+};
+use crate::types::response::stock::account::output::{DailyCcldItem, OpenOrder, ReserveOrderItem};
+use crate::types::response::stock::account::{
+    BuyableResponse, DailyCcldResponse, PsblRvsecnclResponse, ReserveOrderListResponse,
+};
 use crate::types::{
-    request, response, Account, CorrectionClass, Direction, Environment, OrderClass, Price,
-    Quantity, TrId,
+    request, response, Account, CorrectionClass, DateRange, Direction, Environment, Exchange,
+    OrderClass, Price, PriceValue, Quantity, Time, TrId,
 };
-use crate::{auth, Error};
+use crate::{auth, validate, Error};
+use chrono::{Datelike, Weekday};
+use futures::TryStreamExt;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// order_cash_idempotent에서 같은 idempotency key의 주문을 다시 보내지 않는 기간 기본값
+const IDEMPOTENCY_TTL: Duration = Duration::from_secs(60);
+
+type OrderCell = Arc<tokio::sync::OnceCell<response::stock::order::Body::Order>>;
 
 #[derive(Clone)]
 pub struct Korea {
-    client: reqwest::Client,
-    endpoint_url: String,
-    environment: Environment,
-    auth: auth::Auth,
+    rest: RestClient,
     account: Account,
+    calendar: Option<MarketCalendar>,
+    exchange: Exchange,
+    idempotency_ttl: Duration,
+    recent_orders: Arc<Mutex<HashMap<String, (Instant, OrderCell)>>>, // idempotency key -> 주문 응답
 }
 
 impl Korea {
@@ -22,20 +43,62 @@ impl Korea {
         auth: auth::Auth,
         account: Account,
     ) -> Result<Self, Error> {
-        let endpoint_url = match environment {
-            Environment::Real => "https://openapi.koreainvestment.com:9443",
-            Environment::Virtual => "https://openapivts.koreainvestment.com:29443",
-        }
-        .to_string();
         Ok(Self {
-            client: client.clone(),
-            endpoint_url,
-            environment,
-            auth,
+            rest: RestClient::new(client, environment, auth),
             account,
+            calendar: None,
+            exchange: Exchange::default(),
+            idempotency_ttl: IDEMPOTENCY_TTL,
+            recent_orders: Arc::new(Mutex::new(HashMap::new())),
         })
     }
 
+    crate::client::rest_builders!("요청 timeout과 재시도 정책 설정(주문은 재시도하지 않음)");
+
+    /// 첫 주문 전에 REST 서버에 미리 연결하여 connection pool을 채움
+    /// 첫 주문이 DNS 조회와 TLS handshake를 기다리지 않음. token과 요청 수 제한을 사용하지 않음
+    pub async fn warm_up(&self) -> Result<(), Error> {
+        self.rest.warm_up().await
+    }
+
+    /// 주문 전에 개장 여부를 확인하여, 장 운영시간이 아니면 요청을 보내지 않고 Error::MarketClosed 반환
+    /// 시간외 주문구분(장전/장후 시간외, 시간외 단일가)은 해당 세션 시간대인지 확인
+    pub fn with_reject_if_closed(mut self, calendar: MarketCalendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// 주문과 정정/취소를 보낼 거래소(기본 KRX)
+    /// NXT, SOR은 모의투자에서 Error::UnsupportedInVirtual 반환
+    pub fn with_exchange(mut self, exchange: Exchange) -> Self {
+        self.exchange = exchange;
+        self
+    }
+
+    /// order_cash_idempotent에서 같은 idempotency key의 주문을 다시 보내지 않는 기간(기본 60초)
+    pub fn with_idempotency_ttl(mut self, ttl: Duration) -> Self {
+        self.idempotency_ttl = ttl;
+        self
+    }
+
+    fn check_exchange(&self, tr_id: TrId) -> Result<TrId, Error> {
+        match self.exchange {
+            Exchange::Krx => Ok(tr_id),
+            Exchange::Nxt | Exchange::Sor => self.rest.require_real(tr_id),
+        }
+    }
+
+    async fn check_market_open(&self, order_class: &OrderClass) -> Result<(), Error> {
+        if let Some(calendar) = &self.calendar {
+            if !calendar.is_session_open_now(order_class).await? {
+                return Err(Error::MarketClosed(
+                    "rejected before sending: market is closed".to_string(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// 주식주문(현금)[v1_국내주식-001]
     /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_aade4c72-5fb7-418a-9ff2-254b4d5f0ceb)
     pub async fn order_cash(
@@ -46,59 +109,235 @@ impl Korea {
         qty: Quantity,
         price: Price,
     ) -> Result<response::stock::order::Body::Order, Error> {
+        Ok(self
+            .order_cash_with_meta(order_division, order_direction, pdno, qty, price)
+            .await?
+            .body)
+    }
+
+    /// 주식주문(현금)[v1_국내주식-001]
+    /// 응답 헤더(tr_id, gt_uid)를 함께 반환. gt_uid는 주문 문의 시 사용
+    pub async fn order_cash_with_meta(
+        &self,
+        order_division: OrderClass,
+        order_direction: Direction,
+        pdno: &str,
+        qty: Quantity,
+        price: Price,
+    ) -> Result<Envelope<response::stock::order::Body::Order>, Error> {
+        validate::shortcode("pdno", pdno)?;
+        self.check_market_open(&order_division).await?;
         let request = request::stock::order::body::Order::new(
-            self.account.cano.clone(),
-            self.account.acnt_prdt_cd.clone(),
+            self.account.cano().to_string(),
+            self.account.product_code().to_string(),
             pdno.to_string(),
             order_division,
             qty,
             price,
         )
-        .get_json_string();
-        let tr_id: String = match self.environment {
+        .with_exchange(self.exchange);
+        let tr_id = match self.rest.environment() {
             Environment::Real => match order_direction {
-                Direction::Bid => TrId::RealStockCashBidOrder.into(),
-                Direction::Ask => TrId::RealStockCashAskOrder.into(),
+                Direction::Bid => TrId::RealStockCashBidOrder,
+                Direction::Ask => TrId::RealStockCashAskOrder,
             },
             Environment::Virtual => match order_direction {
-                Direction::Bid => TrId::VirtualStockCashBidOrder.into(),
-                Direction::Ask => TrId::VirtualStockCashAskOrder.into(),
+                Direction::Bid => TrId::VirtualStockCashBidOrder,
+                Direction::Ask => TrId::VirtualStockCashAskOrder,
             },
         };
-        let hash = self.auth.get_hash(request.clone()).await?;
-        Ok(self
-            .client
-            .post(format!(
-                "{}/uapi/domestic-stock/v1/trading/order-cash",
-                self.endpoint_url
-            ))
-            .header("Content-Type", "application/json")
-            .header(
-                "Authorization",
-                match self.auth.get_token() {
-                    Some(token) => format!("Bearer {}", token),
-                    None => {
-                        return Err(Error::AuthInitFailed("token"));
-                    }
-                },
+        let tr_id = self.check_exchange(tr_id)?;
+        self.rest
+            .post_with_meta(
+                "/uapi/domestic-stock/v1/trading/order-cash",
+                tr_id,
+                &request,
             )
-            .header("appkey", self.auth.get_appkey())
-            .header("appsecret", self.auth.get_appsecret())
-            .header("tr_id", tr_id)
-            .header("hashkey", hash)
-            .header("custtype", "P")
-            .body(request)
-            .send()
+            .await
+    }
+
+    /// 시장가 매수(주문구분 01, 주문단가 0)
+    pub async fn market_buy(
+        &self,
+        shortcode: &str,
+        qty: u32,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        self.quick_order(
+            Direction::Bid,
+            OrderClass::Market,
+            shortcode,
+            qty,
+            Price::from(0),
+        )
+        .await
+    }
+
+    /// 시장가 매도(주문구분 01, 주문단가 0)
+    pub async fn market_sell(
+        &self,
+        shortcode: &str,
+        qty: u32,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        self.quick_order(
+            Direction::Ask,
+            OrderClass::Market,
+            shortcode,
+            qty,
+            Price::from(0),
+        )
+        .await
+    }
+
+    /// 지정가 매수(주문구분 00)
+    pub async fn limit_buy(
+        &self,
+        shortcode: &str,
+        qty: u32,
+        price: Price,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        self.quick_order(Direction::Bid, OrderClass::Limit, shortcode, qty, price)
+            .await
+    }
+
+    /// 지정가 매도(주문구분 00)
+    pub async fn limit_sell(
+        &self,
+        shortcode: &str,
+        qty: u32,
+        price: Price,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        self.quick_order(Direction::Ask, OrderClass::Limit, shortcode, qty, price)
+            .await
+    }
+
+    /// market_buy 등의 공통 처리. 수량은 1 이상, 지정가는 0보다 커야 함
+    async fn quick_order(
+        &self,
+        direction: Direction,
+        order_class: OrderClass,
+        shortcode: &str,
+        qty: u32,
+        price: Price,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        if qty == 0 {
+            return Err(Error::InvalidParam {
+                field: "qty",
+                reason: "must be non-zero",
+            });
+        }
+        if matches!(order_class, OrderClass::Limit) && price.inner() <= PriceValue::default() {
+            return Err(Error::InvalidParam {
+                field: "price",
+                reason: "limit price must be greater than zero",
+            });
+        }
+        validate::shortcode("shortcode", shortcode)?;
+        self.order_cash(
+            order_class,
+            direction,
+            shortcode,
+            Quantity::from(qty),
+            price,
+        )
+        .await
+    }
+
+    /// 주식주문(현금)[v1_국내주식-001] 중복 방지
+    /// idempotency_ttl 안에 같은 idempotency_key로 다시 요청하면 전송하지 않고 처음 응답을 duplicate: true로 반환
+    /// 동시에 요청한 경우에도 한 번만 전송하며, 나머지는 처음 요청의 응답을 기다림
+    /// KIS에는 중복 주문 방지 기능이 없어 이 Korea(clone 포함) 안에서만 확인하는 best-effort 방식
+    /// 전송에 실패한 주문은 기억하지 않으므로, timeout 등으로 접수 여부를 알 수 없으면 다시 요청하기 전에 open_orders로 확인
+    pub async fn order_cash_idempotent(
+        &self,
+        idempotency_key: &str,
+        order_division: OrderClass,
+        order_direction: Direction,
+        pdno: &str,
+        qty: Quantity,
+        price: Price,
+    ) -> Result<response::stock::order::Body::IdempotentOrder, Error> {
+        let cell = {
+            let now = Instant::now();
+            let mut recent = self
+                .recent_orders
+                .lock()
+                .map_err(|_| Error::BrokenProtocol("order cache lock poisoned", String::new()))?;
+            recent.retain(|_, (sent_at, _)| now.duration_since(*sent_at) < self.idempotency_ttl);
+            recent
+                .entry(idempotency_key.to_string())
+                .or_insert_with(|| (now, OrderCell::default()))
+                .1
+                .clone()
+        };
+        let mut sent = false;
+        let sent_ref = &mut sent;
+        let order = cell
+            .get_or_try_init(|| async move {
+                *sent_ref = true;
+                self.order_cash(order_division, order_direction, pdno, qty, price)
+                    .await
+            })
             .await?
-            .json::<response::stock::order::Body::Order>()
-            .await?)
+            .clone();
+        Ok(response::stock::order::Body::IdempotentOrder::new(
+            order, !sent,
+        ))
     }
 
     // TODO: 주식주문(신용)[v1_국내주식-002]
     // [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_f5769e4a-24d5-44f9-a2d8-232d45abf988)
 
-    /// 주식주문(정정취소)[v1_국내주식-003] TODO: test
+    /// 주식주문(정정)[v1_국내주식-003]
     /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_4bfdfb2b-34a7-43f6-935a-e637724f960a)
+    /// 원주문(krx_fwdg_ord_orgno, orgn_odno)의 수량/가격을 정정. 정정 가격은 0일 수 없음
+    pub async fn revise(
+        &self,
+        order_division: OrderClass,
+        krx_fwdg_ord_orgno: &str,
+        orgn_odno: &str,
+        qty_all_ord_yn: bool,
+        qty: Quantity,
+        price: Price,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        self.correct(
+            order_division,
+            krx_fwdg_ord_orgno,
+            orgn_odno,
+            CorrectionClass::Correction,
+            qty_all_ord_yn,
+            qty,
+            price,
+        )
+        .await
+    }
+
+    /// 주식주문(취소)[v1_국내주식-003]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_4bfdfb2b-34a7-43f6-935a-e637724f960a)
+    /// 원주문(krx_fwdg_ord_orgno, orgn_odno)을 취소. qty_all_ord_yn이 true이면 잔량 전부 취소
+    pub async fn cancel(
+        &self,
+        order_division: OrderClass,
+        krx_fwdg_ord_orgno: &str,
+        orgn_odno: &str,
+        qty_all_ord_yn: bool,
+        qty: Quantity,
+    ) -> Result<response::stock::order::Body::Order, Error> {
+        self.correct(
+            order_division,
+            krx_fwdg_ord_orgno,
+            orgn_odno,
+            CorrectionClass::Cancel,
+            qty_all_ord_yn,
+            qty,
+            Price::from(0),
+        )
+        .await
+    }
+
+    /// 주식주문(정정취소)[v1_국내주식-003]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_4bfdfb2b-34a7-43f6-935a-e637724f960a)
+    /// 취소 주문의 가격은 무시되고 0으로 전송됨
+    #[allow(clippy::too_many_arguments)]
     pub async fn correct(
         &self,
         order_division: OrderClass,
@@ -109,9 +348,34 @@ impl Korea {
         qty: Quantity,
         price: Price,
     ) -> Result<response::stock::order::Body::Order, Error> {
+        let price = match rvse_cncl_dvsn_cd {
+            CorrectionClass::None => {
+                return Err(Error::InvalidParam {
+                    field: "rvse_cncl_dvsn_cd",
+                    reason: "must be Correction or Cancel",
+                });
+            }
+            CorrectionClass::Correction if price == Price::from(0) => {
+                return Err(Error::InvalidParam {
+                    field: "price",
+                    reason: "revision requires a non-zero price",
+                });
+            }
+            CorrectionClass::Correction => price,
+            CorrectionClass::Cancel => Price::from(0),
+        };
+        if !qty_all_ord_yn && qty == Quantity::from(0) {
+            return Err(Error::InvalidParam {
+                field: "qty",
+                reason: "must be non-zero unless qty_all_ord_yn is set",
+            });
+        }
+        validate::order_no("krx_fwdg_ord_orgno", krx_fwdg_ord_orgno)?;
+        validate::order_no("orgn_odno", orgn_odno)?;
+        self.check_market_open(&order_division).await?;
         let request = request::stock::order::body::Correction::new(
-            self.account.cano.clone(),
-            self.account.acnt_prdt_cd.clone(),
+            self.account.cano().to_string(),
+            self.account.product_code().to_string(),
             krx_fwdg_ord_orgno.to_string(),
             orgn_odno.to_string(),
             order_division,
@@ -120,48 +384,679 @@ impl Korea {
             price,
             qty_all_ord_yn,
         )
-        .get_json_string();
-        let tr_id: String = match self.environment {
-            Environment::Real => TrId::RealStockCorrection.into(),
-            Environment::Virtual => TrId::VirtualStockCorrection.into(),
+        .with_exchange(self.exchange);
+        let tr_id = match self.rest.environment() {
+            Environment::Real => TrId::RealStockCorrection,
+            Environment::Virtual => TrId::VirtualStockCorrection,
         };
-        let hash = self.auth.get_hash(request.clone()).await?;
-        Ok(self
-            .client
-            .post(format!(
-                "{}/uapi/domestic-stock/v1/trading/order-rvsecncl",
-                self.endpoint_url
-            ))
-            .header("Content-Type", "application/json")
-            .header(
-                "Authorization",
-                match self.auth.get_token() {
-                    Some(token) => token,
-                    None => {
-                        return Err(Error::AuthInitFailed("token"));
-                    }
-                },
+        let tr_id = self.check_exchange(tr_id)?;
+        self.rest
+            .post(
+                "/uapi/domestic-stock/v1/trading/order-rvsecncl",
+                tr_id,
+                &request,
             )
-            .header("appkey", self.auth.get_appkey())
-            .header("appsecret", self.auth.get_appsecret())
-            .header("tr_id", tr_id)
-            .header("hashkey", hash)
-            .body(request)
-            .send()
-            .await?
-            .json::<response::stock::order::Body::Order>()
-            .await?)
+            .await
     }
 
-    // TODO: 주식정정취소가능주문조회[v1_국내주식-004]
-    // [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_d4537e9c-73f7-414c-9fb0-4eae3bc397d0)
+    /// 주식정정취소가능주문조회[v1_국내주식-004]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_d4537e9c-73f7-414c-9fb0-4eae3bc397d0)
+    /// 정정/취소 가능한 미체결 주문을 연속조회 결과까지 모두 합쳐서 반환. 모의투자 미지원
+    pub async fn open_orders(&self) -> Result<Vec<OpenOrder>, Error> {
+        let tr_id = self.rest.require_real(TrId::RealStockPsblRvsecncl)?;
+        let rest = self.rest.clone();
+        let account = self.account.clone();
+        let pages: Vec<PsblRvsecnclResponse> = PagedStream::new(move |key| {
+            let rest = rest.clone();
+            let tr_id = tr_id.clone();
+            let mut param = PsblRvsecnclParameter::new(&account);
+            if let Some(key) = &key {
+                param = param.with_continuation(key);
+            }
+            async move {
+                let url = rest.url(
+                    "/uapi/domestic-stock/v1/trading/inquire-psbl-rvsecncl",
+                    &param.into_iter(),
+                )?;
+                rest.get_page(url, tr_id, key.is_some()).await
+            }
+        })
+        .try_collect()
+        .await?;
+        Ok(pages
+            .into_iter()
+            .flat_map(|page| page.into_items())
+            .collect())
+    }
+
+    /// 주식일별주문체결조회[v1_국내주식-005]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_bc51f9f7-146f-4971-a5ae-ebd574acec12)
+    /// 시작일이 3개월 이내이면 3개월이내 TR, 그 이전이면 3개월이전 TR로 조회하며 연속조회 결과를 모두 합쳐서 반환
+    pub async fn inquire_daily_ccld(
+        &self,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+        side_filter: Option<Direction>,
+    ) -> Result<Vec<DailyCcldItem>, Error> {
+        let range = DateRange::new(start, end)?;
+        let pages: Vec<DailyCcldResponse> = self
+            .inquire_daily_ccld_pages(range, side_filter)
+            .try_collect()
+            .await?;
+        Ok(pages
+            .into_iter()
+            .flat_map(|page| page.into_items())
+            .collect())
+    }
 
-    // TODO: 주식일별주문체결조회[v1_국내주식-005]
-    // [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_bc51f9f7-146f-4971-a5ae-ebd574acec12)
+    /// 주식일별주문체결조회[v1_국내주식-005] 연속조회
+    /// 페이지별 응답(합계 output2 포함)이 필요한 경우 사용
+    pub fn inquire_daily_ccld_pages(
+        &self,
+        range: DateRange,
+        side_filter: Option<Direction>,
+    ) -> PagedStream<DailyCcldResponse> {
+        let three_months_ago = Time::now()
+            .inner()
+            .date_naive()
+            .checked_sub_months(chrono::Months::new(3))
+            .unwrap_or(chrono::NaiveDate::MIN);
+        let within_3_months = range.start() >= three_months_ago;
+        let tr_id = match (self.rest.environment(), within_3_months) {
+            (Environment::Real, true) => TrId::RealStockDailyCcld,
+            (Environment::Real, false) => TrId::RealStockDailyCcldBefore3Months,
+            (Environment::Virtual, true) => TrId::VirtualStockDailyCcld,
+            (Environment::Virtual, false) => TrId::VirtualStockDailyCcldBefore3Months,
+        };
+        let rest = self.rest.clone();
+        let account = self.account.clone();
+        PagedStream::new(move |key| {
+            let rest = rest.clone();
+            let tr_id = tr_id.clone();
+            let mut param = DailyCcldParameter::new(&account, &range, side_filter.clone());
+            if let Some(key) = &key {
+                param = param.with_continuation(key);
+            }
+            async move {
+                let url = rest.url(
+                    "/uapi/domestic-stock/v1/trading/inquire-daily-ccld",
+                    &param.into_iter(),
+                )?;
+                rest.get_page(url, tr_id, key.is_some()).await
+            }
+        })
+    }
 
-    // TODO: 주식잔고조회[v1_국내주식-006]
-    // [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_66c61080-674f-4c91-a0cc-db5e64e9a5e6)
+    /// 매수가능조회[v1_국내주식-007]
+    /// [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_806e407c-3082-44c0-9d71-e8534db5ad54)
+    /// price가 0이면 단가를 공란으로 보내 현재가 기준으로 계산(시장가). 지정가는 price가 0일 수 없음
+    pub async fn buyable(
+        &self,
+        shortcode: &str,
+        price: u32,
+        order_type: OrderClass,
+    ) -> Result<BuyableResponse, Error> {
+        if price == 0 && order_type == OrderClass::Limit {
+            return Err(Error::InvalidParam {
+                field: "price",
+                reason: "limit order requires a non-zero price",
+            });
+        }
+        validate::shortcode("shortcode", shortcode)?;
+        let tr_id = match self.rest.environment() {
+            Environment::Real => TrId::RealStockPsblOrder,
+            Environment::Virtual => TrId::VirtualStockPsblOrder,
+        };
+        let param =
+            PsblOrderParameter::new(&self.account, shortcode.to_string(), price, order_type);
+        let url = self.rest.url(
+            "/uapi/domestic-stock/v1/trading/inquire-psbl-order",
+            &param.into_iter(),
+        )?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 주식예약주문[v1_국내주식-017]
+    /// reserve_end_date까지 매 영업일 장 시작 시 주문이 제출됨. price가 0이면 시장가. 모의투자 미지원
+    /// 반환된 예약주문순번(reserve_seq)으로 cancel_reserve 가능
+    pub async fn reserve_order(
+        &self,
+        direction: Direction,
+        shortcode: &str,
+        qty: Quantity,
+        price: Price,
+        reserve_end_date: chrono::NaiveDate,
+    ) -> Result<response::stock::order::Body::ReserveOrder, Error> {
+        let tr_id = self.rest.require_real(TrId::RealStockReserveOrder)?;
+        if qty == Quantity::from(0) {
+            return Err(Error::InvalidParam {
+                field: "qty",
+                reason: "must be non-zero",
+            });
+        }
+        validate::shortcode("shortcode", shortcode)?;
+        self.check_reserve_end_date(reserve_end_date).await?;
+        let request = request::stock::order::body::ReserveOrder::new(
+            self.account.cano().to_string(),
+            self.account.product_code().to_string(),
+            direction,
+            shortcode.to_string(),
+            qty,
+            price,
+            format!("{}", reserve_end_date.format("%Y%m%d")),
+        );
+        self.rest
+            .post(
+                "/uapi/domestic-stock/v1/trading/order-resv",
+                tr_id,
+                &request,
+            )
+            .await
+    }
+
+    /// 주식예약주문정정취소[v1_국내주식-018] 취소
+    /// reserve_seq는 reserve_order 응답 또는 inquire_reserve의 예약주문순번. 모의투자 미지원
+    pub async fn cancel_reserve(
+        &self,
+        reserve_seq: &str,
+    ) -> Result<response::stock::order::Body::ReserveCancel, Error> {
+        let tr_id = self.rest.require_real(TrId::RealStockReserveCancel)?;
+        let request = request::stock::order::body::ReserveCancel::new(
+            self.account.cano().to_string(),
+            self.account.product_code().to_string(),
+            reserve_seq.to_string(),
+        );
+        self.rest
+            .post(
+                "/uapi/domestic-stock/v1/trading/order-resv-rvsecncl",
+                tr_id,
+                &request,
+            )
+            .await
+    }
+
+    /// 주식예약주문조회[v1_국내주식-020]
+    /// 예약주문일자가 start~end인 예약주문(취소 내역 포함)을 연속조회 결과까지 모두 합쳐서 반환. 모의투자 미지원
+    pub async fn inquire_reserve(
+        &self,
+        start: chrono::NaiveDate,
+        end: chrono::NaiveDate,
+    ) -> Result<Vec<ReserveOrderItem>, Error> {
+        let tr_id = self.rest.require_real(TrId::RealStockReserveOrderList)?;
+        let range = DateRange::new(start, end)?;
+        let rest = self.rest.clone();
+        let account = self.account.clone();
+        let pages: Vec<ReserveOrderListResponse> = PagedStream::new(move |key| {
+            let rest = rest.clone();
+            let tr_id = tr_id.clone();
+            let mut param = ReserveOrderListParameter::new(&account, &range);
+            if let Some(key) = &key {
+                param = param.with_continuation(key);
+            }
+            async move {
+                let url = rest.url(
+                    "/uapi/domestic-stock/v1/trading/order-resv-ccnl",
+                    &param.into_iter(),
+                )?;
+                rest.get_page(url, tr_id, key.is_some()).await
+            }
+        })
+        .try_collect()
+        .await?;
+        Ok(pages
+            .into_iter()
+            .flat_map(|page| page.into_items())
+            .collect())
+    }
+
+    /// 예약주문 종료일은 오늘(KST) 이후의 영업일이어야 함
+    /// with_reject_if_closed로 MarketCalendar가 설정되어 있으면 휴장일도 확인
+    async fn check_reserve_end_date(&self, date: chrono::NaiveDate) -> Result<(), Error> {
+        if date <= Time::now().inner().date_naive() {
+            return Err(Error::InvalidParam {
+                field: "reserve_end_date",
+                reason: "must be after today",
+            });
+        }
+        let is_open = match &self.calendar {
+            Some(calendar) => calendar.is_open_day(date).await?,
+            None => !matches!(date.weekday(), Weekday::Sat | Weekday::Sun),
+        };
+        if !is_open {
+            return Err(Error::InvalidParam {
+                field: "reserve_end_date",
+                reason: "must be a business day",
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{MockResponse, MockTransport};
+
+    const MALFORMED_SHORTCODES: [&str; 6] = ["", "00593", "0059300", "Q00593", "0059a0", "005 30"];
+
+    fn mock_korea(mock: &Arc<MockTransport>) -> Korea {
+        mock_korea_in(Environment::Virtual, mock)
+    }
+
+    fn mock_korea_in(environment: Environment, mock: &Arc<MockTransport>) -> Korea {
+        let client = reqwest::Client::new();
+        let mut auth = auth::Auth::new(&client, environment.clone(), "appkey", "appsecret")
+            .with_transport(mock.clone());
+        auth.set_token("token".to_string());
+        Korea::new(
+            &client,
+            environment,
+            auth,
+            Account::new("12345678", "01").unwrap(),
+        )
+        .unwrap()
+        .with_transport(mock.clone())
+    }
+
+    fn assert_invalid_param<T: std::fmt::Debug>(
+        result: Result<T, Error>,
+        expected: &str,
+        input: &str,
+    ) {
+        match result {
+            Err(Error::InvalidParam { field, .. }) => assert_eq!(field, expected, "{:?}", input),
+            other => panic!("{:?}: {:?}", input, other),
+        }
+    }
+
+    #[tokio::test]
+    async fn order_cash_rejects_malformed_shortcode() {
+        let mock = Arc::new(MockTransport::new());
+        let korea = mock_korea(&mock);
+        for pdno in MALFORMED_SHORTCODES {
+            let result = korea
+                .order_cash(
+                    OrderClass::Limit,
+                    Direction::Bid,
+                    pdno,
+                    Quantity::from(1),
+                    Price::from(70_000),
+                )
+                .await;
+            assert_invalid_param(result, "pdno", pdno);
+        }
+        assert!(mock.requests().is_empty());
+    }
 
-    // TODO: 매수가능조회[v1_국내주식-007]
-    // [Docs](https://apiportal.koreainvestment.com/apiservice/apiservice-domestic-stock#L_806e407c-3082-44c0-9d71-e8534db5ad54)
+    #[tokio::test]
+    async fn buyable_rejects_malformed_shortcode() {
+        let mock = Arc::new(MockTransport::new());
+        let korea = mock_korea(&mock);
+        for shortcode in MALFORMED_SHORTCODES {
+            let result = korea.buyable(shortcode, 70_000, OrderClass::Limit).await;
+            assert_invalid_param(result, "shortcode", shortcode);
+        }
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn correct_rejects_malformed_order_no() {
+        let mock = Arc::new(MockTransport::new());
+        let korea = mock_korea(&mock);
+        for (orgno, odno, field) in [
+            ("", "0000117057", "krx_fwdg_ord_orgno"),
+            ("06-10", "0000117057", "krx_fwdg_ord_orgno"),
+            ("06010", "", "orgn_odno"),
+            ("06010", "00001170570", "orgn_odno"),
+            ("06010", "117057A", "orgn_odno"),
+        ] {
+            let result = korea
+                .correct(
+                    OrderClass::Limit,
+                    orgno,
+                    odno,
+                    CorrectionClass::Cancel,
+                    true,
+                    Quantity::from(0),
+                    Price::from(0),
+                )
+                .await;
+            assert_invalid_param(result, field, &format!("{}/{}", orgno, odno));
+        }
+        assert!(mock.requests().is_empty());
+    }
+
+    const ORDER: &str = r#"{"rt_cd":"0","msg_cd":"APBK0013","msg1":"주문 전송 완료 되었습니다.","output":{"KRX_FWDG_ORD_ORGNO":"00950","ODNO":"0000117057","ORD_TMD":"091500"}}"#;
+
+    fn order_mock() -> Arc<MockTransport> {
+        Arc::new(
+            MockTransport::new()
+                .on_path("/uapi/hashkey", MockResponse::json(r#"{"HASH":"hash"}"#))
+                .on_tr_id(TrId::VirtualStockCashBidOrder, MockResponse::json(ORDER)),
+        )
+    }
+
+    /// 주문 전송 요청 수(hashkey 요청 제외)
+    fn order_requests(mock: &MockTransport) -> usize {
+        mock.requests()
+            .iter()
+            .filter(|request| {
+                request
+                    .url
+                    .ends_with("/uapi/domestic-stock/v1/trading/order-cash")
+            })
+            .count()
+    }
+
+    async fn idempotent_buy(
+        korea: &Korea,
+        key: &str,
+    ) -> response::stock::order::Body::IdempotentOrder {
+        korea
+            .order_cash_idempotent(
+                key,
+                OrderClass::Limit,
+                Direction::Bid,
+                "005930",
+                Quantity::from(1),
+                Price::from(70_000),
+            )
+            .await
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn order_cash_idempotent_sends_once_per_key() {
+        let mock = order_mock();
+        let korea = mock_korea(&mock);
+
+        let first = idempotent_buy(&korea, "key-1").await;
+        let second = idempotent_buy(&korea, "key-1").await;
+        assert!(!first.duplicate());
+        assert!(second.duplicate());
+        assert_eq!(first.order(), second.order());
+        assert_eq!(second.order().order_no(), Some("0000117057"));
+        assert_eq!(order_requests(&mock), 1);
+
+        assert!(!idempotent_buy(&korea, "key-2").await.duplicate());
+        assert_eq!(order_requests(&mock), 2);
+    }
+
+    #[tokio::test]
+    async fn order_cash_idempotent_concurrent_same_key() {
+        let mock = order_mock();
+        let korea = mock_korea(&mock);
+        let (a, b) = tokio::join!(
+            idempotent_buy(&korea, "key-1"),
+            idempotent_buy(&korea, "key-1")
+        );
+        assert_ne!(a.duplicate(), b.duplicate());
+        assert_eq!(order_requests(&mock), 1);
+    }
+
+    #[tokio::test]
+    async fn order_cash_idempotent_resends_after_ttl() {
+        let mock = order_mock();
+        let korea = mock_korea(&mock).with_idempotency_ttl(Duration::ZERO);
+        idempotent_buy(&korea, "key-1").await;
+        assert!(!idempotent_buy(&korea, "key-1").await.duplicate());
+        assert_eq!(order_requests(&mock), 2);
+    }
+
+    #[tokio::test]
+    async fn order_cash_sends_order_division_code() {
+        let mock = order_mock();
+        let korea = mock_korea(&mock);
+        for (order_class, code) in [
+            (OrderClass::PreMarket, "05"),
+            (OrderClass::PostMarket, "06"),
+            (OrderClass::OutMarketSinglePrice, "07"),
+        ] {
+            korea
+                .order_cash(
+                    order_class,
+                    Direction::Bid,
+                    "005930",
+                    Quantity::from(1),
+                    Price::from(70_000),
+                )
+                .await
+                .unwrap();
+            let requests = mock.requests();
+            let body: serde_json::Value =
+                serde_json::from_str(requests.last().unwrap().body.as_deref().unwrap()).unwrap();
+            assert_eq!(body["ORD_DVSN"], code);
+        }
+    }
+
+    #[tokio::test]
+    async fn quick_orders_emit_order_body() {
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_path("/uapi/hashkey", MockResponse::json(r#"{"HASH":"hash"}"#))
+                .on_tr_id(TrId::RealStockCashBidOrder, MockResponse::json(ORDER))
+                .on_tr_id(TrId::RealStockCashAskOrder, MockResponse::json(ORDER)),
+        );
+        let korea = mock_korea_in(Environment::Real, &mock);
+        let sent = || {
+            let request = mock
+                .requests()
+                .into_iter()
+                .rev()
+                .find(|request| request.url.ends_with("/order-cash"))
+                .unwrap();
+            let body: serde_json::Value =
+                serde_json::from_str(request.body.as_deref().unwrap()).unwrap();
+            (
+                request.tr_id,
+                body["PDNO"].as_str().unwrap().to_string(),
+                body["ORD_DVSN"].as_str().unwrap().to_string(),
+                body["ORD_QTY"].as_str().unwrap().to_string(),
+                body["ORD_UNPR"].as_str().unwrap().to_string(),
+            )
+        };
+        let expected = |tr_id: TrId, ord_dvsn: &str, qty: &str, price: &str| {
+            (
+                tr_id.to_string(),
+                "005930".to_string(),
+                ord_dvsn.to_string(),
+                qty.to_string(),
+                price.to_string(),
+            )
+        };
+
+        korea.market_buy("005930", 10).await.unwrap();
+        assert_eq!(
+            sent(),
+            expected(TrId::RealStockCashBidOrder, "01", "10", "0")
+        );
+        korea.market_sell("005930", 3).await.unwrap();
+        assert_eq!(
+            sent(),
+            expected(TrId::RealStockCashAskOrder, "01", "3", "0")
+        );
+        korea
+            .limit_buy("005930", 5, Price::from(70_000))
+            .await
+            .unwrap();
+        assert_eq!(
+            sent(),
+            expected(TrId::RealStockCashBidOrder, "00", "5", "70000")
+        );
+        korea
+            .limit_sell("005930", 7, Price::from(71_000))
+            .await
+            .unwrap();
+        assert_eq!(
+            sent(),
+            expected(TrId::RealStockCashAskOrder, "00", "7", "71000")
+        );
+    }
+
+    #[tokio::test]
+    async fn quick_orders_reject_zero_quantity_and_price() {
+        let mock = order_mock();
+        let korea = mock_korea(&mock);
+        assert!(matches!(
+            korea.market_buy("005930", 0).await,
+            Err(Error::InvalidParam { .. })
+        ));
+        assert!(matches!(
+            korea.limit_sell("005930", 0, Price::from(70_000)).await,
+            Err(Error::InvalidParam { .. })
+        ));
+        assert!(matches!(
+            korea.limit_buy("005930", 1, Price::from(0)).await,
+            Err(Error::InvalidParam { .. })
+        ));
+        assert!(mock.requests().is_empty());
+    }
+
+    fn open_order(odno: &str) -> String {
+        format!(
+            r#"{{"ord_gno_brno":"06010","odno":"{}","orgn_odno":"","ord_dvsn_name":"지정가","pdno":"005930","prdt_name":"삼성전자","rvse_cncl_dvsn_name":"","ord_qty":"10","ord_unpr":"70000","ord_tmd":"091500","tot_ccld_qty":"0","tot_ccld_amt":"0","psbl_qty":"10","sll_buy_dvsn_cd":"02","ord_dvsn_cd":"00"}}"#,
+            odno
+        )
+    }
+
+    #[tokio::test]
+    async fn open_orders_stitches_continuation_pages() {
+        let first = format!(
+            r#"{{"rt_cd":"0","msg_cd":"KIOK0510","msg1":"ok","ctx_area_fk100":"FK1","ctx_area_nk100":"NK1","output":[{}]}}"#,
+            open_order("0000000001")
+        );
+        let last = format!(
+            r#"{{"rt_cd":"0","msg_cd":"KIOK0460","msg1":"ok","ctx_area_fk100":"FK2","ctx_area_nk100":"NK2","output":[{}]}}"#,
+            open_order("0000000002")
+        );
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_tr_id(
+                    TrId::RealStockPsblRvsecncl,
+                    MockResponse::json(first).with_header("tr_cont", "M"),
+                )
+                .on_tr_id(
+                    TrId::RealStockPsblRvsecncl,
+                    MockResponse::json(last).with_header("tr_cont", "D"),
+                ),
+        );
+        let korea = mock_korea_in(Environment::Real, &mock);
+
+        let orders = korea.open_orders().await.unwrap();
+        let odnos: Vec<&str> = orders.iter().map(|order| order.odno().as_str()).collect();
+        assert_eq!(odnos, ["0000000001", "0000000002"]);
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].headers.get("tr_cont").is_none());
+        assert_eq!(requests[1].headers.get("tr_cont").unwrap(), "N");
+        let url = reqwest::Url::parse(&requests[1].url).unwrap();
+        let query: HashMap<String, String> = url.query_pairs().into_owned().collect();
+        assert_eq!(query["CTX_AREA_FK100"], "FK1");
+        assert_eq!(query["CTX_AREA_NK100"], "NK1");
+    }
+
+    /// 마지막으로 보낸 path 요청 본문의 EXCG_ID_DVSN_CD
+    fn sent_exchange(mock: &MockTransport, path: &str) -> String {
+        let request = mock
+            .requests()
+            .into_iter()
+            .rev()
+            .find(|request| request.url.ends_with(path))
+            .unwrap();
+        let body: serde_json::Value =
+            serde_json::from_str(request.body.as_deref().unwrap()).unwrap();
+        body["EXCG_ID_DVSN_CD"].as_str().unwrap().to_string()
+    }
+
+    fn routing_mock() -> Arc<MockTransport> {
+        Arc::new(
+            MockTransport::new()
+                .on_path("/uapi/hashkey", MockResponse::json(r#"{"HASH":"hash"}"#))
+                .on_tr_id(TrId::RealStockCashBidOrder, MockResponse::json(ORDER))
+                .on_tr_id(TrId::RealStockCorrection, MockResponse::json(ORDER)),
+        )
+    }
+
+    #[tokio::test]
+    async fn orders_emit_exchange_routing_code() {
+        let mock = routing_mock();
+        let default = mock_korea_in(Environment::Real, &mock);
+        for (korea, code) in [
+            (default.clone(), "KRX"),
+            (default.clone().with_exchange(Exchange::Krx), "KRX"),
+            (default.clone().with_exchange(Exchange::Nxt), "NXT"),
+            (default.with_exchange(Exchange::Sor), "SOR"),
+        ] {
+            korea
+                .order_cash(
+                    OrderClass::Limit,
+                    Direction::Bid,
+                    "005930",
+                    Quantity::from(1),
+                    Price::from(70_000),
+                )
+                .await
+                .unwrap();
+            assert_eq!(sent_exchange(&mock, "/order-cash"), code);
+            korea
+                .correct(
+                    OrderClass::Limit,
+                    "00950",
+                    "0000117057",
+                    CorrectionClass::Correction,
+                    true,
+                    Quantity::from(0),
+                    Price::from(71_000),
+                )
+                .await
+                .unwrap();
+            assert_eq!(sent_exchange(&mock, "/order-rvsecncl"), code);
+        }
+    }
+
+    #[tokio::test]
+    async fn virtual_rejects_nxt_and_sor_routing() {
+        let mock = Arc::new(MockTransport::new());
+        for exchange in [Exchange::Nxt, Exchange::Sor] {
+            let korea = mock_korea(&mock).with_exchange(exchange);
+            let result = korea
+                .order_cash(
+                    OrderClass::Limit,
+                    Direction::Bid,
+                    "005930",
+                    Quantity::from(1),
+                    Price::from(70_000),
+                )
+                .await;
+            assert!(
+                matches!(
+                    result,
+                    Err(Error::UnsupportedInVirtual(TrId::VirtualStockCashBidOrder))
+                ),
+                "{:?}",
+                exchange
+            );
+            let result = korea
+                .correct(
+                    OrderClass::Limit,
+                    "00950",
+                    "0000117057",
+                    CorrectionClass::Cancel,
+                    true,
+                    Quantity::from(0),
+                    Price::from(0),
+                )
+                .await;
+            assert!(
+                matches!(
+                    result,
+                    Err(Error::UnsupportedInVirtual(TrId::VirtualStockCorrection))
+                ),
+                "{:?}",
+                exchange
+            );
+        }
+        assert!(mock.requests().is_empty());
+    }
 }
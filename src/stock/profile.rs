@@ -0,0 +1,187 @@
+use crate::types::response::stock::quote::output::MinuteCandle;
+use crate::types::stream::stock::exec;
+use std::collections::BTreeMap;
+
+/// 가치 영역(value area)에 포함할 거래량 비율
+const VALUE_AREA_RATIO: f64 = 0.7;
+
+/// 가격대별 거래량(volume profile)
+/// 실시간 체결 또는 분봉으로 가격별 거래량을 합산하며, 네트워크 요청 없이 계산만 함
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct VolumeProfile {
+    levels: BTreeMap<u32, u64>, // 가격 -> 거래량
+    total_volume: u64,
+}
+
+impl VolumeProfile {
+    /// (가격, 거래량) 목록으로 생성. 같은 가격의 거래량은 합산
+    pub fn from_levels(levels: impl IntoIterator<Item = (u32, u64)>) -> Self {
+        let mut profile = Self::default();
+        for (price, volume) in levels {
+            profile.add(price, volume);
+        }
+        profile
+    }
+
+    /// 실시간 체결(H0STCNT0)의 체결가별 체결 거래량
+    pub fn from_ticks(ticks: &[exec::Body]) -> Self {
+        Self::from_levels(
+            ticks
+                .iter()
+                .map(|tick| (tick.current_price, tick.exec_volume)),
+        )
+    }
+
+    /// 분봉의 종가별 체결 거래량
+    /// 분봉 안의 가격 분포는 알 수 없으므로 거래량 전체를 종가에 합산. 종가나 거래량이 없는 분봉은 제외
+    pub fn from_minute_bars(bars: &[MinuteCandle]) -> Self {
+        Self::from_levels(bars.iter().filter_map(|bar| {
+            let price = u32::try_from(bar.stck_prpr()?).ok()?;
+            let volume = u64::try_from(bar.cntg_vol()?).ok()?;
+            Some((price, volume))
+        }))
+    }
+
+    /// 거래량 추가
+    pub fn add(&mut self, price: u32, volume: u64) {
+        if volume == 0 {
+            return;
+        }
+        *self.levels.entry(price).or_default() += volume;
+        self.total_volume += volume;
+    }
+
+    /// 가격별 거래량(가격 오름차순)
+    pub fn levels(&self) -> &BTreeMap<u32, u64> {
+        &self.levels
+    }
+
+    pub fn total_volume(&self) -> u64 {
+        self.total_volume
+    }
+
+    /// 거래량이 가장 많은 가격(POC). 거래량이 같으면 낮은 가격
+    pub fn point_of_control(&self) -> Option<u32> {
+        self.levels
+            .iter()
+            .fold(
+                None,
+                |best: Option<(u32, u64)>, (price, volume)| match best {
+                    Some((_, best_volume)) if best_volume >= *volume => best,
+                    _ => Some((*price, *volume)),
+                },
+            )
+            .map(|(price, _)| price)
+    }
+
+    /// 전체 거래량의 70%를 포함하는 가치 영역의 (저가, 고가)
+    pub fn value_area(&self) -> Option<(u32, u32)> {
+        self.value_area_with(VALUE_AREA_RATIO)
+    }
+
+    /// 가치 영역 하단
+    pub fn value_area_low(&self) -> Option<u32> {
+        self.value_area().map(|(low, _)| low)
+    }
+
+    /// 가치 영역 상단
+    pub fn value_area_high(&self) -> Option<u32> {
+        self.value_area().map(|(_, high)| high)
+    }
+
+    /// 전체 거래량의 ratio(0.0~1.0)를 포함하는 가치 영역의 (저가, 고가)
+    /// POC에서 시작하여 위/아래 인접 가격 중 거래량이 많은 쪽으로 넓혀 감(같으면 위쪽)
+    pub fn value_area_with(&self, ratio: f64) -> Option<(u32, u32)> {
+        let poc = self.point_of_control()?;
+        let levels: Vec<(u32, u64)> = self.levels.iter().map(|(p, v)| (*p, *v)).collect();
+        let poc_index = levels.iter().position(|(price, _)| *price == poc)?;
+        let target = (self.total_volume as f64 * ratio.clamp(0.0, 1.0)).ceil() as u64;
+        let (mut low, mut high) = (poc_index, poc_index);
+        let mut volume = levels[poc_index].1;
+        while volume < target {
+            let below = low.checked_sub(1).map(|i| levels[i].1);
+            let above = levels.get(high + 1).map(|(_, v)| *v);
+            match (below, above) {
+                (Some(b), Some(a)) if b > a => {
+                    low -= 1;
+                    volume += b;
+                }
+                (_, Some(a)) => {
+                    high += 1;
+                    volume += a;
+                }
+                (Some(b), None) => {
+                    low -= 1;
+                    volume += b;
+                }
+                (None, None) => break,
+            }
+        }
+        Some((levels[low].0, levels[high].0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bell() -> VolumeProfile {
+        VolumeProfile::from_levels([(100, 10), (110, 20), (120, 40), (130, 20), (140, 10)])
+    }
+
+    #[test]
+    fn from_levels_merges_same_price() {
+        let profile = VolumeProfile::from_levels([(100, 3), (110, 0), (100, 4)]);
+        assert_eq!(profile.levels().len(), 1);
+        assert_eq!(profile.levels()[&100], 7);
+        assert_eq!(profile.total_volume(), 7);
+    }
+
+    #[test]
+    fn point_of_control_tie_takes_lower_price() {
+        let profile = VolumeProfile::from_levels([(120, 3), (110, 5), (100, 5)]);
+        assert_eq!(profile.point_of_control(), Some(100));
+        assert_eq!(bell().point_of_control(), Some(120));
+    }
+
+    #[test]
+    fn value_area_expands_toward_larger_side() {
+        // 120(40) -> 위아래 20으로 같으므로 130 -> 110(20) > 140(10)이므로 110: 80 >= 70
+        assert_eq!(bell().value_area(), Some((110, 130)));
+        assert_eq!(bell().value_area_low(), Some(110));
+        assert_eq!(bell().value_area_high(), Some(130));
+    }
+
+    #[test]
+    fn value_area_equal_volume_expands_upward() {
+        let profile = VolumeProfile::from_levels([(100, 10), (110, 30), (120, 10)]);
+        assert_eq!(profile.value_area(), Some((110, 120)));
+    }
+
+    #[test]
+    fn value_area_expands_one_side_at_the_edge() {
+        let profile = VolumeProfile::from_levels([(100, 50), (110, 30), (120, 20)]);
+        assert_eq!(profile.value_area(), Some((100, 110)));
+        let profile = VolumeProfile::from_levels([(100, 20), (110, 30), (120, 50)]);
+        assert_eq!(profile.value_area(), Some((110, 120)));
+    }
+
+    #[test]
+    fn empty_profile() {
+        let mut profile = VolumeProfile::default();
+        profile.add(100, 0);
+        assert_eq!(profile.total_volume(), 0);
+        assert_eq!(profile.point_of_control(), None);
+        assert_eq!(profile.value_area(), None);
+        assert_eq!(profile.value_area_with(1.0), None);
+    }
+
+    #[test]
+    fn value_area_ratio_is_clamped() {
+        assert_eq!(bell().value_area_with(2.0), Some((100, 140)));
+        assert_eq!(bell().value_area_with(1.0), Some((100, 140)));
+        assert_eq!(bell().value_area_with(-1.0), Some((120, 120)));
+        assert_eq!(bell().value_area_with(0.0), Some((120, 120)));
+        assert_eq!(bell().value_area_with(f64::NAN), Some((120, 120)));
+    }
+}
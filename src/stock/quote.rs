@@ -1,22 +1,199 @@
-use reqwest::Response;
-use url::Url;
-
+use crate::client::{Envelope, HeaderOverrides, RestClient};
+use crate::stock::calendar::MarketCalendar;
+use crate::types::de::parse_yyyymmdd;
+#[cfg(feature = "decimal")]
+use crate::types::request::stock::quote::DividendScheduleParameter;
 use crate::types::request::stock::quote::{
-    DailyPriceParameter, PeriodicPriceParameter, VolumeRankParameter,
+    CurrentPriceParameter, DailyPriceParameter, ElwUnderlyingParameter, FluctuationRankParameter,
+    FluctuationSort, IndexPriceParameter, MinuteChartParameter, PeriodicPriceParameter,
+    ProgramTradeParameter, SectorIndexParameter, TimeConclusionParameter, VolumeRankParameter,
+    WatchlistPriceParameter, WATCHLIST_MAX_SYMBOLS,
 };
+use crate::types::response::stock::quote::output::{MinuteCandle, PeriodicPrice, WatchlistPrice};
 use crate::types::response::stock::quote::{
-    DailyPriceResponse, PeriodicPriceResponse, VolumeRankResponse,
+    AskingPriceResponse, Candle, ConclusionStrengthResponse, CurrentPriceResponse,
+    DailyPriceResponse, ElwPriceResponse, ElwSensitivityResponse, ElwUnderlyingResponse,
+    ExpectedConclusion, FluctuationRankResponse, IndexPriceResponse, InvestorTrendResponse,
+    LimitStock, MinuteChartResponse, PeriodicPriceResponse, ProgramTradeResponse, StockInfo,
+    StockStatus, VolumeRankResponse, WatchlistFailure, WatchlistPriceResponse, WatchlistPrices,
+    CANDLE_CSV_HEADER,
+};
+#[cfg(feature = "decimal")]
+use crate::types::response::stock::quote::{DividendEvent, DividendScheduleResponse};
+use crate::types::{
+    Account, ChartPeriod, DailyPeriod, DateRange, Environment, Granularity, IndexCode,
+    ListedMarket, MarketCode, Time, TrId,
 };
-use crate::types::{Account, Environment, MarketCode, PeriodCode, TrId};
-use crate::{auth, Error};
+use crate::{auth, validate, Cache, Error};
+use chrono::{Datelike, NaiveDate, NaiveTime, Timelike};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 장 시작/종료 시각(분봉 조회 범위)
+const MARKET_OPEN_HHMMSS: &str = "090000";
+const MARKET_CLOSE_HHMMSS: &str = "153000";
+
+/// 기간별시세 1회 조회 최대 건수
+const PERIODIC_PRICE_MAX_ROWS: usize = 100;
+
+/// history_cache에 저장한 기간별시세의 유효 기간
+const HISTORY_CACHE_TTL: Duration = Duration::from_secs(30 * 24 * 60 * 60);
 
 #[derive(Clone)]
 pub struct Quote {
-    client: reqwest::Client,
-    endpoint_url: String,
-    environment: Environment,
-    auth: auth::Auth,
+    rest: RestClient,
+    #[allow(dead_code)]
     account: Account,
+    calendar: Option<MarketCalendar>,
+    master: Arc<HashMap<String, StockInfo>>,
+    history_cache: Option<Arc<dyn Cache>>,
+}
+
+/// 기간별시세 캐시 값. start~end 기간의 봉(KIS 응답 형식)
+#[derive(Serialize, Deserialize)]
+struct HistoryEntry {
+    start: String, // YYYYMMDD
+    end: String,   // YYYYMMDD, 당일 봉은 장중에 바뀌므로 최대 전일까지만 조회된 것으로 기록
+    rows: Vec<serde_json::Value>,
+}
+
+/// periodic_candles 후처리 옵션
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CandleOptions {
+    normalize: bool, // 오래된 일자부터 정렬하고 일자 순서를 확인
+    fill_gaps: bool, // 일봉에서 시세가 없는 개장일을 직전 종가로 채움(normalize 필요)
+}
+
+impl CandleOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 오래된 일자부터 정렬하고 일자 순서를 확인
+    pub fn normalize(mut self) -> Self {
+        self.normalize = true;
+        self
+    }
+
+    /// 일봉에서 시세가 없는 개장일을 직전 종가로 채움(normalize 포함)
+    pub fn fill_gaps(mut self) -> Self {
+        self.normalize = true;
+        self.fill_gaps = true;
+        self
+    }
+}
+
+/// 봉을 오래된 일자부터 정렬(KIS는 최신 일자부터 응답)하고 같은 일자가 없는지 확인
+/// 정렬 후에도 일자가 증가하지 않으면(같은 일자가 중복되면) Error::BrokenProtocol
+/// 분봉은 일자와 시각으로 비교
+pub fn normalize_candles(mut candles: Vec<Candle>) -> Result<Vec<Candle>, Error> {
+    candles.sort_by_key(|c| (c.date(), c.time()));
+    if let Some(pair) = candles
+        .windows(2)
+        .find(|pair| (pair[0].date(), pair[0].time()) >= (pair[1].date(), pair[1].time()))
+    {
+        return Err(Error::BrokenProtocol(
+            "candle dates are not monotonic",
+            pair[1].date().to_string(),
+        ));
+    }
+    Ok(candles)
+}
+
+/// 오래된 일자부터 정렬된 일봉에서 open_days 중 시세가 없는 날을 직전 봉의 종가로 채움
+/// 첫 봉 이전과 마지막 봉 이후(아직 시세가 없는 당일 등)의 개장일은 채우지 않음
+pub fn fill_gaps_with(candles: &[Candle], open_days: &[NaiveDate]) -> Vec<Candle> {
+    let mut filled: Vec<Candle> = Vec::with_capacity(candles.len().max(open_days.len()));
+    let mut days = open_days.iter().peekable();
+    for candle in candles {
+        while let Some(day) = days.next_if(|day| **day < candle.date()) {
+            if let Some(previous) = filled.last() {
+                let gap = Candle::forward_filled(previous, *day);
+                filled.push(gap);
+            }
+        }
+        days.next_if(|day| **day == candle.date());
+        filled.push(candle.clone());
+    }
+    filled
+}
+
+/// 기간별시세 행을 봉으로 변환
+/// 일자가 빈 행(KIS가 빈 값으로 채워 보내는 행)은 건너뛰고, 일자나 종가를 읽을 수 없는 행은 버린 뒤 그 수를 warn!으로 남김
+fn to_candles<'a>(prices: impl IntoIterator<Item = &'a PeriodicPrice>) -> Vec<Candle> {
+    let mut dropped = Vec::new();
+    let candles = prices
+        .into_iter()
+        .filter(|price| !price.stck_bsop_date().trim().is_empty())
+        .filter_map(|price| {
+            Candle::try_from(price)
+                .map_err(|_| dropped.push(price.stck_bsop_date().clone()))
+                .ok()
+        })
+        .collect();
+    if !dropped.is_empty() {
+        warn!(
+            "Dropped {} periodic price rows that could not be converted to candles: {:?}",
+            dropped.len(),
+            dropped
+        );
+    }
+    candles
+}
+
+/// 장 시작 시각(KST). 분봉 재집계 구간의 기준
+const SESSION_OPEN: u32 = 9 * 3600;
+
+/// candles를 target 단위의 봉으로 재집계
+/// 시가는 구간의 첫 봉, 고가는 최대, 저가는 최소, 종가는 마지막 봉, 거래량은 합계
+/// 분봉(Minutes)은 장 시작(09:00)부터 n분 단위로 나누며 날짜가 바뀌면 새 구간. 결과 봉의 시각은 구간 시작 시각
+/// 일/주/월/년봉의 일자는 구간의 마지막 영업일. 분봉을 더 짧은 분봉으로 나누거나 일봉을 분봉으로 바꿀 수는 없으며 빈 Vec을 반환
+pub fn resample(candles: &[Candle], target: Granularity) -> Vec<Candle> {
+    let mut sorted = candles.to_vec();
+    sorted.sort_by_key(|c| (c.date(), c.time()));
+    let bucket = |c: &Candle| -> Option<(i32, i64, Option<NaiveTime>)> {
+        let date = c.date();
+        match target {
+            Granularity::Minutes(0) => None,
+            Granularity::Minutes(n) => {
+                let time = c.time()?;
+                let secs = time.num_seconds_from_midnight() as i64 - SESSION_OPEN as i64;
+                let index = secs.div_euclid(n as i64 * 60);
+                let start = SESSION_OPEN as i64 + index * n as i64 * 60;
+                let start = NaiveTime::from_num_seconds_from_midnight_opt(start as u32, 0);
+                Some((date.num_days_from_ce(), index, start))
+            }
+            Granularity::Days => Some((date.num_days_from_ce(), 0, None)),
+            Granularity::Weeks => {
+                let week = date.iso_week();
+                Some((week.year(), week.week() as i64, None))
+            }
+            Granularity::Months => Some((date.year(), date.month() as i64, None)),
+            Granularity::Years => Some((date.year(), 0, None)),
+        }
+    };
+    let mut resampled = Vec::new();
+    let mut group: Vec<Candle> = Vec::new();
+    let mut current: Option<(i32, i64, Option<NaiveTime>)> = None;
+    for candle in sorted {
+        let Some(key) = bucket(&candle) else {
+            return Vec::new();
+        };
+        if current.is_some_and(|current| current != key) {
+            resampled.extend(Candle::merge(&group, current.and_then(|c| c.2)));
+            group.clear();
+        }
+        current = Some(key);
+        group.push(candle);
+    }
+    resampled.extend(Candle::merge(&group, current.and_then(|c| c.2)));
+    resampled
 }
 
 impl Quote {
@@ -28,28 +205,246 @@ impl Quote {
         auth: auth::Auth,
         account: Account,
     ) -> Result<Self, Error> {
-        let endpoint_url = match environment {
-            Environment::Real => "https://openapi.koreainvestment.com:9443",
-            Environment::Virtual => "https://openapivts.koreainvestment.com:29443",
-        }
-        .to_string();
         Ok(Self {
-            client: client.clone(),
-            endpoint_url,
-            environment,
-            auth,
+            rest: RestClient::new(client, environment, auth),
             account,
+            calendar: None,
+            master: Arc::new(HashMap::new()),
+            history_cache: None,
         })
     }
 
+    crate::client::rest_builders!();
+
+    /// periodic_price_full(periodic_candles 포함) 결과를 저장할 Cache(MemoryCache, FileCache 등)
+    /// 종목, 시장, 기간 분류, 수정주가 여부별로 조회한 기간과 봉을 저장하며, 저장된 기간 안의 조회는 요청 없이 반환하고
+    /// 시작일은 저장된 기간 안이지만 종료일이 그 뒤이면 저장된 마지막 일자부터만 다시 조회하여 합침
+    pub fn with_history_cache(mut self, cache: Arc<dyn Cache>) -> Self {
+        self.history_cache = Some(cache);
+        self
+    }
+
+    /// periodic_candles의 fill_gaps에서 개장일을 확인할 MarketCalendar
+    pub fn with_calendar(mut self, calendar: MarketCalendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// sector_constituents에서 업종별 종목을 찾을 종목 마스터(parse_master 결과, 여러 시장은 합쳐서 전달)
+    pub fn with_master(mut self, master: HashMap<String, StockInfo>) -> Self {
+        self.master = Arc::new(master);
+        self
+    }
+
+    /// 주식현재가 시세[v1_국내주식-008]
+    pub async fn current_price(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+    ) -> Result<CurrentPriceResponse, Error> {
+        Ok(self
+            .current_price_with_meta(market_code, shortcode)
+            .await?
+            .body)
+    }
+
+    /// 상장 시장을 지정한 주식현재가 시세[v1_국내주식-008]
+    /// 코스피, 코스닥, 코넥스 종목 모두 Stock(J)으로 조회하며,
+    /// 모의투자에서 코넥스 종목은 요청하지 않고 Error::UnsupportedListedMarket 반환
+    pub async fn current_price_listed(
+        &self,
+        listed_market: ListedMarket,
+        shortcode: &str,
+    ) -> Result<CurrentPriceResponse, Error> {
+        if !listed_market.is_supported_in(self.rest.environment()) {
+            return Err(Error::UnsupportedListedMarket(listed_market));
+        }
+        self.current_price(MarketCode::Stock, shortcode).await
+    }
+
+    /// 주식현재가 시세[v1_국내주식-008]
+    /// 응답 헤더(tr_cont, tr_id, gt_uid)를 함께 반환
+    pub async fn current_price_with_meta(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+    ) -> Result<Envelope<CurrentPriceResponse>, Error> {
+        self.check_market(&market_code)?;
+        validate::shortcode("shortcode", shortcode)?;
+        let tr_id = TrId::CurrentPrice;
+        let param = CurrentPriceParameter::new(market_code, shortcode.to_string());
+        let path = "/uapi/domestic-stock/v1/quotations/inquire-price";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get_with_meta(url, tr_id).await
+    }
+
+    /// 종목 상태
+    /// 주식현재가 시세의 관리종목, 시장경고(투자주의/경고/위험), 거래정지, 단기과열, 정리매매 여부와 외국인 소진율
+    pub async fn stock_status(&self, shortcode: &str) -> Result<StockStatus, Error> {
+        let response = self.current_price(MarketCode::Stock, shortcode).await?;
+        response
+            .output()
+            .as_ref()
+            .map(StockStatus::from)
+            .ok_or(Error::BrokenProtocol(
+                "no output in current price response",
+                shortcode.to_string(),
+            ))
+    }
+
+    /// 주식현재가 호가/예상체결[v1_국내주식-011]
+    pub async fn asking_price(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+    ) -> Result<AskingPriceResponse, Error> {
+        self.check_market(&market_code)?;
+        validate::shortcode("shortcode", shortcode)?;
+        let tr_id = TrId::AskingPrice;
+        let param = CurrentPriceParameter::new(market_code, shortcode.to_string());
+        let path = "/uapi/domestic-stock/v1/quotations/inquire-asking-price-exp-ccn";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 동시호가 예상체결가
+    /// 장 시작(08:30~09:00)/마감(15:20~15:30) 동시호가 시간이 아니면 Error::NotInAuction
+    /// with_calendar로 MarketCalendar를 설정했으면 휴장일도 동시호가 시간이 아닌 것으로 처리
+    pub async fn expected_price(&self, shortcode: &str) -> Result<ExpectedConclusion, Error> {
+        let in_auction = match &self.calendar {
+            Some(calendar) => calendar.is_auction_now().await?,
+            None => MarketCalendar::is_auction_hours(Time::now().inner().time()),
+        };
+        if !in_auction {
+            return Err(Error::NotInAuction);
+        }
+        let response = self.asking_price(MarketCode::Stock, shortcode).await?;
+        response
+            .output2()
+            .as_ref()
+            .and_then(|expected| ExpectedConclusion::try_from(expected).ok())
+            .ok_or(Error::BrokenProtocol(
+                "no expected conclusion in asking price response",
+                shortcode.to_string(),
+            ))
+    }
+
+    /// 관심종목(멀티종목) 시세조회[국내주식-205]
+    /// 30종목씩 나누어 조회하고 codes 순서대로 합쳐서 반환. 모의투자 미지원
+    /// 일부 요청이 실패해도 나머지 종목은 조회하며, 실패한 요청의 종목과 에러는 WatchlistPrices::failures로 반환
+    pub async fn watchlist_prices(&self, codes: &[&str]) -> Result<WatchlistPrices, Error> {
+        let tr_id = self.rest.require_real(TrId::WatchlistPrice)?;
+        for code in codes {
+            validate::shortcode("codes", code)?;
+        }
+        let path = "/uapi/domestic-stock/v1/quotations/intstock-multprice";
+        let mut prices: Vec<WatchlistPrice> = Vec::with_capacity(codes.len());
+        let mut failures = Vec::new();
+        for chunk in codes.chunks(WATCHLIST_MAX_SYMBOLS) {
+            let chunk_codes: Vec<String> = chunk.iter().map(|code| code.to_string()).collect();
+            let param = WatchlistPriceParameter::new(chunk_codes.clone())?;
+            let url = self.rest.url(path, param.into_iter())?;
+            let response: WatchlistPriceResponse = match self.rest.get(url, tr_id.clone()).await {
+                Ok(response) => response,
+                Err(e) => {
+                    failures.push(WatchlistFailure::new(chunk_codes, e));
+                    continue;
+                }
+            };
+            let mut page = response.output().clone().unwrap_or_default();
+            page.sort_by_key(|price| {
+                chunk
+                    .iter()
+                    .position(|code| code == price.inter_shrn_iscd())
+                    .unwrap_or(chunk.len())
+            });
+            prices.extend(page);
+        }
+        Ok(WatchlistPrices::new(prices, failures))
+    }
+
+    /// 주식현재가 투자자[v1_국내주식-012]
+    /// 최근 영업일부터 일자별 개인/외국인/기관계 순매수 수량과 거래대금
+    pub async fn investor_trend(&self, shortcode: &str) -> Result<InvestorTrendResponse, Error> {
+        validate::shortcode("shortcode", shortcode)?;
+        let tr_id = TrId::InvestorTrend;
+        let param = CurrentPriceParameter::new(MarketCode::Stock, shortcode.to_string());
+        let path = "/uapi/domestic-stock/v1/quotations/inquire-investor";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 프로그램매매 종합현황(시간)[국내주식-114]
+    /// 당일 시간대별 차익/비차익 프로그램매매 매도, 매수, 순매수 수량과 거래대금. 모의투자 미지원
+    pub async fn program_trade_trend(
+        &self,
+        market: ListedMarket,
+    ) -> Result<ProgramTradeResponse, Error> {
+        let tr_id = self.rest.require_real(TrId::ProgramTradeTrend)?;
+        let param = ProgramTradeParameter::new(market)?;
+        let path = "/uapi/domestic-stock/v1/quotations/comp-program-trade-today";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 주식현재가 시간대별체결[v1_국내주식-023]
+    /// 현재 시각 이전의 시간대별 체결가, 체결량과 당일 체결강도(최근 시각부터)
+    /// 매수/매도 체결량은 ConclusionStrengthResponse::estimated_buy_sell_volume으로 추정
+    pub async fn conclusion_strength(
+        &self,
+        shortcode: &str,
+    ) -> Result<ConclusionStrengthResponse, Error> {
+        validate::shortcode("shortcode", shortcode)?;
+        let tr_id = TrId::TimeConclusion;
+        let hour = Time::now().inner().format("%H%M%S").to_string();
+        let param = TimeConclusionParameter::new(shortcode, &hour);
+        let path = "/uapi/domestic-stock/v1/quotations/inquire-time-itemconclusion";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 예탁원정보(배당일정)[국내주식-145]
+    /// year의 배당 기준일, 지급일, 주당 배당금, 배당률. 모의투자 미지원
+    /// 무상증자, 합병/분할 등 다른 권리 일정은 이 TR에서 제공하지 않음
+    #[cfg(feature = "decimal")]
+    pub async fn dividend_schedule(
+        &self,
+        shortcode: &str,
+        year: u16,
+    ) -> Result<Vec<DividendEvent>, Error> {
+        let tr_id = self.rest.require_real(TrId::DividendSchedule)?;
+        validate::shortcode("shortcode", shortcode)?;
+        let year = i32::from(year);
+        let range = match (
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            NaiveDate::from_ymd_opt(year, 12, 31),
+        ) {
+            (Some(start), Some(end)) => DateRange::new(start, end)?,
+            _ => {
+                return Err(Error::InvalidParam {
+                    field: "year",
+                    reason: "out of range",
+                })
+            }
+        };
+        let param = DividendScheduleParameter::new(shortcode.to_string(), &range);
+        let path = "/uapi/domestic-stock/v1/ksdinfo/dividend";
+        let url = self.rest.url(path, &param.into_iter())?;
+        let response: DividendScheduleResponse = self.rest.get(url, tr_id).await?;
+        Ok(response.output1().iter().map(DividendEvent::from).collect())
+    }
+
     /// 주식현재가 일자별[v1_국내주식-010]
+    /// 최근 30일/30주/30개월(년 단위는 DailyPeriod에 없으므로 지정 불가)
     pub async fn daily_price(
         &self,
         market_code: MarketCode,
         shortcode: &str,
-        period_code: PeriodCode,
+        period_code: DailyPeriod,
         is_adjust_price: bool,
     ) -> Result<DailyPriceResponse, Error> {
+        self.check_market(&market_code)?;
+        validate::shortcode("shortcode", shortcode)?;
         let tr_id = TrId::DailyPrice;
         let param = DailyPriceParameter::new(
             market_code,
@@ -57,28 +452,56 @@ impl Quote {
             period_code,
             is_adjust_price,
         );
-        let url = format!(
-            "{}/uapi/domestic-stock/v1/quotations/inquire-daily-price",
-            self.endpoint_url
-        );
-        let url = reqwest::Url::parse_with_params(&url, &param.into_iter())?;
-        Ok(self.send_request(url, tr_id).await?.json().await?)
+        let path = "/uapi/domestic-stock/v1/quotations/inquire-daily-price";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
     }
 
+    /// 여러 종목의 주식현재가 일자별[v1_국내주식-010]을 동시에 조회
+    /// 동시 요청 수는 RateLimiter의 초당 허용 건수로 제한되며, 결과는 입력 순서대로 반환
+    /// 일부 종목의 실패는 해당 위치의 Err로만 반환되고 나머지 조회는 계속됨
+    pub async fn daily_price_many(
+        &self,
+        requests: Vec<(MarketCode, String)>,
+        period_code: DailyPeriod,
+        is_adjust_price: bool,
+    ) -> Vec<Result<DailyPriceResponse, Error>> {
+        let concurrency = self.rest.rate_limiter().per_second() as usize;
+        let mut results: Vec<(usize, Result<DailyPriceResponse, Error>)> =
+            futures::stream::iter(requests.into_iter().enumerate())
+                .map(|(i, (market_code, shortcode))| {
+                    let period_code = period_code.clone();
+                    async move {
+                        let result = self
+                            .daily_price(market_code, &shortcode, period_code, is_adjust_price)
+                            .await;
+                        (i, result)
+                    }
+                })
+                .buffer_unordered(concurrency)
+                .collect()
+                .await;
+        results.sort_by_key(|(i, _)| *i);
+        results.into_iter().map(|(_, result)| result).collect()
+    }
+
+    /// 국내주식기간별시세(일/주/월/년)[v1_국내주식-016]
+    /// 날짜를 YYYYMMDD 문자열로 직접 넘기는 하위 API. 일반적으로 periodic_price_range를 사용
     pub async fn periodic_price(
         &self,
         market_code: MarketCode,
         shortcode: &str,
-        period_code: PeriodCode,
+        period_code: ChartPeriod,
         start_day: &str, // YYYYMMDD
         end_day: &str,   // YYYYMMDD
         is_adjust_price: bool,
     ) -> Result<PeriodicPriceResponse, Error> {
+        self.check_market(&market_code)?;
+        validate::shortcode("shortcode", shortcode)?;
+        validate::yyyymmdd("start_day", start_day)?;
+        validate::yyyymmdd("end_day", end_day)?;
         let tr_id = TrId::PeriodicPrice;
-        let url = format!(
-            "{}/uapi/domestic-stock/v1/quotations/inquire-daily-itemchartprice",
-            self.endpoint_url
-        );
+        let path = "/uapi/domestic-stock/v1/quotations/inquire-daily-itemchartprice";
         let param = PeriodicPriceParameter::new(
             market_code,
             shortcode.to_string(),
@@ -87,43 +510,1282 @@ impl Quote {
             period_code,
             is_adjust_price,
         );
-        let url = reqwest::Url::parse_with_params(&url, &param.into_iter())?;
-        Ok(self.send_request(url, tr_id).await?.json().await?)
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 국내주식기간별시세(일/주/월/년)[v1_국내주식-016]
+    /// 한 번에 최대 100건까지 조회되므로, 기간이 이를 넘으면 Error::InvalidDateRange
+    pub async fn periodic_price_range(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        range: DateRange,
+        period_code: ChartPeriod,
+        is_adjust_price: bool,
+    ) -> Result<PeriodicPriceResponse, Error> {
+        let rows = match period_code {
+            ChartPeriod::Days => range.weekdays(),
+            ChartPeriod::Weeks => range.weeks(),
+            ChartPeriod::Months => range.months(),
+            ChartPeriod::Years => range.years(),
+        };
+        if rows > PERIODIC_PRICE_MAX_ROWS {
+            return Err(Error::InvalidDateRange(
+                "range exceeds 100 rows per request",
+            ));
+        }
+        self.periodic_price(
+            market_code,
+            shortcode,
+            period_code,
+            &range.start_str(),
+            &range.end_str(),
+            is_adjust_price,
+        )
+        .await
+    }
+
+    /// 국내주식기간별시세(일/주/월/년)[v1_국내주식-016] 봉
+    /// options.normalize가 false이면 100건 이내 기간을 한 번 조회하여 KIS 응답 순서(최신 일자부터)대로 반환
+    /// true이면 전체 기간을 조회하여 오래된 일자부터 정렬하고 일자가 증가하는지 확인
+    /// options.fill_gaps는 일봉에서만 사용하며, with_calendar로 설정한 MarketCalendar의 개장일 중 시세가 없는 날을 직전 종가로 채움
+    pub async fn periodic_candles(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        range: DateRange,
+        period_code: ChartPeriod,
+        is_adjust_price: bool,
+        options: CandleOptions,
+    ) -> Result<Vec<Candle>, Error> {
+        if !options.normalize {
+            let response = self
+                .periodic_price_range(market_code, shortcode, range, period_code, is_adjust_price)
+                .await?;
+            return Ok(to_candles(response.output2().iter().flatten()));
+        }
+        let fill_gaps = options.fill_gaps && matches!(period_code, ChartPeriod::Days);
+        let calendar = match (&self.calendar, fill_gaps) {
+            (Some(calendar), true) => Some(calendar),
+            (None, true) => {
+                return Err(Error::InvalidParam {
+                    field: "fill_gaps",
+                    reason: "requires a MarketCalendar(with_calendar)",
+                })
+            }
+            _ => None,
+        };
+        let prices = self
+            .periodic_price_full(market_code, shortcode, range, period_code, is_adjust_price)
+            .await?;
+        let candles = normalize_candles(to_candles(&prices))?;
+        let calendar = match calendar {
+            Some(calendar) => calendar,
+            None => return Ok(candles),
+        };
+        let mut open_days = Vec::new();
+        for date in range.start().iter_days().take_while(|d| *d <= range.end()) {
+            if calendar.is_open_day(date).await? {
+                open_days.push(date);
+            }
+        }
+        Ok(fill_gaps_with(&candles, &open_days))
+    }
+
+    /// 국내주식기간별시세(일/주/월/년)[v1_국내주식-016] 전체 기간
+    /// 이 API는 연속조회 키를 돌려주지 않으므로, 직전 응답의 가장 이른 일자 전날로 조회 종료일을 옮기며
+    /// range 시작일까지 반복 조회함. 페이지 경계에서 겹치는 일자는 한 번만 포함
+    /// 결과는 오래된 일자부터 정렬됨
+    pub async fn periodic_price_full(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        range: DateRange,
+        period_code: ChartPeriod,
+        is_adjust_price: bool,
+    ) -> Result<Vec<PeriodicPrice>, Error> {
+        match &self.history_cache {
+            Some(cache) => {
+                self.cached_periodic_price(
+                    cache.as_ref(),
+                    market_code,
+                    shortcode,
+                    range,
+                    period_code,
+                    is_adjust_price,
+                )
+                .await
+            }
+            None => {
+                self.fetch_periodic_price(
+                    market_code,
+                    shortcode,
+                    range,
+                    period_code,
+                    is_adjust_price,
+                )
+                .await
+            }
+        }
+    }
+
+    /// range 전체 기간을 조회하여 오래된 일자부터 정렬
+    async fn fetch_periodic_price(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        range: DateRange,
+        period_code: ChartPeriod,
+        is_adjust_price: bool,
+    ) -> Result<Vec<PeriodicPrice>, Error> {
+        let mut candles: BTreeMap<String, PeriodicPrice> = BTreeMap::new();
+        self.for_each_periodic_page(
+            market_code,
+            shortcode,
+            range,
+            period_code,
+            is_adjust_price,
+            |page| {
+                for candle in page {
+                    candles
+                        .entry(candle.stck_bsop_date().clone())
+                        .or_insert(candle);
+                }
+                Ok(())
+            },
+        )
+        .await?;
+        Ok(candles.into_values().collect())
+    }
+
+    /// history_cache에 저장된 기간을 사용하고 부족한 기간만 조회
+    async fn cached_periodic_price(
+        &self,
+        cache: &dyn Cache,
+        market_code: MarketCode,
+        shortcode: &str,
+        range: DateRange,
+        period_code: ChartPeriod,
+        is_adjust_price: bool,
+    ) -> Result<Vec<PeriodicPrice>, Error> {
+        let key = format!(
+            "history:{}:{}:{}:{}:{}",
+            self.rest.environment(),
+            market_code,
+            shortcode,
+            period_code,
+            if is_adjust_price { "Y" } else { "N" }
+        );
+        let (start, end) = (range.start_str(), range.end_str());
+        let cached: Option<HistoryEntry> = cache
+            .get(&key)
+            .and_then(|value| serde_json::from_str(&value).ok());
+        let in_range = |rows: Vec<PeriodicPrice>| -> Vec<PeriodicPrice> {
+            rows.into_iter()
+                .filter(|row| start <= *row.stck_bsop_date() && *row.stck_bsop_date() <= end)
+                .collect()
+        };
+        let (mut candles, covered) = match cached {
+            Some(entry) => {
+                let rows: Vec<PeriodicPrice> = serde_json::from_value(entry.rows.into())?;
+                if entry.start <= start && end <= entry.end {
+                    return Ok(in_range(rows));
+                }
+                let first = parse_yyyymmdd(&entry.start).ok_or(Error::InvalidDateRange(
+                    "invalid start date in history cache",
+                ))?;
+                let last = parse_yyyymmdd(&entry.end)
+                    .ok_or(Error::InvalidDateRange("invalid end date in history cache"))?;
+                let candles: BTreeMap<String, PeriodicPrice> = rows
+                    .into_iter()
+                    .map(|row| (row.stck_bsop_date().clone(), row))
+                    .collect();
+                (candles, Some((first, last)))
+            }
+            None => (BTreeMap::new(), None),
+        };
+        // 저장된 기간 앞뒤로 부족한 기간만 조회. 저장된 기간과 떨어진 기간을 요청하면
+        // 사이 기간도 함께 조회하여 저장된 기간이 항상 하나로 이어지게 함
+        let fetch_ranges = match covered {
+            Some((first, last)) => {
+                let mut fetch_ranges = Vec::new();
+                if range.start() < first {
+                    let head_end = first.pred_opt().unwrap_or(first);
+                    fetch_ranges.push(DateRange::new(range.start(), head_end)?);
+                }
+                if last < range.end() {
+                    // 저장된 마지막 일자부터 다시 조회하여 그 일자의 봉(주봉, 월봉은 구간)을 갱신
+                    fetch_ranges.push(DateRange::new(last, range.end())?);
+                }
+                fetch_ranges
+            }
+            None => vec![range],
+        };
+        for fetch_range in fetch_ranges {
+            let fetched = self
+                .fetch_periodic_price(
+                    market_code.clone(),
+                    shortcode,
+                    fetch_range,
+                    period_code.clone(),
+                    is_adjust_price,
+                )
+                .await?;
+            for row in fetched {
+                candles.insert(row.stck_bsop_date().clone(), row);
+            }
+        }
+        let yesterday = Time::now()
+            .inner()
+            .date_naive()
+            .pred_opt()
+            .unwrap_or_default();
+        // 당일 봉은 장중에 바뀌므로 최대 전일까지만 조회된 것으로 기록
+        let fetched_end = range.end().min(yesterday);
+        let (covered_start, covered_end) = match covered {
+            Some((first, last)) => (first.min(range.start()), last.max(fetched_end)),
+            None => (range.start(), fetched_end),
+        };
+        let entry = HistoryEntry {
+            start: covered_start.format("%Y%m%d").to_string(),
+            end: covered_end.format("%Y%m%d").to_string(),
+            rows: candles.values().map(PeriodicPrice::to_json).collect(),
+        };
+        if entry.start <= entry.end {
+            cache.set(&key, serde_json::to_string(&entry)?, HISTORY_CACHE_TTL);
+        }
+        Ok(in_range(candles.into_values().collect()))
+    }
+
+    /// 국내주식기간별시세(일/주/월/년)[v1_국내주식-016] 전체 기간을 CSV 파일로 저장
+    /// 전체 봉을 메모리에 모으지 않고 페이지를 받을 때마다 기록하므로 KIS 응답 순서(최신 일자부터)로 저장됨
+    /// 열은 date(YYYY-MM-DD), open, high, low, close, volume, value이며 기록한 행 수를 반환
+    pub async fn periodic_price_full_to_csv(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        range: DateRange,
+        period_code: ChartPeriod,
+        is_adjust_price: bool,
+        path: impl AsRef<Path>,
+    ) -> Result<usize, Error> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(CANDLE_CSV_HEADER.as_bytes())?;
+        let mut rows = 0;
+        let mut last_written: Option<NaiveDate> = None;
+        self.for_each_periodic_page(
+            market_code,
+            shortcode,
+            range,
+            period_code,
+            is_adjust_price,
+            |mut page| {
+                page.sort_by(|a, b| b.stck_bsop_date().cmp(a.stck_bsop_date()));
+                for candle in to_candles(&page) {
+                    // 페이지 경계에서 겹치는 일자는 한 번만 기록
+                    if last_written.is_some_and(|last| candle.date() >= last) {
+                        continue;
+                    }
+                    candle.write_csv_row(&mut writer)?;
+                    last_written = Some(candle.date());
+                    rows += 1;
+                }
+                Ok(())
+            },
+        )
+        .await?;
+        writer.flush()?;
+        Ok(rows)
+    }
+
+    /// 조회 종료일을 직전 응답의 가장 이른 일자 전날로 옮기며 range 시작일까지 기간별시세를 조회하고 페이지마다 f 호출
+    async fn for_each_periodic_page<F>(
+        &self,
+        market_code: MarketCode,
+        shortcode: &str,
+        range: DateRange,
+        period_code: ChartPeriod,
+        is_adjust_price: bool,
+        mut f: F,
+    ) -> Result<(), Error>
+    where
+        F: FnMut(Vec<PeriodicPrice>) -> Result<(), Error>,
+    {
+        let start = range.start_str();
+        let mut end = range.end();
+        loop {
+            let response = self
+                .periodic_price(
+                    market_code.clone(),
+                    shortcode,
+                    period_code.clone(),
+                    &start,
+                    &end.format("%Y%m%d").to_string(),
+                    is_adjust_price,
+                )
+                .await?;
+            let page: Vec<PeriodicPrice> = response
+                .output2()
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|c| !c.stck_bsop_date().trim().is_empty())
+                .collect();
+            let earliest = match page
+                .iter()
+                .filter_map(|c| parse_yyyymmdd(c.stck_bsop_date()))
+                .min()
+            {
+                Some(earliest) => earliest,
+                None => break,
+            };
+            f(page)?;
+            let next = match earliest.pred_opt() {
+                Some(next) if next >= range.start() && next < end => next,
+                _ => break,
+            };
+            end = next;
+        }
+        Ok(())
+    }
+
+    /// 주식당일분봉조회[v1_국내주식-022]
+    /// time_hhmmss 이전 30건의 분봉을 최근 시각부터 반환
+    pub async fn minute_chart(
+        &self,
+        shortcode: &str,
+        time_hhmmss: &str,
+        include_past: bool,
+    ) -> Result<MinuteChartResponse, Error> {
+        validate::shortcode("shortcode", shortcode)?;
+        validate::hhmmss("time_hhmmss", time_hhmmss)?;
+        let tr_id = TrId::MinuteChart;
+        let param = MinuteChartParameter::new(
+            MarketCode::Stock,
+            shortcode.to_string(),
+            time_hhmmss.to_string(),
+            include_past,
+        );
+        let path = "/uapi/domestic-stock/v1/quotations/inquire-time-itemchartprice";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 주식당일분봉조회[v1_국내주식-022] 당일 전체
+    /// 장 종료 시각부터 직전 응답의 가장 이른 분봉 이전으로 기준 시각을 옮기며 장 시작까지 조회
+    /// (당일 분봉만 제공됨)
+    /// 결과는 최근 시각부터 정렬됨
+    pub async fn minute_chart_full(&self, shortcode: &str) -> Result<Vec<MinuteCandle>, Error> {
+        let open = chrono::NaiveTime::parse_from_str(MARKET_OPEN_HHMMSS, "%H%M%S")?;
+        let mut anchor = MARKET_CLOSE_HHMMSS.to_string();
+        let mut candles: Vec<MinuteCandle> = Vec::new();
+        loop {
+            let response = self.minute_chart(shortcode, &anchor, false).await?;
+            let page = response.output2().clone().unwrap_or_default();
+            let earliest = match page.last() {
+                Some(candle) => {
+                    chrono::NaiveTime::parse_from_str(candle.stck_cntg_hour(), "%H%M%S")?
+                }
+                None => break,
+            };
+            let last = candles.last().map(|c| c.stck_cntg_hour().clone());
+            candles.extend(page.into_iter().filter(|c| match &last {
+                Some(last) => c.stck_cntg_hour() < last,
+                None => true,
+            }));
+            if earliest <= open {
+                break;
+            }
+            let next = (earliest - std::time::Duration::from_secs(60))
+                .format("%H%M%S")
+                .to_string();
+            if next >= anchor {
+                break;
+            }
+            anchor = next;
+        }
+        Ok(candles)
+    }
+
+    /// 국내업종 현재지수[v1_국내주식-063]
+    pub async fn index_price(&self, index: IndexCode) -> Result<IndexPriceResponse, Error> {
+        let tr_id = TrId::IndexPrice;
+        let param = IndexPriceParameter::new(index);
+        let path = "/uapi/domestic-stock/v1/quotations/inquire-index-price";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 국내업종 현재지수[v1_국내주식-063]
+    /// sector_code는 업종코드 4자리(SectorCode::code() 등)
+    pub async fn sector_index(&self, sector_code: &str) -> Result<IndexPriceResponse, Error> {
+        let tr_id = TrId::IndexPrice;
+        let param = SectorIndexParameter::new(sector_code)?;
+        let path = "/uapi/domestic-stock/v1/quotations/inquire-index-price";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 업종 구성 종목
+    /// 업종별 구성 종목을 조회하는 TR이 없어 with_master로 불러온 종목 마스터의 지수업종대분류코드로 찾음
+    /// 종목코드 순으로 정렬하며, 종목 시세는 current_price 등으로 조회
+    pub fn sector_constituents(&self, sector_code: &str) -> Result<Vec<StockInfo>, Error> {
+        let sector_code = SectorIndexParameter::new(sector_code)?
+            .fid_input_iscd()
+            .clone();
+        if self.master.is_empty() {
+            return Err(Error::InvalidParam {
+                field: "master",
+                reason: "stock master is required; load it with with_master",
+            });
+        }
+        let mut constituents: Vec<StockInfo> = self
+            .master
+            .values()
+            .filter(|info| *info.sector() == sector_code)
+            .cloned()
+            .collect();
+        constituents.sort_by(|a, b| a.code().cmp(b.code()));
+        Ok(constituents)
+    }
+
+    /// ELW 현재가 시세[v1_국내주식-014]
+    /// 종목코드가 ELW가 아니면 Error::NotAnElw. 모의투자 미지원
+    pub async fn elw_price(&self, elw_code: &str) -> Result<ElwPriceResponse, Error> {
+        let tr_id = self.rest.require_real(TrId::ElwPrice)?;
+        validate::shortcode("elw_code", elw_code)?;
+        let param = CurrentPriceParameter::new(MarketCode::Elw, elw_code.to_string());
+        let path = "/uapi/domestic-stock/v1/quotations/inquire-elw-price";
+        let url = self.rest.url(path, &param.into_iter())?;
+        let response: ElwPriceResponse = self.rest.get(url, tr_id).await?;
+        match response.output() {
+            Some(output) if !output.elw_shrn_iscd().trim().is_empty() => Ok(response),
+            _ => Err(Error::NotAnElw(elw_code.to_string())),
+        }
+    }
+
+    /// ELW 민감도 추이(일별)
+    /// 일자별 이론가, 델타/감마/세타/베가/로우, 내재변동성. 모의투자 미지원
+    pub async fn elw_sensitivity(&self, elw_code: &str) -> Result<ElwSensitivityResponse, Error> {
+        let tr_id = self.rest.require_real(TrId::ElwSensitivity)?;
+        validate::shortcode("elw_code", elw_code)?;
+        let param = CurrentPriceParameter::new(MarketCode::Elw, elw_code.to_string());
+        let path = "/uapi/elw/v1/quotations/sensitivity-trend-daily";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// ELW 기초자산별 종목시세
+    /// 기초자산(예: 005930)에 대한 ELW 목록. 모의투자 미지원
+    pub async fn elw_underlying_search(
+        &self,
+        underlying: &str,
+    ) -> Result<ElwUnderlyingResponse, Error> {
+        let tr_id = self.rest.require_real(TrId::ElwUnderlyingPrice)?;
+        let param = ElwUnderlyingParameter::new(underlying.to_string());
+        let path = "/uapi/elw/v1/quotations/udrl-asset-price";
+        let url = self.rest.url(path, &param.into_iter())?;
+        self.rest.get(url, tr_id).await
     }
 
     /// 거래량순위[v1_국내주식-047]
+    /// 모의투자 미지원: Virtual 환경에서는 Error::UnsupportedInVirtual 반환
     pub async fn volume_rank(
         &self,
         params: VolumeRankParameter,
     ) -> Result<VolumeRankResponse, Error> {
-        let tr_id = TrId::VolumeRank;
-        let url = format!(
-            "{}/uapi/domestic-stock/v1/quotations/volume-rank",
-            "https://openapi.koreainvestment.com:9443", // no VirtualMarket support
+        let tr_id = self.rest.require_real(TrId::VolumeRank)?;
+        let url = self.rest.url(
+            "/uapi/domestic-stock/v1/quotations/volume-rank",
+            &params.into_iter(),
+        )?;
+        self.rest.get(url, tr_id).await
+    }
+
+    /// 상한가/하한가 종목
+    /// 등락률 순위(상승율순, 하락율순)에서 전일 대비 부호가 상한/하한인 종목만 반환. 모의투자 미지원
+    /// 등락률 순위는 30위까지 조회되므로 상한가/하한가 종목이 그보다 많으면 일부만 반환됨
+    pub async fn upper_lower_limit_scan(
+        &self,
+        market_code: MarketCode,
+    ) -> Result<Vec<LimitStock>, Error> {
+        let tr_id = self.rest.require_real(TrId::FluctuationRank)?;
+        if !matches!(market_code, MarketCode::Stock) {
+            return Err(Error::UnsupportedMarket(market_code));
+        }
+        let mut limits = Vec::new();
+        for sort in [FluctuationSort::Rise, FluctuationSort::Fall] {
+            let param = FluctuationRankParameter::new(market_code.clone(), sort);
+            let url = self.rest.url(
+                "/uapi/domestic-stock/v1/ranking/fluctuation",
+                &param.into_iter(),
+            )?;
+            let response: FluctuationRankResponse = self.rest.get(url, tr_id.clone()).await?;
+            limits.extend(
+                response
+                    .output()
+                    .iter()
+                    .flatten()
+                    .filter_map(LimitStock::from_rank),
+            );
+        }
+        Ok(limits)
+    }
+
+    /// 이 crate에서 아직 지원하지 않는 조회 TR을 직접 호출하는 저수준 API
+    /// path(예: "/uapi/domestic-stock/v1/quotations/inquire-price")에 params를 query parameter로 붙여
+    /// 공통 헤더(token, appkey, appsecret, tr_id, custtype)와 함께 GET 요청하고, 응답을 타입 없이 반환
+    /// 요청 수 제한, 재시도, rt_cd 확인은 다른 조회와 같음. tr_id와 params는 검증하지 않으므로 KIS 문서를 참고
+    pub async fn raw_get(
+        &self,
+        path: &str,
+        tr_id: &str,
+        params: &[(&str, &str)],
+    ) -> Result<serde_json::Value, Error> {
+        let url = self.rest.url(path, params)?;
+        self.rest.get_raw(url, tr_id, None).await
+    }
+
+    /// raw_get에 요청별 헤더를 추가하거나 덮어씀(법인 전용 헤더, tr_cont 등)
+    /// overrides는 with_headers로 설정한 헤더보다 나중에 적용
+    pub async fn raw_get_with_headers(
+        &self,
+        path: &str,
+        tr_id: &str,
+        params: &[(&str, &str)],
+        overrides: &HeaderOverrides,
+    ) -> Result<serde_json::Value, Error> {
+        let url = self.rest.url(path, params)?;
+        self.rest.get_raw(url, tr_id, Some(overrides)).await
+    }
+
+    fn check_market(&self, market_code: &MarketCode) -> Result<(), Error> {
+        if !market_code.is_supported_in(self.rest.environment()) {
+            return Err(Error::UnsupportedMarket(market_code.clone()));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{MockResponse, MockTransport};
+    use crate::types::{
+        BelongClassCode, Endpoints, PriceValue, ShareClassCode, TargetClassCode,
+        TargetExeceptClassCode,
+    };
+    use crate::MemoryCache;
+
+    fn virtual_quote() -> Quote {
+        let client = reqwest::Client::new();
+        let mut auth = auth::Auth::new(&client, Environment::Virtual, "appkey", "appsecret");
+        auth.set_token("token".to_string());
+        Quote::new(
+            &client,
+            Environment::Virtual,
+            auth,
+            Account::new("12345678", "01").unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn virtual_rejects_unsupported_market() {
+        let quote = virtual_quote();
+        for market_code in [MarketCode::Nxt, MarketCode::Unified, MarketCode::Elw] {
+            let result = quote.current_price(market_code.clone(), "005930").await;
+            assert!(
+                matches!(result, Err(Error::UnsupportedMarket(_))),
+                "{:?}",
+                market_code
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn volume_rank_virtual_errors_without_request() {
+        let mock = Arc::new(MockTransport::new());
+        let quote = virtual_quote().with_transport(mock.clone());
+        let params = VolumeRankParameter::new(
+            "0000".to_string(),
+            ShareClassCode::Whole,
+            BelongClassCode::MeanVolume,
+            TargetClassCode::default(),
+            TargetExeceptClassCode::default(),
+            None,
+            None,
+            None,
         );
-        let url = reqwest::Url::parse_with_params(&url, &params.into_iter())?;
-        Ok(self.send_request(url, tr_id).await?.json().await?)
+        let result = quote.volume_rank(params).await;
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedInVirtual(TrId::VolumeRank))
+        ));
+        assert!(mock.requests().is_empty());
     }
 
-    async fn send_request(&self, url: Url, tr_id: TrId) -> Result<Response, Error> {
-        Ok(self
-            .client
-            .get(url)
-            .header("Content-Type", "application/json")
-            .header(
-                "Authorization",
-                match self.auth.get_token() {
-                    Some(token) => format!("Bearer {}", token),
-                    None => {
-                        return Err(Error::AuthInitFailed("token"));
-                    }
-                },
+    fn daily_rows(dates: &[&str]) -> String {
+        let rows: Vec<String> = dates
+            .iter()
+            .map(|date| {
+                format!(
+                    r#"{{"stck_bsop_date":"{}","stck_oprc":"100","stck_hgpr":"110","stck_lwpr":"90","stck_clpr":"105","acml_vol":"1000","acml_tr_pbmn":"105000"}}"#,
+                    date
+                )
+            })
+            .collect();
+        format!(
+            r#"{{"rt_cd":"0","msg_cd":"MCA00000","msg1":"ok","output2":[{}]}}"#,
+            rows.join(",")
+        )
+    }
+
+    fn date(yyyymmdd: &str) -> NaiveDate {
+        parse_yyyymmdd(yyyymmdd).unwrap()
+    }
+
+    /// 기간별시세 요청의 (조회 시작일, 조회 종료일)
+    fn requested_dates(mock: &MockTransport) -> Vec<(String, String)> {
+        mock.requests()
+            .iter()
+            .map(|request| {
+                let url = reqwest::Url::parse(&request.url).unwrap();
+                let param = |name: &str| {
+                    url.query_pairs()
+                        .find(|(key, _)| key == name)
+                        .map(|(_, value)| value.to_string())
+                        .unwrap()
+                };
+                (param("FID_INPUT_DATE_1"), param("FID_INPUT_DATE_2"))
+            })
+            .collect()
+    }
+
+    async fn cached_daily(quote: &Quote, start: &str, end: &str) -> Vec<String> {
+        quote
+            .periodic_price_full(
+                MarketCode::Stock,
+                "005930",
+                DateRange::new(date(start), date(end)).unwrap(),
+                ChartPeriod::Days,
+                true,
+            )
+            .await
+            .unwrap()
+            .iter()
+            .map(|row| row.stck_bsop_date().clone())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn periodic_price_full_merges_overlapping_pages() {
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_tr_id(
+                    TrId::PeriodicPrice,
+                    MockResponse::json(daily_rows(&[
+                        "20240110", "20240109", "20240108", "20240105",
+                    ])),
+                )
+                .on_tr_id(
+                    TrId::PeriodicPrice,
+                    MockResponse::json(daily_rows(&[
+                        "20240105", "20240104", "20240103", "20240102",
+                    ])),
+                ),
+        );
+        let quote = virtual_quote().with_transport(mock.clone());
+        let merged = cached_daily(&quote, "20240102", "20240110").await;
+        assert_eq!(
+            merged,
+            ["20240102", "20240103", "20240104", "20240105", "20240108", "20240109", "20240110"]
+        );
+        // 두 번째 페이지는 첫 페이지의 가장 이른 일자 전날까지 조회
+        assert_eq!(
+            requested_dates(&mock),
+            [
+                ("20240102".to_string(), "20240110".to_string()),
+                ("20240102".to_string(), "20240104".to_string())
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn periodic_price_full_to_csv_matches_fixture() {
+        let fixture: serde_json::Value =
+            serde_json::from_str(include_str!("../../tests/fixtures/periodic_price.json")).unwrap();
+        let rows = fixture["output2"].as_array().unwrap();
+        // 두 번째 페이지는 첫 페이지의 마지막 일자(20240103)부터 시작
+        let page = |rows: &[serde_json::Value]| {
+            let mut page = fixture.clone();
+            page["output2"] = rows.into();
+            MockResponse::json(page.to_string())
+        };
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_tr_id(TrId::PeriodicPrice, page(&rows[..3]))
+                .on_tr_id(TrId::PeriodicPrice, page(&rows[2..])),
+        );
+        let quote = virtual_quote().with_transport(mock.clone());
+        let path = std::env::temp_dir().join(format!("periodic_price_{}.csv", std::process::id()));
+        let written = quote
+            .periodic_price_full_to_csv(
+                MarketCode::Stock,
+                "005930",
+                DateRange::new(date("20240102"), date("20240105")).unwrap(),
+                ChartPeriod::Days,
+                true,
+                &path,
             )
-            .header("appkey", self.auth.get_appkey())
-            .header("appsecret", self.auth.get_appsecret())
-            .header("tr_id", Into::<String>::into(tr_id))
-            .header("custtype", "P")
-            .send()
-            .await?)
+            .await
+            .unwrap();
+        let csv = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(written, 4);
+        assert_eq!(mock.requests().len(), 2);
+        assert_eq!(
+            csv,
+            include_bytes!("../../tests/fixtures/periodic_price.csv")
+        );
+    }
+
+    fn history_quote(mock: &Arc<MockTransport>) -> Quote {
+        virtual_quote()
+            .with_transport(mock.clone())
+            .with_history_cache(Arc::new(MemoryCache::new()))
+    }
+
+    #[tokio::test]
+    async fn history_cache_miss_then_full_hit() {
+        let rows = daily_rows(&["20240105", "20240104", "20240103", "20240102"]);
+        let mock =
+            Arc::new(MockTransport::new().on_tr_id(TrId::PeriodicPrice, MockResponse::json(&rows)));
+        let quote = history_quote(&mock);
+
+        let miss = cached_daily(&quote, "20240103", "20240104").await;
+        assert_eq!(miss, ["20240103", "20240104"]);
+        assert_eq!(
+            requested_dates(&mock),
+            [("20240103".to_string(), "20240104".to_string())]
+        );
+
+        let hit = cached_daily(&quote, "20240104", "20240104").await;
+        assert_eq!(hit, ["20240104"]);
+        assert_eq!(mock.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn history_cache_extends_tail() {
+        let rows = daily_rows(&["20240105", "20240104", "20240103", "20240102"]);
+        let mock =
+            Arc::new(MockTransport::new().on_tr_id(TrId::PeriodicPrice, MockResponse::json(&rows)));
+        let quote = history_quote(&mock);
+
+        cached_daily(&quote, "20240102", "20240103").await;
+        let extended = cached_daily(&quote, "20240102", "20240105").await;
+        assert_eq!(extended, ["20240102", "20240103", "20240104", "20240105"]);
+        // 저장된 마지막 일자부터 요청 종료일까지만 조회
+        assert_eq!(
+            requested_dates(&mock)[1],
+            ("20240103".to_string(), "20240105".to_string())
+        );
+
+        cached_daily(&quote, "20240102", "20240105").await;
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn history_cache_extends_head_without_dropping_rows() {
+        let rows = daily_rows(&["20240105", "20240104", "20240103", "20240102"]);
+        let mock =
+            Arc::new(MockTransport::new().on_tr_id(TrId::PeriodicPrice, MockResponse::json(&rows)));
+        let quote = history_quote(&mock);
+
+        cached_daily(&quote, "20240104", "20240105").await;
+        let extended = cached_daily(&quote, "20240102", "20240105").await;
+        assert_eq!(extended, ["20240102", "20240103", "20240104", "20240105"]);
+        // 저장된 첫 일자 전날까지만 조회
+        assert_eq!(
+            requested_dates(&mock)[1],
+            ("20240102".to_string(), "20240103".to_string())
+        );
+
+        // 앞뒤로 늘린 전체 기간이 저장되어 있음
+        let hit = cached_daily(&quote, "20240102", "20240105").await;
+        assert_eq!(hit.len(), 4);
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    fn periodic_row(date: &str, close: &str) -> PeriodicPrice {
+        serde_json::from_value(serde_json::json!({
+            "stck_bsop_date": date,
+            "stck_clpr": close,
+            "acml_vol": "1000",
+        }))
+        .unwrap()
+    }
+
+    fn candle(date: &str, close: u32) -> Candle {
+        Candle::try_from(&periodic_row(date, &close.to_string())).unwrap()
+    }
+
+    #[test]
+    fn to_candles_skips_padding_and_drops_unreadable_rows() {
+        let rows = [
+            periodic_row("20240105", "105"),
+            periodic_row("", ""),
+            periodic_row("2024-01-04", "104"),
+            periodic_row("20240103", ""),
+            periodic_row("20240102", "102"),
+        ];
+        let candles = to_candles(&rows);
+        let dates: Vec<NaiveDate> = candles.iter().map(Candle::date).collect();
+        assert_eq!(dates, [date("20240105"), date("20240102")]);
+        assert_eq!(candles[0].open(), PriceValue::from(105u32));
+    }
+
+    #[test]
+    fn normalize_candles_reverses_kis_order() {
+        let candles = normalize_candles(vec![
+            candle("20240105", 105),
+            candle("20240104", 104),
+            candle("20240102", 102),
+        ])
+        .unwrap();
+        let dates: Vec<NaiveDate> = candles.iter().map(Candle::date).collect();
+        assert_eq!(
+            dates,
+            [date("20240102"), date("20240104"), date("20240105")]
+        );
+    }
+
+    #[test]
+    fn normalize_candles_rejects_duplicate_dates() {
+        let result = normalize_candles(vec![
+            candle("20240105", 105),
+            candle("20240104", 104),
+            candle("20240105", 106),
+        ]);
+        assert!(matches!(
+            result,
+            Err(Error::BrokenProtocol("candle dates are not monotonic", date)) if date == "2024-01-05"
+        ));
+    }
+
+    #[test]
+    fn fill_gaps_with_previous_close() {
+        let candles = [candle("20240103", 103), candle("20240108", 108)];
+        let open_days = [
+            date("20240102"),
+            date("20240103"),
+            date("20240104"),
+            date("20240105"),
+            date("20240108"),
+            date("20240109"),
+        ];
+        let filled = fill_gaps_with(&candles, &open_days);
+        let rows: Vec<(NaiveDate, PriceValue, bool)> = filled
+            .iter()
+            .map(|c| (c.date(), c.close(), c.filled()))
+            .collect();
+        // 첫 봉 이전(01-02)과 마지막 봉 이후(01-09)는 채우지 않음
+        assert_eq!(
+            rows,
+            [
+                (date("20240103"), PriceValue::from(103u32), false),
+                (date("20240104"), PriceValue::from(103u32), true),
+                (date("20240105"), PriceValue::from(103u32), true),
+                (date("20240108"), PriceValue::from(108u32), false),
+            ]
+        );
+        assert_eq!(filled[1].volume(), 0);
+    }
+
+    /// (시가, 고가, 저가, 종가, 거래량, 거래대금)
+    type Ohlcv = (i64, i64, i64, i64, i64, i64);
+
+    fn ohlcv_json(ohlcv: Ohlcv) -> serde_json::Value {
+        let (open, high, low, close, volume, value) = ohlcv;
+        serde_json::json!({
+            "stck_oprc": open.to_string(),
+            "stck_hgpr": high.to_string(),
+            "stck_lwpr": low.to_string(),
+            "stck_clpr": close.to_string(),
+            "stck_prpr": close.to_string(),
+            "acml_vol": volume.to_string(),
+            "cntg_vol": volume.to_string(),
+            "acml_tr_pbmn": value.to_string(),
+        })
+    }
+
+    fn daily(yyyymmdd: &str, ohlcv: Ohlcv) -> Candle {
+        let mut row = ohlcv_json(ohlcv);
+        row["stck_bsop_date"] = yyyymmdd.into();
+        let row: PeriodicPrice = serde_json::from_value(row).unwrap();
+        Candle::try_from(&row).unwrap()
+    }
+
+    fn minute(yyyymmdd: &str, hhmmss: &str, ohlcv: Ohlcv) -> Candle {
+        let mut row = ohlcv_json(ohlcv);
+        row["stck_bsop_date"] = yyyymmdd.into();
+        row["stck_cntg_hour"] = hhmmss.into();
+        let row: MinuteCandle = serde_json::from_value(row).unwrap();
+        Candle::try_from(&row).unwrap()
+    }
+
+    fn ohlcv(candle: &Candle) -> Ohlcv {
+        let n = |v: PriceValue| v.to_string().parse::<i64>().unwrap();
+        (
+            n(candle.open()),
+            n(candle.high()),
+            n(candle.low()),
+            n(candle.close()),
+            candle.volume(),
+            n(candle.value()),
+        )
+    }
+
+    #[test]
+    fn resample_daily_to_weekly() {
+        // KIS 응답 순서(최신 일자부터)로 입력
+        let candles = [
+            daily("20240109", (100, 130, 99, 125, 15, 1500)),
+            daily("20240108", (99, 101, 97, 100, 5, 500)),
+            daily("20240105", (92, 100, 91, 99, 40, 4000)),
+            daily("20240104", (115, 118, 90, 92, 30, 3000)),
+            daily("20240103", (105, 120, 100, 115, 20, 2000)),
+            daily("20240102", (100, 110, 95, 105, 10, 1000)),
+        ];
+        let weekly = resample(&candles, Granularity::Weeks);
+        let rows: Vec<(NaiveDate, Ohlcv)> = weekly.iter().map(|c| (c.date(), ohlcv(c))).collect();
+        // 주봉의 일자는 그 주의 마지막 영업일, 거래대금은 합계
+        assert_eq!(
+            rows,
+            [
+                (date("20240105"), (100, 120, 90, 99, 100, 10000)),
+                (date("20240109"), (99, 130, 97, 125, 20, 2000)),
+            ]
+        );
+        assert!(weekly.iter().all(|c| c.time().is_none()));
+    }
+
+    #[test]
+    fn resample_minutes_to_five_minutes() {
+        let candles = [
+            minute("20240102", "090000", (100, 101, 99, 100, 1, 100)),
+            minute("20240102", "090100", (100, 103, 100, 102, 2, 300)),
+            minute("20240102", "090400", (102, 102, 98, 98, 3, 600)),
+            minute("20240102", "090500", (98, 99, 97, 99, 4, 1000)),
+            minute("20240102", "090900", (99, 105, 99, 104, 5, 1500)),
+            minute("20240103", "090200", (200, 200, 200, 200, 1, 200)),
+        ];
+        let resampled = resample(&candles, Granularity::Minutes(5));
+        let rows: Vec<(NaiveDate, Option<NaiveTime>, Ohlcv)> = resampled
+            .iter()
+            .map(|c| (c.date(), c.time(), ohlcv(c)))
+            .collect();
+        let hm = |h, m| NaiveTime::from_hms_opt(h, m, 0);
+        // 구간 시작 시각, 거래대금은 누적 값이므로 구간 마지막 봉의 값
+        assert_eq!(
+            rows,
+            [
+                (date("20240102"), hm(9, 0), (100, 103, 98, 98, 6, 600)),
+                (date("20240102"), hm(9, 5), (98, 105, 97, 104, 9, 1500)),
+                (date("20240103"), hm(9, 0), (200, 200, 200, 200, 1, 200)),
+            ]
+        );
+    }
+
+    #[test]
+    fn resample_rejects_finer_granularity() {
+        let candles = [daily("20240102", (100, 110, 95, 105, 10, 1000))];
+        assert!(resample(&candles, Granularity::Minutes(5)).is_empty());
+        let minutes = [minute("20240102", "090000", (100, 101, 99, 100, 1, 100))];
+        assert!(resample(&minutes, Granularity::Minutes(0)).is_empty());
+    }
+
+    fn real_quote(mock: &Arc<MockTransport>) -> Quote {
+        let client = reqwest::Client::new();
+        let mut auth = auth::Auth::new(&client, Environment::Real, "appkey", "appsecret");
+        auth.set_token("token".to_string());
+        Quote::new(
+            &client,
+            Environment::Real,
+            auth,
+            Account::new("12345678", "01").unwrap(),
+        )
+        .unwrap()
+        .with_transport(mock.clone())
+    }
+
+    /// 관심종목 시세 요청마다 보낸 종목코드(FID_INPUT_ISCD_n)
+    fn requested_codes(mock: &MockTransport) -> Vec<Vec<String>> {
+        mock.requests()
+            .iter()
+            .map(|request| {
+                reqwest::Url::parse(&request.url)
+                    .unwrap()
+                    .query_pairs()
+                    .filter(|(key, _)| key.starts_with("FID_INPUT_ISCD_"))
+                    .map(|(_, value)| value.to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn watchlist_prices_chunks_by_30_symbols() {
+        let empty = r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":"ok","output":[]}"#;
+        let codes: Vec<String> = (1..=61).map(|i| format!("{:06}", i)).collect();
+        for (len, chunks) in [
+            (0, vec![]),
+            (1, vec![1]),
+            (30, vec![30]),
+            (31, vec![30, 1]),
+            (61, vec![30, 30, 1]),
+        ] {
+            let mock = Arc::new(
+                MockTransport::new().on_tr_id(TrId::WatchlistPrice, MockResponse::json(empty)),
+            );
+            let chunk: Vec<&str> = codes[..len].iter().map(String::as_str).collect();
+            let result = real_quote(&mock).watchlist_prices(&chunk).await.unwrap();
+            assert!(result.is_complete());
+            let requested = requested_codes(&mock);
+            let sizes: Vec<usize> = requested.iter().map(Vec::len).collect();
+            assert_eq!(sizes, chunks, "{} symbols", len);
+            // 입력 순서대로 나누어 요청
+            assert_eq!(requested.concat(), codes[..len]);
+        }
+    }
+
+    #[tokio::test]
+    async fn watchlist_prices_keeps_successful_chunks() {
+        let page = r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":"ok","output":[
+            {"inter_shrn_iscd":"000020","inter_kor_isnm":"B","prdy_vrss_sign":"2","inter2_prpr":"200"},
+            {"inter_shrn_iscd":"000010","inter_kor_isnm":"A","prdy_vrss_sign":"5","inter2_prpr":"100"}
+        ]}"#;
+        let failure =
+            r#"{"rt_cd":"1","msg_cd":"OPSQ0002","msg1":"조회할 자료가 없습니다","output":[]}"#;
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_tr_id(TrId::WatchlistPrice, MockResponse::json(page))
+                .on_tr_id(TrId::WatchlistPrice, MockResponse::json(failure)),
+        );
+        let quote = real_quote(&mock);
+        let codes: Vec<String> = (1..=31).map(|i| format!("{:06}", i * 10)).collect();
+        let codes: Vec<&str> = codes.iter().map(String::as_str).collect();
+
+        let result = quote.watchlist_prices(&codes).await.unwrap();
+        assert_eq!(mock.requests().len(), 2);
+        assert!(!result.is_complete());
+        let prices: Vec<&str> = result
+            .prices()
+            .iter()
+            .map(|price| price.inter_shrn_iscd().as_str())
+            .collect();
+        assert_eq!(prices, ["000010", "000020"]);
+        assert_eq!(result.failures().len(), 1);
+        assert_eq!(result.failures()[0].codes(), &["000310".to_string()]);
+        assert!(matches!(result.failures()[0].error(), Error::Api { .. }));
+    }
+
+    #[tokio::test]
+    async fn daily_price_many_keeps_input_order_and_per_item_errors() {
+        let daily = |date: &str, close: &str| {
+            MockResponse::json(format!(
+                r#"{{"rt_cd":"0","msg_cd":"MCA00000","msg1":"ok","output":[
+                    {{"stck_bsop_date":"{}","stck_clpr":"{}","prdy_vrss_sign":"2","flng_cls_code":"00"}}
+                ]}}"#,
+                date, close
+            ))
+        };
+        // 앞선 종목일수록 늦게 응답해 완료 순서가 입력 순서와 반대가 되도록 함
+        let mock = Arc::new(
+            MockTransport::new()
+                .on_query(
+                    "FID_INPUT_ISCD",
+                    "005930",
+                    daily("20240105", "100").with_delay(Duration::from_millis(150)),
+                )
+                .on_query(
+                    "FID_INPUT_ISCD",
+                    "000660",
+                    MockResponse::json(
+                        r#"{"rt_cd":"1","msg_cd":"OPSQ0002","msg1":"조회할 자료가 없습니다"}"#,
+                    )
+                    .with_delay(Duration::from_millis(75)),
+                )
+                .on_query("FID_INPUT_ISCD", "035720", daily("20240104", "300")),
+        );
+        let quote = real_quote(&mock);
+        let requests = ["005930", "000660", "035720"]
+            .iter()
+            .map(|code| (MarketCode::Stock, code.to_string()))
+            .collect();
+
+        let results = quote
+            .daily_price_many(requests, DailyPeriod::Days, false)
+            .await;
+        assert_eq!(mock.requests().len(), 3);
+        assert_eq!(results.len(), 3);
+        let close = |result: &Result<DailyPriceResponse, Error>| {
+            result.as_ref().unwrap().output().as_ref().unwrap()[0].stck_clpr()
+        };
+        assert_eq!(close(&results[0]), Some(100));
+        assert!(matches!(
+            &results[1],
+            Err(Error::Api { msg_cd, .. }) if msg_cd == "OPSQ0002"
+        ));
+        assert_eq!(close(&results[2]), Some(300));
+    }
+
+    #[tokio::test]
+    async fn current_price_emits_market_division_code() {
+        let mock = Arc::new(MockTransport::new().on_tr_id(
+            TrId::CurrentPrice,
+            MockResponse::json(
+                r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":"정상처리 되었습니다."}"#,
+            ),
+        ));
+        let quote = real_quote(&mock);
+        for (market_code, code) in [
+            (MarketCode::Stock, "J"),
+            (MarketCode::Nxt, "NX"),
+            (MarketCode::Unified, "UN"),
+        ] {
+            quote.current_price(market_code, "005930").await.unwrap();
+            let request = mock.requests().pop().unwrap();
+            let url = reqwest::Url::parse(&request.url).unwrap();
+            let sent = url
+                .query_pairs()
+                .find(|(key, _)| key == "FID_COND_MRKT_DIV_CODE")
+                .map(|(_, value)| value.to_string());
+            assert_eq!(sent.as_deref(), Some(code));
+        }
+    }
+
+    /// 받은 요청마다 (요청줄, Host 헤더)를 보내고 빈 성공 응답을 돌려주는 로컬 HTTP 서버
+    fn local_server() -> (String, std::sync::mpsc::Receiver<(String, String)>) {
+        use std::io::{BufRead, BufReader, Write};
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut host = String::new();
+                loop {
+                    let mut line = String::new();
+                    if reader.read_line(&mut line).unwrap() == 0 || line == "\r\n" {
+                        break;
+                    }
+                    if let Some(value) = line.to_ascii_lowercase().strip_prefix("host:") {
+                        host = value.trim().to_string();
+                    }
+                }
+                let body = r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":"ok"}"#;
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+                let _ = sender.send((request_line.trim_end().to_string(), host));
+            }
+        });
+        (format!("http://{}", addr), receiver)
+    }
+
+    #[tokio::test]
+    async fn custom_endpoints_route_requests_to_local_server() {
+        let (rest, requests) = local_server();
+        let client = reqwest::Client::new();
+        let mut auth = auth::Auth::new(&client, Environment::Real, "appkey", "appsecret")
+            .with_endpoints(Endpoints::new(&rest, "ws://127.0.0.1:21000"));
+        auth.set_token("token".to_string());
+        let quote = Quote::new(
+            &client,
+            Environment::Real,
+            auth,
+            Account::new("12345678", "01").unwrap(),
+        )
+        .unwrap();
+        let host = rest.trim_start_matches("http://");
+
+        quote
+            .current_price(MarketCode::Stock, "005930")
+            .await
+            .unwrap();
+        let (request_line, sent_host) = requests.recv().unwrap();
+        assert!(
+            request_line.starts_with("GET /uapi/domestic-stock/v1/quotations/inquire-price?"),
+            "{}",
+            request_line
+        );
+        assert_eq!(sent_host, host);
+
+        let params = VolumeRankParameter::new(
+            "0000".to_string(),
+            ShareClassCode::Whole,
+            BelongClassCode::MeanVolume,
+            TargetClassCode::default(),
+            TargetExeceptClassCode::default(),
+            None,
+            None,
+            None,
+        );
+        quote.volume_rank(params).await.unwrap();
+        let (request_line, sent_host) = requests.recv().unwrap();
+        assert!(
+            request_line.starts_with("GET /uapi/domestic-stock/v1/quotations/volume-rank?"),
+            "{}",
+            request_line
+        );
+        assert_eq!(sent_host, host);
+    }
+
+    #[tokio::test]
+    async fn virtual_rejects_konex_listed_market() {
+        let mock = Arc::new(MockTransport::new());
+        let quote = virtual_quote().with_transport(mock.clone());
+        let result = quote
+            .current_price_listed(ListedMarket::Konex, "278990")
+            .await;
+        assert!(matches!(
+            result,
+            Err(Error::UnsupportedListedMarket(ListedMarket::Konex))
+        ));
+        assert!(mock.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn listed_market_quotes_use_stock_division() {
+        let mock = Arc::new(MockTransport::new().on_tr_id(
+            TrId::CurrentPrice,
+            MockResponse::json(
+                r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":"정상처리 되었습니다."}"#,
+            ),
+        ));
+        let quote = real_quote(&mock);
+        for listed_market in [
+            ListedMarket::Kospi,
+            ListedMarket::Kosdaq,
+            ListedMarket::Konex,
+        ] {
+            quote
+                .current_price_listed(listed_market, "278990")
+                .await
+                .unwrap();
+            let request = mock.requests().pop().unwrap();
+            assert!(
+                request.url.contains("FID_COND_MRKT_DIV_CODE=J"),
+                "{}",
+                request.url
+            );
+        }
     }
 }
@@ -0,0 +1,229 @@
+use crate::client::RestClient;
+use crate::types::request::stock::quote::{RankingKind, RankingParameter};
+use crate::types::response::stock::quote::{output::RankingItem, RankingResponse};
+use crate::types::Environment;
+use crate::{auth, Error};
+
+/// 국내주식 순위분석(등락률, 시가총액, 거래대금 순위)
+/// 모든 순위 조회는 모의투자 미지원: Virtual 환경에서는 Error::UnsupportedInVirtual 반환
+#[derive(Clone)]
+pub struct Ranking {
+    rest: RestClient,
+}
+
+impl Ranking {
+    /// 국내주식 순위분석에 관한 API
+    pub fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+    ) -> Result<Self, Error> {
+        Ok(Self {
+            rest: RestClient::new(client, environment, auth),
+        })
+    }
+
+    crate::client::rest_builders!();
+
+    /// 등락률 순위[v1_국내주식-088] 상승율순
+    pub async fn rise(&self, params: &RankingParameter) -> Result<Vec<RankingItem>, Error> {
+        self.rank(RankingKind::Rise, params).await
+    }
+
+    /// 등락률 순위[v1_국내주식-088] 하락율순
+    pub async fn fall(&self, params: &RankingParameter) -> Result<Vec<RankingItem>, Error> {
+        self.rank(RankingKind::Fall, params).await
+    }
+
+    /// 시가총액 상위[v1_국내주식-091]
+    pub async fn market_cap(&self, params: &RankingParameter) -> Result<Vec<RankingItem>, Error> {
+        self.rank(RankingKind::MarketCap, params).await
+    }
+
+    /// 거래대금 순위(거래량순위[v1_국내주식-047]를 거래금액순으로 조회)
+    pub async fn trading_value(
+        &self,
+        params: &RankingParameter,
+    ) -> Result<Vec<RankingItem>, Error> {
+        self.rank(RankingKind::TradingValue, params).await
+    }
+
+    /// kind에 맞는 TR, path, FID로 순위 조회
+    /// 응답에 순위 목록이 없으면 빈 Vec
+    pub async fn rank(
+        &self,
+        kind: RankingKind,
+        params: &RankingParameter,
+    ) -> Result<Vec<RankingItem>, Error> {
+        let tr_id = self.rest.require_real(kind.tr_id())?;
+        let url = self.rest.url(kind.path(), params.into_iter(kind))?;
+        let response: RankingResponse = self.rest.get(url, tr_id).await?;
+        Ok(response.output().clone().unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transport::{MockResponse, MockTransport};
+    use crate::types::{Price, ShareClassCode};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    const RANKING: &str =
+        r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":"정상처리 되었습니다.","output":[]}"#;
+
+    fn ranking(environment: Environment, mock: &Arc<MockTransport>) -> Ranking {
+        let client = reqwest::Client::new();
+        let mut auth = auth::Auth::new(&client, environment.clone(), "appkey", "appsecret");
+        auth.set_token("token".to_string());
+        Ranking::new(&client, environment, auth)
+            .unwrap()
+            .with_transport(mock.clone())
+    }
+
+    fn params() -> RankingParameter {
+        RankingParameter::builder()
+            .sector("0001")
+            .share_class(ShareClassCode::Common)
+            .min_price(Price::from(1000))
+            .max_price(Price::from(50000))
+            .min_volume(100000)
+            .build()
+            .unwrap()
+    }
+
+    fn query(url: &str) -> HashMap<String, String> {
+        reqwest::Url::parse(url)
+            .unwrap()
+            .query_pairs()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect()
+    }
+
+    /// kind로 한 번 조회하고 보낸 (path, tr_id, query) 반환
+    async fn sent(kind: RankingKind) -> (String, String, HashMap<String, String>) {
+        let mock =
+            Arc::new(MockTransport::new().on_tr_id(kind.tr_id(), MockResponse::json(RANKING)));
+        let items = ranking(Environment::Real, &mock)
+            .rank(kind, &params())
+            .await
+            .unwrap();
+        assert!(items.is_empty());
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 1);
+        let url = reqwest::Url::parse(&requests[0].url).unwrap();
+        (
+            url.path().to_string(),
+            requests[0].tr_id.clone(),
+            query(&requests[0].url),
+        )
+    }
+
+    #[tokio::test]
+    async fn rise_and_fall_encode_sort_order() {
+        for (kind, sort) in [(RankingKind::Rise, "0"), (RankingKind::Fall, "1")] {
+            let (path, tr_id, query) = sent(kind).await;
+            assert_eq!(path, "/uapi/domestic-stock/v1/ranking/fluctuation");
+            assert_eq!(tr_id, "FHPST01700000");
+            assert_eq!(query["FID_COND_SCR_DIV_CODE"], "20170");
+            assert_eq!(query["FID_RANK_SORT_CLS_CODE"], sort);
+            assert_eq!(query["FID_COND_MRKT_DIV_CODE"], "J");
+            assert_eq!(query["FID_INPUT_ISCD"], "0001");
+            assert_eq!(query["FID_INPUT_PRICE_1"], "1000");
+            assert_eq!(query["FID_INPUT_PRICE_2"], "50000");
+            assert_eq!(query["FID_VOL_CNT"], "100000");
+            // 등락률 순위는 분류구분을 무시하고 전체로 조회
+            assert_eq!(query["FID_DIV_CLS_CODE"], "0");
+        }
+    }
+
+    #[tokio::test]
+    async fn market_cap_encodes_share_class() {
+        let (path, tr_id, query) = sent(RankingKind::MarketCap).await;
+        assert_eq!(path, "/uapi/domestic-stock/v1/ranking/market-cap");
+        assert_eq!(tr_id, "FHPST01740000");
+        assert_eq!(query["FID_COND_SCR_DIV_CODE"], "20174");
+        assert_eq!(query["FID_DIV_CLS_CODE"], "1");
+        assert_eq!(query["FID_INPUT_ISCD"], "0001");
+        assert_eq!(query["FID_INPUT_PRICE_1"], "1000");
+        assert_eq!(query["FID_INPUT_PRICE_2"], "50000");
+        assert_eq!(query["FID_VOL_CNT"], "100000");
+        assert!(!query.contains_key("FID_RANK_SORT_CLS_CODE"));
+    }
+
+    #[tokio::test]
+    async fn trading_value_uses_volume_rank_by_amount() {
+        let (path, tr_id, query) = sent(RankingKind::TradingValue).await;
+        assert_eq!(path, "/uapi/domestic-stock/v1/quotations/volume-rank");
+        assert_eq!(tr_id, "FHPST01710000");
+        assert_eq!(query["FID_COND_SCR_DIV_CODE"], "20171");
+        assert_eq!(query["FID_BLNG_CLS_CODE"], "3");
+        assert_eq!(query["FID_DIV_CLS_CODE"], "1");
+        assert_eq!(query["FID_INPUT_ISCD"], "0001");
+        assert_eq!(query["FID_INPUT_PRICE_1"], "1000");
+        assert_eq!(query["FID_INPUT_PRICE_2"], "50000");
+        assert_eq!(query["FID_VOL_CNT"], "100000");
+    }
+
+    #[test]
+    fn unset_conditions_are_sent_empty() {
+        let params = RankingParameter::builder().build().unwrap();
+        for kind in [
+            RankingKind::Rise,
+            RankingKind::Fall,
+            RankingKind::MarketCap,
+            RankingKind::TradingValue,
+        ] {
+            let fields: HashMap<_, _> = params.into_iter(kind).into_iter().collect();
+            assert_eq!(fields["FID_INPUT_ISCD"], "0000", "{kind}");
+            assert_eq!(fields["FID_INPUT_PRICE_1"], "", "{kind}");
+            assert_eq!(fields["FID_INPUT_PRICE_2"], "", "{kind}");
+            assert_eq!(fields["FID_VOL_CNT"], "", "{kind}");
+        }
+    }
+
+    #[test]
+    fn builder_rejects_min_price_over_max_price() {
+        let result = RankingParameter::builder()
+            .min_price(Price::from(50000))
+            .max_price(Price::from(1000))
+            .build();
+        assert!(matches!(result, Err(Error::InvalidParam { .. })));
+    }
+
+    #[tokio::test]
+    async fn virtual_environment_errors_without_request() {
+        let mock = Arc::new(MockTransport::new());
+        let ranking = ranking(Environment::Virtual, &mock);
+        for kind in [
+            RankingKind::Rise,
+            RankingKind::Fall,
+            RankingKind::MarketCap,
+            RankingKind::TradingValue,
+        ] {
+            let result = ranking.rank(kind, &params()).await;
+            assert!(
+                matches!(result, Err(Error::UnsupportedInVirtual(tr_id)) if tr_id == kind.tr_id())
+            );
+        }
+        assert!(mock.requests().is_empty());
+    }
+
+    #[test]
+    fn kind_names_round_trip() {
+        for kind in [
+            RankingKind::Rise,
+            RankingKind::Fall,
+            RankingKind::MarketCap,
+            RankingKind::TradingValue,
+        ] {
+            assert_eq!(kind.to_string().parse::<RankingKind>().unwrap(), kind);
+        }
+        assert_eq!(
+            " Market_Cap ".parse::<RankingKind>().unwrap(),
+            RankingKind::MarketCap
+        );
+        assert!("volume".parse::<RankingKind>().is_err());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::client::RestClient;
+use crate::types::request::stock::quote::StockBasicInfoParameter;
+use crate::types::response::stock::quote::{StockBasicInfoResponse, StockInfo};
+use crate::types::{Environment, ListedMarket, TrId};
+use crate::{auth, validate, Cache, Error, MemoryCache};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// 주식기본조회 응답 캐시 유효 기간
+const BASIC_INFO_CACHE_TTL: Duration = Duration::from_secs(12 * 60 * 60);
+
+/// 종목명/종목코드 검색
+/// 종목명 검색은 with_master로 불러온 종목 마스터가 있어야 하며,
+/// 종목코드는 마스터에 없으면 주식기본조회 API로 조회함
+#[derive(Clone)]
+pub struct Search {
+    rest: RestClient,
+    master: Arc<HashMap<String, StockInfo>>,
+}
+
+impl Search {
+    /// 종목 검색에 관한 API
+    pub fn new(
+        client: &reqwest::Client,
+        environment: Environment,
+        auth: auth::Auth,
+    ) -> Result<Self, Error> {
+        let mut rest = RestClient::new(client, environment, auth);
+        rest.set_cache(Arc::new(MemoryCache::new()));
+        Ok(Self {
+            rest,
+            master: Arc::new(HashMap::new()),
+        })
+    }
+
+    crate::client::rest_builders!();
+
+    /// 주식기본조회 응답을 저장할 Cache(기본값은 MemoryCache, 파일에 저장하려면 FileCache)
+    pub fn with_cache(mut self, cache: Arc<dyn Cache>) -> Self {
+        self.rest.set_cache(cache);
+        self
+    }
+
+    /// 종목명 검색에 사용할 종목 마스터(parse_master 결과, 여러 시장은 합쳐서 전달)
+    pub fn with_master(mut self, master: HashMap<String, StockInfo>) -> Self {
+        self.master = Arc::new(master);
+        self
+    }
+
+    /// 종목 검색
+    /// 종목 마스터에서 종목코드가 같거나 종목명에 query가 포함된 종목을 찾고,
+    /// 찾지 못했는데 query가 6자리 종목코드이면 주식기본조회로 조회
+    pub async fn search_stock(&self, query: &str) -> Result<Vec<StockInfo>, Error> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Err(Error::InvalidParam {
+                field: "query",
+                reason: "must not be empty",
+            });
+        }
+        if let Some(info) = self.master.get(query) {
+            return Ok(vec![info.clone()]);
+        }
+        let mut found: Vec<StockInfo> = self
+            .master
+            .values()
+            .filter(|info| info.name().contains(query))
+            .cloned()
+            .collect();
+        if found.is_empty() && is_shortcode(query) {
+            let response = self.basic_info(query).await?;
+            found.extend(response.output().iter().map(StockInfo::from));
+        }
+        found.sort_by(|a, b| a.code().cmp(b.code()));
+        Ok(found)
+    }
+
+    /// 주식기본조회[v1_국내주식-067]
+    /// 종목명, 시장, 업종, 상장/거래정지 여부 등. 모의투자 미지원. 같은 종목은 12시간 동안 캐시된 응답을 반환
+    pub async fn basic_info(&self, shortcode: &str) -> Result<StockBasicInfoResponse, Error> {
+        let tr_id = self.rest.require_real(TrId::StockBasicInfo)?;
+        validate::shortcode("shortcode", shortcode)?;
+        let param = StockBasicInfoParameter::new(shortcode.to_string());
+        let url = self.rest.url(
+            "/uapi/domestic-stock/v1/quotations/search-stock-info",
+            &param.into_iter(),
+        )?;
+        self.rest.get_cached(url, tr_id, BASIC_INFO_CACHE_TTL).await
+    }
+}
+
+/// 종목 마스터 파일(kospi_code.mst, kosdaq_code.mst)을 종목코드별 StockInfo로 변환
+/// 파일은 CP949로 인코딩되어 있으므로 UTF-8로 변환한 내용을 전달해야 함
+/// 각 행은 단축코드(9), 표준코드(12), 한글명(가변), 고정 길이 부가 정보(코스피 228, 코스닥 222)로 구성됨
+pub fn parse_master(
+    contents: &str,
+    market: ListedMarket,
+) -> Result<HashMap<String, StockInfo>, Error> {
+    let tail_len = match market {
+        ListedMarket::Kospi => 228,
+        ListedMarket::Kosdaq => 222,
+        _ => {
+            return Err(Error::InvalidParam {
+                field: "market",
+                reason: "master file is only available for Kospi and Kosdaq",
+            })
+        }
+    };
+    let mut master = HashMap::new();
+    for line in contents.lines() {
+        let line = line.trim_end_matches('\r');
+        let chars = line.chars().count();
+        if chars <= tail_len + 21 {
+            continue;
+        }
+        // 부가 정보는 ASCII만 포함하므로 문자 수와 byte 수가 같음
+        let split = line.len() - tail_len;
+        let (head, tail) = match (line.get(..split), line.get(split..)) {
+            (Some(head), Some(tail)) => (head, tail),
+            _ => continue,
+        };
+        let code = head.get(..9).unwrap_or_default().trim();
+        let name = head.get(21..).unwrap_or_default().trim();
+        if code.is_empty() || name.is_empty() {
+            continue;
+        }
+        // 그룹코드(2), 시가총액규모(1) 다음 지수업종대분류코드(4)
+        let sector = tail.get(3..7).unwrap_or_default().trim();
+        master.insert(
+            code.to_string(),
+            StockInfo::new(
+                code.to_string(),
+                name.to_string(),
+                market,
+                sector.to_string(),
+                true,
+                None,
+            ),
+        );
+    }
+    Ok(master)
+}
+
+fn is_shortcode(query: &str) -> bool {
+    query.len() == 6 && query.chars().all(|c| c.is_ascii_alphanumeric())
+}
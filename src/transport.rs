@@ -0,0 +1,574 @@
+use crate::Error;
+use futures::future::BoxFuture;
+use reqwest::header::HeaderMap;
+use reqwest::{Request, StatusCode};
+
+/// HTTP 응답(상태 코드, 헤더, 본문)
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: String,
+}
+
+/// 서비스 구조체의 REST 요청을 실제로 전송하는 계층
+/// 기본값은 reqwest::Client로 전송하는 ReqwestTransport이며,
+/// 테스트에서는 서버 없이 응답을 돌려주는 구현(testing feature의 MockTransport)으로 교체할 수 있음
+pub trait Transport: Send + Sync {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<HttpResponse, Error>>;
+}
+
+/// reqwest::Client로 요청을 전송하는 기본 Transport
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: &reqwest::Client) -> Self {
+        Self {
+            client: client.clone(),
+        }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+        Box::pin(async move {
+            let response = self.client.execute(request).await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.text().await?;
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+pub use mock::{MockResponse, MockTransport, RecordedRequest, Recorder};
+
+#[cfg(any(test, feature = "testing"))]
+mod mock {
+    use super::{HttpResponse, Transport};
+    use crate::types::TrId;
+    use crate::Error;
+    use futures::future::BoxFuture;
+    use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+    use reqwest::{Request, StatusCode};
+    use serde::{Deserialize, Serialize};
+    use std::collections::{HashMap, VecDeque};
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    /// 녹화 파일에 민감 정보 대신 남기는 값
+    const REDACTED: &str = "REDACTED";
+
+    /// 녹화 파일에서 값을 가리는 query parameter, JSON key(소문자 비교)
+    const REDACTED_KEYS: [&str; 7] = [
+        "appkey",
+        "appsecret",
+        "secretkey",
+        "token",
+        "access_token",
+        "approval_key",
+        "authorization",
+    ];
+
+    /// MockTransport가 돌려줄 응답
+    #[derive(Clone, Debug)]
+    pub struct MockResponse {
+        status: StatusCode,
+        headers: HeaderMap,
+        body: String,
+        delay: Option<Duration>,
+    }
+
+    impl MockResponse {
+        /// 200 OK와 JSON 본문
+        pub fn json(body: impl Into<String>) -> Self {
+            Self {
+                status: StatusCode::OK,
+                headers: HeaderMap::new(),
+                body: body.into(),
+                delay: None,
+            }
+        }
+
+        /// 응답을 돌려주기 전 대기(동시 요청의 완료 순서 테스트용)
+        pub fn with_delay(mut self, delay: Duration) -> Self {
+            self.delay = Some(delay);
+            self
+        }
+
+        pub fn with_status(mut self, status: StatusCode) -> Self {
+            self.status = status;
+            self
+        }
+
+        /// 응답 헤더 추가(예: 연속조회 tr_cont)
+        pub fn with_header(mut self, name: &'static str, value: &str) -> Self {
+            if let Ok(value) = HeaderValue::from_str(value) {
+                self.headers.insert(HeaderName::from_static(name), value);
+            }
+            self
+        }
+    }
+
+    /// MockTransport가 받은 요청
+    #[derive(Clone, Debug)]
+    pub struct RecordedRequest {
+        pub method: String,
+        pub url: String,
+        pub tr_id: String,
+        pub headers: HeaderMap,
+        pub body: Option<String>,
+    }
+
+    /// tr_id 또는 URL path별로 준비된 응답을 돌려주는 Transport
+    /// 같은 대상에 응답을 여러 개 등록하면 차례로 돌려주고, 마지막 응답은 계속 반복함(연속조회 테스트용)
+    /// query parameter, tr_id, path 순으로 등록된 응답을 찾음
+    #[derive(Clone, Default)]
+    pub struct MockTransport {
+        by_request: Arc<Mutex<HashMap<String, VecDeque<MockResponse>>>>,
+        by_query: Arc<Mutex<HashMap<String, VecDeque<MockResponse>>>>,
+        by_tr_id: Arc<Mutex<HashMap<String, VecDeque<MockResponse>>>>,
+        by_path: Arc<Mutex<HashMap<String, VecDeque<MockResponse>>>>,
+        requests: Arc<Mutex<Vec<RecordedRequest>>>,
+    }
+
+    impl MockTransport {
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// tr_id 헤더가 일치하는 요청의 응답 등록
+        pub fn on_tr_id(self, tr_id: TrId, response: MockResponse) -> Self {
+            self.by_tr_id
+                .lock()
+                .unwrap()
+                .entry(tr_id.to_string())
+                .or_default()
+                .push_back(response);
+            self
+        }
+
+        /// query parameter(name=value)가 포함된 요청의 응답 등록(예: 종목코드별 응답)
+        pub fn on_query(self, name: &str, value: &str, response: MockResponse) -> Self {
+            self.by_query
+                .lock()
+                .unwrap()
+                .entry(format!("{}={}", name, value))
+                .or_default()
+                .push_back(response);
+            self
+        }
+
+        /// URL path가 일치하는 요청의 응답 등록
+        pub fn on_path(self, path: &str, response: MockResponse) -> Self {
+            self.by_path
+                .lock()
+                .unwrap()
+                .entry(path.to_string())
+                .or_default()
+                .push_back(response);
+            self
+        }
+
+        /// Recorder로 녹화한 디렉터리의 응답을 재생
+        /// tr_id, method, path, query parameter, 요청 본문이 모두 같은 요청에 녹화된 응답을 녹화 순서대로 돌려줌
+        /// 녹화되지 않은 요청은 on_tr_id, on_path로 등록한 응답을 사용
+        pub fn from_recording(dir: impl AsRef<Path>) -> Result<Self, Error> {
+            let mut files: Vec<PathBuf> = std::fs::read_dir(dir.as_ref())?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+                .collect();
+            files.sort();
+            let transport = Self::new();
+            {
+                let mut by_request = transport.by_request.lock().unwrap();
+                for file in files {
+                    let recording: Recording =
+                        serde_json::from_str(&std::fs::read_to_string(&file)?)?;
+                    let mut response = MockResponse::json(match &recording.response {
+                        serde_json::Value::String(body) => body.clone(),
+                        body => body.to_string(),
+                    })
+                    .with_status(StatusCode::from_u16(recording.status).unwrap_or(StatusCode::OK));
+                    if !recording.tr_cont.is_empty() {
+                        response = response.with_header("tr_cont", &recording.tr_cont);
+                    }
+                    by_request
+                        .entry(recording.key())
+                        .or_default()
+                        .push_back(response);
+                }
+            }
+            Ok(transport)
+        }
+
+        /// 지금까지 받은 요청
+        pub fn requests(&self) -> Vec<RecordedRequest> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        fn next(
+            responses: &Mutex<HashMap<String, VecDeque<MockResponse>>>,
+            key: &str,
+        ) -> Option<MockResponse> {
+            let mut responses = responses.lock().unwrap();
+            let queue = responses.get_mut(key)?;
+            if queue.len() > 1 {
+                queue.pop_front()
+            } else {
+                queue.front().cloned()
+            }
+        }
+    }
+
+    impl Transport for MockTransport {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+            let tr_id = request
+                .headers()
+                .get("tr_id")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+            let path = request.url().path().to_string();
+            self.requests.lock().unwrap().push(RecordedRequest {
+                method: request.method().to_string(),
+                url: request.url().to_string(),
+                tr_id: tr_id.clone(),
+                headers: request.headers().clone(),
+                body: request
+                    .body()
+                    .and_then(|b| b.as_bytes())
+                    .map(|b| String::from_utf8_lossy(b).to_string()),
+            });
+            let key = Recording::from_request(&request).key();
+            let response = Self::next(&self.by_request, &key)
+                .or_else(|| {
+                    request
+                        .url()
+                        .query_pairs()
+                        .find_map(|(k, v)| Self::next(&self.by_query, &format!("{}={}", k, v)))
+                })
+                .or_else(|| Self::next(&self.by_tr_id, &tr_id))
+                .or_else(|| Self::next(&self.by_path, &path));
+            Box::pin(async move {
+                let response = response.ok_or(Error::BrokenProtocol(
+                    "MockTransport",
+                    format!("no response registered for tr_id={} path={}", tr_id, path),
+                ))?;
+                if let Some(delay) = response.delay {
+                    tokio::time::sleep(delay).await;
+                }
+                Ok(HttpResponse {
+                    status: response.status,
+                    headers: response.headers,
+                    body: response.body,
+                })
+            })
+        }
+    }
+
+    /// 녹화된 요청/응답 한 건
+    #[derive(Serialize, Deserialize)]
+    struct Recording {
+        tr_id: String,
+        method: String,
+        path: String,
+        query: Vec<(String, String)>, // 민감 정보를 제외하고 key 순으로 정렬
+        body: Option<serde_json::Value>, // 요청 본문(민감 정보 제외)
+        #[serde(default)]
+        status: u16,
+        #[serde(default)]
+        tr_cont: String, // 응답 헤더의 연속 거래 여부
+        #[serde(default)]
+        response: serde_json::Value, // 응답 본문(JSON이 아니면 문자열)
+    }
+
+    impl Recording {
+        fn from_request(request: &Request) -> Self {
+            let mut query: Vec<(String, String)> = request
+                .url()
+                .query_pairs()
+                .map(|(k, v)| {
+                    let v = if is_redacted(&k) { REDACTED } else { &v };
+                    (k.to_string(), v.to_string())
+                })
+                .collect();
+            query.sort();
+            let body = request
+                .body()
+                .and_then(|b| b.as_bytes())
+                .map(|b| redact(parse_json(&String::from_utf8_lossy(b))));
+            Self {
+                tr_id: request
+                    .headers()
+                    .get("tr_id")
+                    .and_then(|v| v.to_str().ok())
+                    .unwrap_or_default()
+                    .to_string(),
+                method: request.method().to_string(),
+                path: request.url().path().to_string(),
+                query,
+                body,
+                status: 0,
+                tr_cont: String::new(),
+                response: serde_json::Value::Null,
+            }
+        }
+
+        /// 재생 시 요청을 찾는 key
+        fn key(&self) -> String {
+            let query: Vec<String> = self
+                .query
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, v))
+                .collect();
+            format!(
+                "{} {} {}?{} {}",
+                self.tr_id,
+                self.method,
+                self.path,
+                query.join("&"),
+                self.body
+                    .as_ref()
+                    .map(|b| b.to_string())
+                    .unwrap_or_default()
+            )
+        }
+    }
+
+    fn is_redacted(key: &str) -> bool {
+        REDACTED_KEYS.contains(&key.to_ascii_lowercase().as_str())
+    }
+
+    fn parse_json(s: &str) -> serde_json::Value {
+        serde_json::from_str(s).unwrap_or_else(|_| serde_json::Value::String(s.to_string()))
+    }
+
+    /// JSON object에서 민감 정보 key의 값을 REDACTED로 바꿈
+    /// key는 남겨 두므로 재생한 token 발급 응답 등도 그대로 역직렬화됨
+    fn redact(mut value: serde_json::Value) -> serde_json::Value {
+        match &mut value {
+            serde_json::Value::Object(map) => {
+                for (k, v) in map.iter_mut() {
+                    *v = if is_redacted(k) {
+                        serde_json::Value::String(REDACTED.to_string())
+                    } else {
+                        redact(v.take())
+                    };
+                }
+            }
+            serde_json::Value::Array(values) => {
+                for v in values.iter_mut() {
+                    *v = redact(v.take());
+                }
+            }
+            _ => {}
+        }
+        value
+    }
+
+    /// 요청을 inner Transport로 전송하면서 요청과 응답 원문을 dir에 JSON 파일로 녹화하는 Transport
+    /// 파일은 "{순번}-{tr_id}.json"(tr_id가 없으면 "{순번}-{path}.json")이며,
+    /// appkey, appsecret, token 등의 값은 REDACTED로 바꿈(요청 헤더는 녹화하지 않음)
+    /// 녹화한 디렉터리는 MockTransport::from_recording으로 재생
+    #[derive(Clone)]
+    pub struct Recorder {
+        inner: Arc<dyn Transport>,
+        dir: PathBuf,
+        seq: Arc<AtomicU64>,
+    }
+
+    impl Recorder {
+        /// dir이 없으면 생성
+        pub fn new(inner: Arc<dyn Transport>, dir: impl Into<PathBuf>) -> Result<Self, Error> {
+            let dir = dir.into();
+            std::fs::create_dir_all(&dir)?;
+            Ok(Self {
+                inner,
+                dir,
+                seq: Arc::new(AtomicU64::new(1)),
+            })
+        }
+
+        /// reqwest::Client로 실제 서버에 요청하면서 녹화
+        pub fn with_client(
+            client: &reqwest::Client,
+            dir: impl Into<PathBuf>,
+        ) -> Result<Self, Error> {
+            Self::new(Arc::new(super::ReqwestTransport::new(client)), dir)
+        }
+
+        fn write(&self, mut recording: Recording, response: &HttpResponse) {
+            recording.status = response.status.as_u16();
+            recording.tr_cont = response
+                .headers
+                .get("tr_cont")
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_string();
+            recording.response = redact(parse_json(&response.body));
+            let seq = self.seq.fetch_add(1, Ordering::SeqCst);
+            let name: String = if recording.tr_id.is_empty() {
+                // token, hashkey 등 tr_id가 없는 요청은 path로 구분
+                recording
+                    .path
+                    .trim_matches('/')
+                    .chars()
+                    .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+                    .collect()
+            } else {
+                recording
+                    .tr_id
+                    .chars()
+                    .filter(|c| c.is_ascii_alphanumeric())
+                    .collect()
+            };
+            let path = self.dir.join(format!("{:04}-{}.json", seq, name));
+            let result = serde_json::to_string_pretty(&recording)
+                .map_err(|e| e.to_string())
+                .and_then(|contents| std::fs::write(&path, contents).map_err(|e| e.to_string()));
+            if let Err(e) = result {
+                warn!("Failed to write recording {:?}: {}", path, e);
+            }
+        }
+    }
+
+    impl Transport for Recorder {
+        fn send(&self, request: Request) -> BoxFuture<'_, Result<HttpResponse, Error>> {
+            let recording = Recording::from_request(&request);
+            Box::pin(async move {
+                let response = self.inner.send(request).await?;
+                self.write(recording, &response);
+                Ok(response)
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    /// 테스트마다 비어 있는 녹화 디렉터리
+    fn recording_dir(name: &str) -> std::path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("kis-recording-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn quote_request(shortcode: &str) -> Request {
+        reqwest::Client::new()
+            .get("https://openapi.koreainvestment.com:9443/uapi/domestic-stock/v1/quotations/inquire-price")
+            .query(&[("FID_INPUT_ISCD", shortcode), ("appkey", "my-appkey")])
+            .header("tr_id", "FHKST01010100")
+            .header("authorization", "Bearer my-token")
+            .build()
+            .unwrap()
+    }
+
+    fn token_request() -> Request {
+        reqwest::Client::new()
+            .post("https://openapi.koreainvestment.com:9443/oauth2/tokenP")
+            .body(r#"{"grant_type":"client_credentials","appkey":"my-appkey","appsecret":"my-appsecret"}"#)
+            .build()
+            .unwrap()
+    }
+
+    const QUOTE: &str =
+        r#"{"rt_cd":"0","msg_cd":"MCA00000","msg1":"ok","output":{"stck_prpr":"72000"}}"#;
+    const TOKEN: &str = r#"{"access_token":"my-token","token_type":"Bearer","expires_in":86400}"#;
+
+    #[tokio::test]
+    async fn recording_strips_secrets_and_replays() {
+        let dir = recording_dir("replay");
+        let inner = MockTransport::new()
+            .on_tr_id(
+                crate::types::TrId::CurrentPrice,
+                MockResponse::json(QUOTE).with_header("tr_cont", "M"),
+            )
+            .on_path("/oauth2/tokenP", MockResponse::json(TOKEN));
+        let recorder = Recorder::new(Arc::new(inner), &dir).unwrap();
+        recorder.send(quote_request("005930")).await.unwrap();
+        recorder.send(token_request()).await.unwrap();
+
+        let mut files: Vec<_> = std::fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        assert_eq!(
+            files,
+            ["0001-FHKST01010100.json", "0002-oauth2-tokenP.json"]
+        );
+        for file in &files {
+            let contents = std::fs::read_to_string(dir.join(file)).unwrap();
+            for secret in ["my-appkey", "my-appsecret", "my-token"] {
+                assert!(!contents.contains(secret), "{} in {}", secret, file);
+            }
+        }
+
+        let replay = MockTransport::from_recording(&dir).unwrap();
+        let response = replay.send(quote_request("005930")).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&response.body).unwrap(),
+            serde_json::from_str::<serde_json::Value>(QUOTE).unwrap()
+        );
+        assert_eq!(response.headers["tr_cont"], "M");
+        let token: serde_json::Value =
+            serde_json::from_str(&replay.send(token_request()).await.unwrap().body).unwrap();
+        assert_eq!(token["token_type"], "Bearer");
+        assert_eq!(token["access_token"], "REDACTED");
+
+        // 녹화되지 않은 parameter의 요청은 재생하지 않음
+        let result = replay.send(quote_request("000660")).await;
+        assert!(matches!(result, Err(Error::BrokenProtocol(..))));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn recorded_auth_flow_replays() {
+        let dir = recording_dir("auth");
+        let inner = MockTransport::new()
+            .on_path("/oauth2/tokenP", MockResponse::json(TOKEN))
+            .on_path(
+                "/oauth2/Approval",
+                MockResponse::json(r#"{"approval_key":"my-approval-key"}"#),
+            );
+        let client = reqwest::Client::new();
+        let recorder = Arc::new(Recorder::new(Arc::new(inner), &dir).unwrap());
+        let auth = crate::auth::Auth::new(
+            &client,
+            crate::types::Environment::Real,
+            "my-appkey",
+            "my-appsecret",
+        )
+        .with_transport(recorder);
+        auth.create_token().await.unwrap();
+        auth.create_approval_key().await.unwrap();
+
+        let replay = Arc::new(MockTransport::from_recording(&dir).unwrap());
+        let auth = crate::auth::Auth::new(
+            &client,
+            crate::types::Environment::Real,
+            "my-appkey",
+            "my-appsecret",
+        )
+        .with_transport(replay);
+        assert_eq!(auth.create_token().await.unwrap(), "REDACTED");
+        assert_eq!(auth.create_approval_key().await.unwrap(), "REDACTED");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
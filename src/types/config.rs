@@ -1,5 +1,8 @@
 use crate::types::Environment;
-use getset::Getters;
+use crate::CircuitBreaker;
+use getset::{CopyGetters, Getters};
+use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(serde::Deserialize, Debug, Clone, Default, Getters)]
 pub struct Config {
@@ -15,4 +18,66 @@ pub struct Config {
     app_secret: String,
     #[getset(get = "pub")]
     environment: Environment,
+    #[getset(get = "pub")]
+    token_cache_path: Option<PathBuf>,
+    #[serde(default)]
+    #[getset(get = "pub")]
+    circuit_breaker: CircuitBreakerConfig,
+}
+
+/// 연속 실패 시 요청 차단 설정(`[circuit_breaker]` table)
+/// 설정하지 않은 값은 CircuitBreaker::default()와 같음(5번 연속 실패하면 30초 차단)
+#[derive(serde::Deserialize, Debug, Clone, Copy, CopyGetters)]
+#[serde(default)]
+pub struct CircuitBreakerConfig {
+    #[getset(get_copy = "pub")]
+    failure_threshold: u32, // 차단할 연속 실패 횟수
+    #[getset(get_copy = "pub")]
+    cool_down_secs: u64, // 차단 시간(초)
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            cool_down_secs: 30,
+        }
+    }
+}
+
+impl From<&CircuitBreakerConfig> for CircuitBreaker {
+    fn from(config: &CircuitBreakerConfig) -> Self {
+        CircuitBreaker::new(
+            config.failure_threshold,
+            Duration::from_secs(config.cool_down_secs),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CircuitState;
+
+    const CONFIG: &str = r#"{"hts_id":"","cano":"12345678","acnt_prdt_cd":"01","app_key":"","app_secret":"","environment":"Virtual"}"#;
+
+    #[test]
+    fn circuit_breaker_defaults_when_not_configured() {
+        let config: Config = serde_json::from_str(CONFIG).unwrap();
+        assert_eq!(config.circuit_breaker().failure_threshold(), 5);
+        assert_eq!(config.circuit_breaker().cool_down_secs(), 30);
+    }
+
+    #[test]
+    fn circuit_breaker_from_config() {
+        let json = CONFIG.replace(
+            r#""environment""#,
+            r#""circuit_breaker":{"failure_threshold":1,"cool_down_secs":60},"environment""#,
+        );
+        let config: Config = serde_json::from_str(&json).unwrap();
+        let circuit_breaker = CircuitBreaker::from(config.circuit_breaker());
+        let _probe = circuit_breaker.check().unwrap();
+        circuit_breaker.record_failure();
+        assert_eq!(circuit_breaker.state(), CircuitState::Open);
+    }
 }
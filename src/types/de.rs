@@ -0,0 +1,83 @@
+//! KIS 응답의 문자열 숫자 필드("70500", "-1.23")를 숫자로 역직렬화
+//! 빈 문자열("")은 None으로 처리
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+use serde::{Deserialize, Deserializer};
+use std::str::FromStr;
+
+fn from_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let s = String::deserialize(deserializer)?;
+    let s = s.trim();
+    if s.is_empty() {
+        return Ok(None);
+    }
+    s.parse::<T>().map(Some).map_err(serde::de::Error::custom)
+}
+
+pub(crate) fn de_f64_from_str<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    from_str(deserializer)
+}
+
+pub(crate) fn de_i64_from_str<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    from_str(deserializer)
+}
+
+#[cfg(feature = "decimal")]
+pub(crate) fn de_decimal_from_str<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    from_str(deserializer)
+}
+
+/// 숫자 len자리인지 확인. chrono는 자릿수가 모자란 값("2024011" 등)도 읽으므로 먼저 확인
+fn is_digits(s: &str, len: usize) -> bool {
+    s.len() == len && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// YYYYMMDD 형식의 날짜 문자열을 NaiveDate로 변환. 빈 문자열이나 잘못된 형식은 None
+pub(crate) fn parse_yyyymmdd(s: &str) -> Option<chrono::NaiveDate> {
+    let s = s.trim();
+    if !is_digits(s, 8) {
+        return None;
+    }
+    chrono::NaiveDate::parse_from_str(s, "%Y%m%d").ok()
+}
+
+/// HHMMSS 형식의 시각 문자열을 NaiveTime으로 변환. 빈 문자열이나 잘못된 형식은 None
+pub(crate) fn parse_hhmmss(s: &str) -> Option<chrono::NaiveTime> {
+    let s = s.trim();
+    if !is_digits(s, 6) {
+        return None;
+    }
+    chrono::NaiveTime::parse_from_str(s, "%H%M%S").ok()
+}
+
+/// 배열 또는 단일 객체로 오는 output을 Vec으로 역직렬화
+pub(crate) fn de_one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::Many(v) => v,
+        OneOrMany::One(t) => vec![t],
+    })
+}
@@ -1,15 +1,17 @@
-#[cfg(feature = "example")]
 pub mod config;
 mod crypto;
+pub(crate) mod de;
 pub mod request;
 pub mod response;
 pub mod stream;
 mod time;
 
 pub(crate) use crypto::Aes256CbcDec;
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use serde_with::SerializeDisplay;
-pub use time::Time;
+use serde_with::{DeserializeFromStr, SerializeDisplay};
+pub use time::{DateRange, KisDate, KisTime, Time};
 
 pub fn parse_bool(s: &str) -> bool {
     match s.to_ascii_uppercase().as_str() {
@@ -19,6 +21,31 @@ pub fn parse_bool(s: &str) -> bool {
     }
 }
 
+/// 코드 문자열을 enum으로 변환하지 못한 경우
+/// 코드 enum의 FromStr은 Display가 출력하는 코드 값을 받음
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("Unknown {kind} code: {value:?}")]
+pub struct ParseCodeError {
+    kind: &'static str, // 변환하려던 타입
+    value: String,      // 입력 값
+}
+impl ParseCodeError {
+    pub(crate) fn new(kind: &'static str, value: &str) -> Self {
+        Self {
+            kind,
+            value: value.to_string(),
+        }
+    }
+
+    pub fn kind(&self) -> &'static str {
+        self.kind
+    }
+
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+}
+
 /// 투자환경
 /// 실전투자: Real
 /// 모의투자: Virtual
@@ -49,16 +76,113 @@ impl std::fmt::Display for Environment {
     }
 }
 
+/// 투자환경별 접속 주소
+/// REST API, OAuth(토큰/approval key 발급), websocket이 같은 투자환경의 서버를 사용하도록 한 곳에서 관리
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Endpoints {
+    pub rest: String,      // REST API / OAuth 도메인
+    pub websocket: String, // 실시간 websocket 도메인
+}
+
+impl Endpoints {
+    /// 직접 지정한 접속 주소(로컬 mock 서버, 기록/재생 프록시 등)
+    /// 끝의 '/'는 제거함
+    pub fn new(rest: &str, websocket: &str) -> Self {
+        Self {
+            rest: rest.trim_end_matches('/').to_string(),
+            websocket: websocket.trim_end_matches('/').to_string(),
+        }
+    }
+
+    pub fn for_environment(environment: &Environment) -> Self {
+        match environment {
+            Environment::Real => Self {
+                rest: "https://openapi.koreainvestment.com:9443".to_string(),
+                websocket: "ws://ops.koreainvestment.com:21000".to_string(),
+            },
+            Environment::Virtual => Self {
+                rest: "https://openapivts.koreainvestment.com:29443".to_string(),
+                websocket: "ws://ops.koreainvestment.com:31000".to_string(),
+            },
+        }
+    }
+
+    /// 실시간 (웹소켓) 접속키 발급 주소
+    pub fn approval_url(&self) -> String {
+        format!("{}/oauth2/Approval", self.rest)
+    }
+}
+
+impl From<&Environment> for Endpoints {
+    fn from(environment: &Environment) -> Self {
+        Self::for_environment(environment)
+    }
+}
+
 /// 계좌
 /// cano: CANO(계좌번호 체계(8-2)의 앞 8자리)
 /// acnt_prdt_cd: ACNT_PRDT_CD(계좌번호 체계(8-2)의 뒤 2자리)
-#[derive(Clone)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Account {
-    pub cano: String,
-    pub acnt_prdt_cd: String,
+    cano: String,
+    acnt_prdt_cd: String,
+}
+
+impl Account {
+    /// 종합계좌번호(숫자 8자리)와 계좌상품코드(숫자 2자리)로 계좌 생성
+    pub fn new(cano: &str, acnt_prdt_cd: &str) -> Result<Self, crate::Error> {
+        let is_digits =
+            |s: &str, len: usize| s.len() == len && s.bytes().all(|b| b.is_ascii_digit());
+        if !is_digits(cano, 8) || !is_digits(acnt_prdt_cd, 2) {
+            return Err(crate::Error::InvalidAccount(format!(
+                "{}-{}",
+                cano, acnt_prdt_cd
+            )));
+        }
+        Ok(Self {
+            cano: cano.to_string(),
+            acnt_prdt_cd: acnt_prdt_cd.to_string(),
+        })
+    }
+
+    /// 종합계좌번호(CANO)
+    pub fn cano(&self) -> &str {
+        &self.cano
+    }
+
+    /// 계좌상품코드(ACNT_PRDT_CD)
+    pub fn product_code(&self) -> &str {
+        &self.acnt_prdt_cd
+    }
 }
 
+/// "12345678-01" 또는 "1234567801" 형식
+impl std::str::FromStr for Account {
+    type Err = crate::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.split_once('-') {
+            Some((cano, acnt_prdt_cd)) => Self::new(cano, acnt_prdt_cd),
+            None if s.len() == 10 && s.is_ascii() => Self::new(&s[..8], &s[8..]),
+            None => Err(crate::Error::InvalidAccount(s.to_string())),
+        }
+    }
+}
+
+impl std::fmt::Display for Account {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.cano, self.acnt_prdt_cd)
+    }
+}
+
+/// 주문구분(ORD_DVSN)
+/// 시간외 세션 주문은 별도 variant 없이 다음 코드로 보냄(MarketCalendar::is_session_hours의 시간대)
+/// - 장전 시간외 종가(08:30~08:40, 전일 종가): PreMarket(05)
+/// - 장후 시간외 종가(15:40~16:00, 당일 종가): PostMarket(06)
+/// - 시간외 단일가(16:00~18:00, 10분 단위 단일가): OutMarketSinglePrice(07)
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "String")]
 pub enum OrderClass {
     Limit,                // 지정가
     Market,               // 시장가
@@ -78,78 +202,100 @@ pub enum OrderClass {
     IOCBest,              // IOC최유리
     FOKBest,              // FOK최유리
 }
-impl Into<String> for OrderClass {
-    fn into(self) -> String {
-        match self {
-            Self::Limit => "00".to_string(),
-            Self::Market => "01".to_string(),
-            Self::ConditionalLimit => "02".to_string(),
-            Self::Best => "03".to_string(),
-            Self::First => "04".to_string(),
-            Self::PreMarket => "05".to_string(),
-            Self::PostMarket => "06".to_string(),
-            Self::OutMarketSinglePrice => "07".to_string(),
-            Self::MyStock => "08".to_string(),
-            Self::MyStockSOption => "09".to_string(),
-            Self::MyStockMoneyTrust => "10".to_string(),
-            Self::IOCLimit => "11".to_string(),
-            Self::FOKLimit => "12".to_string(),
-            Self::IOCMarket => "13".to_string(),
-            Self::FOKMarket => "14".to_string(),
-            Self::IOCBest => "15".to_string(),
-            Self::FOKBest => "16".to_string(),
-        }
+impl std::fmt::Display for OrderClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Limit => "00",
+            Self::Market => "01",
+            Self::ConditionalLimit => "02",
+            Self::Best => "03",
+            Self::First => "04",
+            Self::PreMarket => "05",
+            Self::PostMarket => "06",
+            Self::OutMarketSinglePrice => "07",
+            Self::MyStock => "08",
+            Self::MyStockSOption => "09",
+            Self::MyStockMoneyTrust => "10",
+            Self::IOCLimit => "11",
+            Self::FOKLimit => "12",
+            Self::IOCMarket => "13",
+            Self::FOKMarket => "14",
+            Self::IOCBest => "15",
+            Self::FOKBest => "16",
+        })
+    }
+}
+impl std::str::FromStr for OrderClass {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "00" => Self::Limit,
+            "01" => Self::Market,
+            "02" => Self::ConditionalLimit,
+            "03" => Self::Best,
+            "04" => Self::First,
+            "05" => Self::PreMarket,
+            "06" => Self::PostMarket,
+            "07" => Self::OutMarketSinglePrice,
+            "08" => Self::MyStock,
+            "09" => Self::MyStockSOption,
+            "10" => Self::MyStockMoneyTrust,
+            "11" => Self::IOCLimit,
+            "12" => Self::FOKLimit,
+            "13" => Self::IOCMarket,
+            "14" => Self::FOKMarket,
+            "15" => Self::IOCBest,
+            "16" => Self::FOKBest,
+            _ => return Err(ParseCodeError::new("OrderClass", s)),
+        })
+    }
+}
+impl From<OrderClass> for String {
+    fn from(value: OrderClass) -> String {
+        value.to_string()
     }
 }
 impl From<&str> for OrderClass {
     fn from(s: &str) -> Self {
-        match s {
-            "00" => OrderClass::Limit,
-            "01" => OrderClass::Market,
-            "02" => OrderClass::ConditionalLimit,
-            "03" => OrderClass::Best,
-            "04" => OrderClass::First,
-            "05" => OrderClass::PreMarket,
-            "06" => OrderClass::PostMarket,
-            "07" => OrderClass::OutMarketSinglePrice,
-            "08" => OrderClass::MyStock,
-            "09" => OrderClass::MyStockSOption,
-            "10" => OrderClass::MyStockMoneyTrust,
-            "11" => OrderClass::IOCLimit,
-            "12" => OrderClass::FOKLimit,
-            "13" => OrderClass::IOCMarket,
-            "14" => OrderClass::FOKMarket,
-            "15" => OrderClass::IOCBest,
-            "16" => OrderClass::FOKBest,
-            _ => todo!(),
-        }
+        s.parse().unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "String")]
 pub enum CorrectionClass {
     None,
     Correction,
     Cancel,
 }
-impl Into<String> for CorrectionClass {
-    fn into(self) -> String {
-        match self {
+impl std::fmt::Display for CorrectionClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
             Self::None => "0",
             Self::Correction => "01",
             Self::Cancel => "02",
-        }
-        .to_string()
+        })
+    }
+}
+impl std::str::FromStr for CorrectionClass {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "0" => Self::None,
+            "01" => Self::Correction,
+            "02" => Self::Cancel,
+            _ => return Err(ParseCodeError::new("CorrectionClass", s)),
+        })
+    }
+}
+impl From<CorrectionClass> for String {
+    fn from(value: CorrectionClass) -> String {
+        value.to_string()
     }
 }
 impl From<&str> for CorrectionClass {
     fn from(s: &str) -> CorrectionClass {
-        match s {
-            "0" => CorrectionClass::None,
-            "01" => CorrectionClass::Correction,
-            "02" => CorrectionClass::Cancel,
-            _ => todo!(),
-        }
+        s.parse().unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
@@ -159,28 +305,44 @@ pub enum Direction {
     Ask, // sell
 }
 
-impl From<&str> for Direction {
-    fn from(direction: &str) -> Self {
-        match direction {
+/// 매도매수구분코드(SLL_BUY_DVSN_CD)
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Ask => "01",
+            Self::Bid => "02",
+        })
+    }
+}
+impl std::str::FromStr for Direction {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
             "01" => Self::Ask,
             "02" => Self::Bid,
-            _ => unreachable!(),
-        }
+            _ => return Err(ParseCodeError::new("Direction", s)),
+        })
+    }
+}
+impl From<&str> for Direction {
+    fn from(direction: &str) -> Self {
+        direction.parse().unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(into = "String")]
 pub struct Quantity {
     inner: u32,
 }
-impl Quantity {
-    pub fn from(quantity: u32) -> Self {
+impl From<u32> for Quantity {
+    fn from(quantity: u32) -> Self {
         Self { inner: quantity }
     }
 }
-impl Into<String> for Quantity {
-    fn into(self) -> String {
-        format!("{}", self.inner)
+impl From<Quantity> for String {
+    fn from(value: Quantity) -> String {
+        format!("{}", value.inner)
     }
 }
 impl From<&str> for Quantity {
@@ -191,18 +353,95 @@ impl From<&str> for Quantity {
     }
 }
 
+/// 가격 값의 타입
+/// decimal feature가 있으면 Decimal, 없으면 원 단위 정수(i64)
+#[cfg(feature = "decimal")]
+pub type PriceValue = Decimal;
+#[cfg(not(feature = "decimal"))]
+pub type PriceValue = i64;
+
+/// 가격
+/// 원화 주식은 정수이지만 해외주식 등 소수점 호가가 있으므로 f64 대신 Decimal로 보관
+/// decimal feature를 사용하지 않으면 원 단위 정수로 보관
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(into = "String")]
 pub struct Price {
-    inner: u32,
+    inner: PriceValue,
+}
+impl From<u32> for Price {
+    /// 원화 가격
+    fn from(price: u32) -> Self {
+        Self {
+            inner: PriceValue::from(price),
+        }
+    }
 }
 impl Price {
-    pub fn from(price: u32) -> Self {
+    pub(crate) fn from_value(inner: PriceValue) -> Self {
+        Self { inner }
+    }
+
+    pub fn inner(&self) -> PriceValue {
+        self.inner
+    }
+
+    /// 국내 시장의 호가가격단위
+    /// 주식(코스피, 코스닥, 코넥스): 가격대별 1 ~ 1,000원, ETF/ELW: 5원
+    pub fn tick_size(&self, market: &MarketCode) -> PriceValue {
+        if let MarketCode::Etf | MarketCode::Elw = market {
+            return PriceValue::from(5u32);
+        }
+        let bands: [(u32, u32); 6] = [
+            (2_000, 1),
+            (5_000, 5),
+            (20_000, 10),
+            (50_000, 50),
+            (200_000, 100),
+            (500_000, 500),
+        ];
+        bands
+            .iter()
+            .find(|(upper, _)| self.inner < PriceValue::from(*upper))
+            .map(|(_, tick)| PriceValue::from(*tick))
+            .unwrap_or(PriceValue::from(1_000u32))
+    }
+
+    /// 가장 가까운 호가가격단위로 맞춘 가격(중간값은 올림)
+    pub fn round_to_tick(&self, market: &MarketCode) -> Self {
+        let tick = self.tick_size(market);
+        #[cfg(feature = "decimal")]
+        let snapped = (self.inner / tick)
+            .round_dp_with_strategy(0, rust_decimal::RoundingStrategy::MidpointAwayFromZero)
+            * tick;
+        #[cfg(not(feature = "decimal"))]
+        let snapped = (self.inner + tick / 2).div_euclid(tick) * tick;
+        // 올림으로 가격대가 바뀐 경우(예: 1,999.6 -> 2,000) 새 가격대의 호가단위로 다시 맞춤
+        let snapped = Self { inner: snapped };
+        let tick = snapped.tick_size(market);
+        #[cfg(feature = "decimal")]
+        let inner = (snapped.inner / tick).floor() * tick;
+        #[cfg(not(feature = "decimal"))]
+        let inner = snapped.inner.div_euclid(tick) * tick;
+        Self { inner }
+    }
+
+    /// 호가가격단위에 맞는 가격인지 확인
+    pub fn is_valid_tick(&self, market: &MarketCode) -> bool {
+        self.inner % self.tick_size(market) == PriceValue::default()
+    }
+}
+#[cfg(feature = "decimal")]
+impl From<Decimal> for Price {
+    fn from(price: Decimal) -> Self {
         Self { inner: price }
     }
 }
-impl Into<String> for Price {
-    fn into(self) -> String {
-        format!("{}", self.inner)
+impl From<Price> for String {
+    fn from(value: Price) -> String {
+        #[cfg(feature = "decimal")]
+        return format!("{}", value.inner.normalize());
+        #[cfg(not(feature = "decimal"))]
+        return format!("{}", value.inner);
     }
 }
 impl From<&str> for Price {
@@ -212,6 +451,17 @@ impl From<&str> for Price {
         }
     }
 }
+impl std::str::FromStr for Price {
+    #[cfg(feature = "decimal")]
+    type Err = rust_decimal::Error;
+    #[cfg(not(feature = "decimal"))]
+    type Err = std::num::ParseIntError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            inner: s.trim().parse()?,
+        })
+    }
+}
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq)]
 pub enum TrId {
@@ -229,13 +479,145 @@ pub enum TrId {
     RealStockCorrection,
     #[serde(rename = "VTTC0803U")]
     VirtualStockCorrection,
+    // Account
+    #[serde(rename = "TTTC8434R")]
+    RealStockBalance,
+    #[serde(rename = "VTTC8434R")]
+    VirtualStockBalance,
+    #[serde(rename = "TTTC8001R")]
+    RealStockDailyCcld,
+    #[serde(rename = "VTTC8001R")]
+    VirtualStockDailyCcld,
+    #[serde(rename = "CTSC9115R")]
+    RealStockDailyCcldBefore3Months,
+    #[serde(rename = "VTSC9115R")]
+    VirtualStockDailyCcldBefore3Months,
+    #[serde(rename = "TTTC8036R")]
+    RealStockPsblRvsecncl,
+    #[serde(rename = "TTTC8908R")]
+    RealStockPsblOrder,
+    #[serde(rename = "TTTC8909R")]
+    RealStockCreditPsblOrder,
+    #[serde(rename = "TTTC8715R")]
+    RealStockPeriodProfit,
+    #[serde(rename = "CTSC0008U")]
+    RealStockReserveOrder,
+    #[serde(rename = "CTSC0009U")]
+    RealStockReserveCancel,
+    #[serde(rename = "CTSC0004R")]
+    RealStockReserveOrderList,
+    #[serde(rename = "VTTC8908R")]
+    VirtualStockPsblOrder,
     // Quote
+    #[serde(rename = "FHKST01010100")]
+    CurrentPrice,
     #[serde(rename = "FHKST01010400")]
     DailyPrice,
     #[serde(rename = "FHKST03010100")]
     PeriodicPrice,
+    #[serde(rename = "FHKST03010200")]
+    MinuteChart,
+    #[serde(rename = "FHKST01010200")]
+    AskingPrice,
+    #[serde(rename = "FHKST01010900")]
+    InvestorTrend,
+    #[serde(rename = "FHKEW15010000")]
+    ElwPrice,
+    #[serde(rename = "FHPEW02740000")]
+    ElwSensitivity,
+    #[serde(rename = "FHKEW154101C0")]
+    ElwUnderlyingPrice,
+    #[serde(rename = "CTPF1002R")]
+    StockBasicInfo,
+    #[serde(rename = "FHPPG04600000")]
+    ProgramTradeTrend,
+    #[serde(rename = "HHKDB669102C0")]
+    DividendSchedule,
+    #[serde(rename = "FHKST11300006")]
+    WatchlistPrice,
+    #[serde(rename = "FHPST01060000")]
+    TimeConclusion,
+    #[serde(rename = "FHPST01700000")]
+    FluctuationRank,
     #[serde(rename = "FHPST01710000")]
     VolumeRank,
+    #[serde(rename = "FHPST01740000")]
+    MarketCapRank,
+    #[serde(rename = "FHPUP02100000")]
+    IndexPrice,
+    #[serde(rename = "CTCA0903R")]
+    HolidayCheck,
+    // Overseas order
+    #[serde(rename = "TTTT1002U")]
+    RealOverseasUsBidOrder,
+    #[serde(rename = "TTTT1006U")]
+    RealOverseasUsAskOrder,
+    #[serde(rename = "TTTS1002U")]
+    RealOverseasHongKongBidOrder,
+    #[serde(rename = "TTTS1001U")]
+    RealOverseasHongKongAskOrder,
+    #[serde(rename = "TTTS0202U")]
+    RealOverseasShanghaiBidOrder,
+    #[serde(rename = "TTTS1005U")]
+    RealOverseasShanghaiAskOrder,
+    #[serde(rename = "TTTS0305U")]
+    RealOverseasShenzhenBidOrder,
+    #[serde(rename = "TTTS0304U")]
+    RealOverseasShenzhenAskOrder,
+    #[serde(rename = "TTTS0308U")]
+    RealOverseasJapanBidOrder,
+    #[serde(rename = "TTTS0307U")]
+    RealOverseasJapanAskOrder,
+    #[serde(rename = "VTTT1002U")]
+    VirtualOverseasUsBidOrder,
+    #[serde(rename = "VTTT1001U")]
+    VirtualOverseasUsAskOrder,
+    #[serde(rename = "VTTS1002U")]
+    VirtualOverseasHongKongBidOrder,
+    #[serde(rename = "VTTS1001U")]
+    VirtualOverseasHongKongAskOrder,
+    #[serde(rename = "VTTS0202U")]
+    VirtualOverseasShanghaiBidOrder,
+    #[serde(rename = "VTTS1005U")]
+    VirtualOverseasShanghaiAskOrder,
+    #[serde(rename = "VTTS0305U")]
+    VirtualOverseasShenzhenBidOrder,
+    #[serde(rename = "VTTS0304U")]
+    VirtualOverseasShenzhenAskOrder,
+    #[serde(rename = "VTTS0308U")]
+    VirtualOverseasJapanBidOrder,
+    #[serde(rename = "VTTS0307U")]
+    VirtualOverseasJapanAskOrder,
+    // Overseas account
+    #[serde(rename = "TTTS3012R")]
+    RealOverseasBalance,
+    #[serde(rename = "VTTS3012R")]
+    VirtualOverseasBalance,
+    #[serde(rename = "TTTS3007R")]
+    RealOverseasBuyable,
+    #[serde(rename = "VTTS3007R")]
+    VirtualOverseasBuyable,
+    // Overseas quote
+    #[serde(rename = "HHDFS00000300")]
+    OverseasCurrentPrice,
+    #[serde(rename = "HHDFS76240000")]
+    OverseasDailyChart,
+    // Derivatives
+    #[serde(rename = "FHMIF10000000")]
+    FuturesPrice,
+    #[serde(rename = "TTTO1101U")]
+    RealFuturesOrder,
+    #[serde(rename = "VTTO1101U")]
+    VirtualFuturesOrder,
+    // Bond
+    #[serde(rename = "FHKBJ773400C0")]
+    BondPrice,
+    #[serde(rename = "CTPF1114R")]
+    BondBasicInfo,
+    #[serde(rename = "TTTC0952U")]
+    RealBondBuyOrder,
+    #[serde(rename = "TTTC0958U")]
+    RealBondSellOrder,
     // Market data
     #[serde(rename = "H0STCNT0")]
     RealtimeExec,
@@ -249,8 +631,9 @@ pub enum TrId {
     #[serde(rename = "PINGPONG")]
     PingPong,
 }
-impl Into<String> for TrId {
-    fn into(self) -> String {
+impl TrId {
+    /// tr_id 헤더 값
+    pub fn code(&self) -> &'static str {
         match self {
             // Order
             TrId::RealStockCashBidOrder => "TTTC0802U",
@@ -260,10 +643,77 @@ impl Into<String> for TrId {
             // Correction
             TrId::RealStockCorrection => "TTTC0803U",
             TrId::VirtualStockCorrection => "VTTC0803U",
+            // Account
+            TrId::RealStockBalance => "TTTC8434R",
+            TrId::VirtualStockBalance => "VTTC8434R",
+            TrId::RealStockDailyCcld => "TTTC8001R",
+            TrId::VirtualStockDailyCcld => "VTTC8001R",
+            TrId::RealStockDailyCcldBefore3Months => "CTSC9115R",
+            TrId::VirtualStockDailyCcldBefore3Months => "VTSC9115R",
+            TrId::RealStockPsblRvsecncl => "TTTC8036R",
+            TrId::RealStockPsblOrder => "TTTC8908R",
+            TrId::RealStockCreditPsblOrder => "TTTC8909R",
+            TrId::RealStockPeriodProfit => "TTTC8715R",
+            TrId::RealStockReserveOrder => "CTSC0008U",
+            TrId::RealStockReserveCancel => "CTSC0009U",
+            TrId::RealStockReserveOrderList => "CTSC0004R",
+            TrId::VirtualStockPsblOrder => "VTTC8908R",
             // Quote
+            TrId::CurrentPrice => "FHKST01010100",
             TrId::DailyPrice => "FHKST01010400",
             TrId::PeriodicPrice => "FHKST03010100",
+            TrId::MinuteChart => "FHKST03010200",
+            TrId::AskingPrice => "FHKST01010200",
+            TrId::InvestorTrend => "FHKST01010900",
+            TrId::ElwPrice => "FHKEW15010000",
+            TrId::ElwSensitivity => "FHPEW02740000",
+            TrId::ElwUnderlyingPrice => "FHKEW154101C0",
+            TrId::StockBasicInfo => "CTPF1002R",
+            TrId::ProgramTradeTrend => "FHPPG04600000",
+            TrId::DividendSchedule => "HHKDB669102C0",
+            TrId::WatchlistPrice => "FHKST11300006",
+            TrId::TimeConclusion => "FHPST01060000",
+            TrId::FluctuationRank => "FHPST01700000",
             TrId::VolumeRank => "FHPST01710000",
+            TrId::MarketCapRank => "FHPST01740000",
+            TrId::IndexPrice => "FHPUP02100000",
+            TrId::HolidayCheck => "CTCA0903R",
+            // Overseas order
+            TrId::RealOverseasUsBidOrder => "TTTT1002U",
+            TrId::RealOverseasUsAskOrder => "TTTT1006U",
+            TrId::RealOverseasHongKongBidOrder => "TTTS1002U",
+            TrId::RealOverseasHongKongAskOrder => "TTTS1001U",
+            TrId::RealOverseasShanghaiBidOrder => "TTTS0202U",
+            TrId::RealOverseasShanghaiAskOrder => "TTTS1005U",
+            TrId::RealOverseasShenzhenBidOrder => "TTTS0305U",
+            TrId::RealOverseasShenzhenAskOrder => "TTTS0304U",
+            TrId::RealOverseasJapanBidOrder => "TTTS0308U",
+            TrId::RealOverseasJapanAskOrder => "TTTS0307U",
+            TrId::VirtualOverseasUsBidOrder => "VTTT1002U",
+            TrId::VirtualOverseasUsAskOrder => "VTTT1001U",
+            TrId::VirtualOverseasHongKongBidOrder => "VTTS1002U",
+            TrId::VirtualOverseasHongKongAskOrder => "VTTS1001U",
+            TrId::VirtualOverseasShanghaiBidOrder => "VTTS0202U",
+            TrId::VirtualOverseasShanghaiAskOrder => "VTTS1005U",
+            TrId::VirtualOverseasShenzhenBidOrder => "VTTS0305U",
+            TrId::VirtualOverseasShenzhenAskOrder => "VTTS0304U",
+            TrId::VirtualOverseasJapanBidOrder => "VTTS0308U",
+            TrId::VirtualOverseasJapanAskOrder => "VTTS0307U",
+            TrId::RealOverseasBalance => "TTTS3012R",
+            TrId::VirtualOverseasBalance => "VTTS3012R",
+            TrId::RealOverseasBuyable => "TTTS3007R",
+            TrId::VirtualOverseasBuyable => "VTTS3007R",
+            // Overseas quote
+            TrId::OverseasCurrentPrice => "HHDFS00000300",
+            TrId::OverseasDailyChart => "HHDFS76240000",
+            // Derivatives
+            TrId::FuturesPrice => "FHMIF10000000",
+            TrId::RealFuturesOrder => "TTTO1101U",
+            TrId::VirtualFuturesOrder => "VTTO1101U",
+            TrId::BondPrice => "FHKBJ773400C0",
+            TrId::BondBasicInfo => "CTPF1114R",
+            TrId::RealBondBuyOrder => "TTTC0952U",
+            TrId::RealBondSellOrder => "TTTC0958U",
             // Market data
             TrId::RealtimeExec => "H0STCNT0",
             TrId::RealtimeOrdb => "H0STASP0",
@@ -272,13 +722,23 @@ impl Into<String> for TrId {
             // PingPong
             TrId::PingPong => "PINGPONG",
         }
-        .to_string()
+    }
+}
+impl std::fmt::Display for TrId {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+impl From<TrId> for String {
+    fn from(value: TrId) -> String {
+        value.code().to_string()
     }
 }
 
-impl From<&str> for TrId {
-    fn from(s: &str) -> Self {
-        match s {
+impl std::str::FromStr for TrId {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
             // Order
             "TTTC0802U" => TrId::RealStockCashBidOrder,
             "TTTC0801U" => TrId::RealStockCashAskOrder,
@@ -287,9 +747,77 @@ impl From<&str> for TrId {
             // Correction
             "TTTC0803U" => TrId::RealStockCorrection,
             "VTTC0803U" => TrId::VirtualStockCorrection,
+            // Account
+            "TTTC8434R" => TrId::RealStockBalance,
+            "VTTC8434R" => TrId::VirtualStockBalance,
+            "TTTC8001R" => TrId::RealStockDailyCcld,
+            "VTTC8001R" => TrId::VirtualStockDailyCcld,
+            "CTSC9115R" => TrId::RealStockDailyCcldBefore3Months,
+            "VTSC9115R" => TrId::VirtualStockDailyCcldBefore3Months,
+            "TTTC8036R" => TrId::RealStockPsblRvsecncl,
+            "TTTC8908R" => TrId::RealStockPsblOrder,
+            "TTTC8909R" => TrId::RealStockCreditPsblOrder,
+            "TTTC8715R" => TrId::RealStockPeriodProfit,
+            "CTSC0008U" => TrId::RealStockReserveOrder,
+            "CTSC0009U" => TrId::RealStockReserveCancel,
+            "CTSC0004R" => TrId::RealStockReserveOrderList,
+            "VTTC8908R" => TrId::VirtualStockPsblOrder,
             // Quote
+            "FHKST01010100" => TrId::CurrentPrice,
             "FHKST01010400" => TrId::DailyPrice,
+            "FHKST03010100" => TrId::PeriodicPrice,
+            "FHKST03010200" => TrId::MinuteChart,
+            "FHKST01010200" => TrId::AskingPrice,
+            "FHKST01010900" => TrId::InvestorTrend,
+            "FHKEW15010000" => TrId::ElwPrice,
+            "FHPEW02740000" => TrId::ElwSensitivity,
+            "FHKEW154101C0" => TrId::ElwUnderlyingPrice,
+            "CTPF1002R" => TrId::StockBasicInfo,
+            "FHPPG04600000" => TrId::ProgramTradeTrend,
+            "HHKDB669102C0" => TrId::DividendSchedule,
+            "FHKST11300006" => TrId::WatchlistPrice,
+            "FHPST01060000" => TrId::TimeConclusion,
+            "FHPST01700000" => TrId::FluctuationRank,
             "FHPST01710000" => TrId::VolumeRank,
+            "FHPST01740000" => TrId::MarketCapRank,
+            "FHPUP02100000" => TrId::IndexPrice,
+            "CTCA0903R" => TrId::HolidayCheck,
+            // Overseas order
+            "TTTT1002U" => TrId::RealOverseasUsBidOrder,
+            "TTTT1006U" => TrId::RealOverseasUsAskOrder,
+            "TTTS1002U" => TrId::RealOverseasHongKongBidOrder,
+            "TTTS1001U" => TrId::RealOverseasHongKongAskOrder,
+            "TTTS0202U" => TrId::RealOverseasShanghaiBidOrder,
+            "TTTS1005U" => TrId::RealOverseasShanghaiAskOrder,
+            "TTTS0305U" => TrId::RealOverseasShenzhenBidOrder,
+            "TTTS0304U" => TrId::RealOverseasShenzhenAskOrder,
+            "TTTS0308U" => TrId::RealOverseasJapanBidOrder,
+            "TTTS0307U" => TrId::RealOverseasJapanAskOrder,
+            "VTTT1002U" => TrId::VirtualOverseasUsBidOrder,
+            "VTTT1001U" => TrId::VirtualOverseasUsAskOrder,
+            "VTTS1002U" => TrId::VirtualOverseasHongKongBidOrder,
+            "VTTS1001U" => TrId::VirtualOverseasHongKongAskOrder,
+            "VTTS0202U" => TrId::VirtualOverseasShanghaiBidOrder,
+            "VTTS1005U" => TrId::VirtualOverseasShanghaiAskOrder,
+            "VTTS0305U" => TrId::VirtualOverseasShenzhenBidOrder,
+            "VTTS0304U" => TrId::VirtualOverseasShenzhenAskOrder,
+            "VTTS0308U" => TrId::VirtualOverseasJapanBidOrder,
+            "VTTS0307U" => TrId::VirtualOverseasJapanAskOrder,
+            "TTTS3012R" => TrId::RealOverseasBalance,
+            "VTTS3012R" => TrId::VirtualOverseasBalance,
+            "TTTS3007R" => TrId::RealOverseasBuyable,
+            "VTTS3007R" => TrId::VirtualOverseasBuyable,
+            // Overseas quote
+            "HHDFS00000300" => TrId::OverseasCurrentPrice,
+            "HHDFS76240000" => TrId::OverseasDailyChart,
+            // Derivatives
+            "FHMIF10000000" => TrId::FuturesPrice,
+            "TTTO1101U" => TrId::RealFuturesOrder,
+            "VTTO1101U" => TrId::VirtualFuturesOrder,
+            "FHKBJ773400C0" => TrId::BondPrice,
+            "CTPF1114R" => TrId::BondBasicInfo,
+            "TTTC0952U" => TrId::RealBondBuyOrder,
+            "TTTC0958U" => TrId::RealBondSellOrder,
             // Market data
             "H0STCNT0" => TrId::RealtimeExec,
             "H0STASP0" => TrId::RealtimeOrdb,
@@ -297,25 +825,72 @@ impl From<&str> for TrId {
             "H0STCNI9" => TrId::VirtualRealtimeMyExec,
             // PingPong
             "PINGPONG" => TrId::PingPong,
-            _ => todo!(),
-        }
+            _ => return Err(ParseCodeError::new("TrId", s)),
+        })
+    }
+}
+impl From<&str> for TrId {
+    fn from(s: &str) -> Self {
+        s.parse().unwrap_or_else(|e| panic!("{}", e))
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// 고객타입(custtype 헤더)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub enum CustomerType {
     #[serde(rename = "B")]
-    Business,
+    Business, // 법인
     #[serde(rename = "P")]
-    Personal,
+    #[default]
+    Personal, // 개인
 }
-impl Into<String> for CustomerType {
-    fn into(self) -> String {
-        match self {
-            CustomerType::Business => "B",
-            CustomerType::Personal => "P",
-        }
-        .to_string()
+impl std::fmt::Display for CustomerType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Business => "B",
+            Self::Personal => "P",
+        })
+    }
+}
+impl std::str::FromStr for CustomerType {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().to_ascii_uppercase().as_str() {
+            "B" => Self::Business,
+            "P" => Self::Personal,
+            _ => return Err(ParseCodeError::new("CustomerType", s)),
+        })
+    }
+}
+impl From<CustomerType> for String {
+    fn from(value: CustomerType) -> String {
+        value.to_string()
+    }
+}
+
+/// 법인 고객 추가 헤더
+/// 값이 있는 항목만 요청 헤더에 포함됨
+#[derive(Debug, Clone, Default)]
+pub struct CorporateHeaders {
+    pub personalseckey: Option<String>, // 고객식별키(법인 필수)
+    pub seq_no: Option<String>,         // 일련번호(법인 01, 주문은 Auth가 발급하는 번호로 대체)
+    pub mac_address: Option<String>,    // 맥주소
+    pub phone_number: Option<String>,   // 핸드폰번호(법인 필수, 하이픈 제외)
+    pub ip_addr: Option<String>,        // 접속 단말 공인 IP(법인 필수)
+    pub gt_uid: bool,                   // 요청마다 거래고유번호(gt_uid) 생성하여 전송(법인)
+}
+
+impl CorporateHeaders {
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (&'static str, &str)> {
+        [
+            ("personalseckey", &self.personalseckey),
+            ("seq_no", &self.seq_no),
+            ("mac_address", &self.mac_address),
+            ("phone_number", &self.phone_number),
+            ("ip_addr", &self.ip_addr),
+        ]
+        .into_iter()
+        .filter_map(|(name, value)| value.as_deref().map(|v| (name, v)))
     }
 }
 
@@ -339,33 +914,53 @@ impl From<&str> for ExecClass {
 }
 
 /// 대비구분
-#[derive(Clone, Debug, Deserialize)]
+/// 전일 대비 부호 등 가격 대비 구분
+/// 정의되지 않은 코드는 Unknown으로 역직렬화됨
+#[derive(Clone, Copy, Debug, PartialEq, Eq, DeserializeFromStr)]
 pub enum VsPriceSign {
-    #[serde(rename = "1")]
     UpperLimit, // 상한(1)
-    #[serde(rename = "2")]
-    Increase, // 상승(2)
-    #[serde(rename = "3")]
-    Steady, // 보합(3)
-    #[serde(rename = "4")]
-    Decrease, // 하락(4)
-    #[serde(rename = "5")]
-    LowerLimit, // 하한(5)
+    Increase,   // 상승(2)
+    Steady,     // 보합(3)
+    LowerLimit, // 하한(4)
+    Decrease,   // 하락(5)
+    Unknown,    // 그 외
+}
+
+/// VsPriceSign의 다른 이름
+pub type PriceSign = VsPriceSign;
+
+impl VsPriceSign {
+    /// 상한/상승은 1, 보합/Unknown은 0, 하락/하한은 -1
+    pub fn as_multiplier(&self) -> i8 {
+        match self {
+            Self::UpperLimit | Self::Increase => 1,
+            Self::Steady | Self::Unknown => 0,
+            Self::Decrease | Self::LowerLimit => -1,
+        }
+    }
 }
 
 impl From<&str> for VsPriceSign {
     fn from(s: &str) -> Self {
-        match s {
+        match s.trim() {
             "1" => Self::UpperLimit,
             "2" => Self::Increase,
             "3" => Self::Steady,
-            "4" => Self::Decrease,
-            "5" => Self::LowerLimit,
-            _ => unreachable!(),
+            "4" => Self::LowerLimit,
+            "5" => Self::Decrease,
+            _ => Self::Unknown,
         }
     }
 }
 
+impl std::str::FromStr for VsPriceSign {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from(s))
+    }
+}
+
 /// 시간 구분 코드
 #[derive(Clone, Debug)]
 pub enum TimeClassCode {
@@ -445,7 +1040,7 @@ impl From<&str> for MarketOperationClassCode {
             "2" => What::Massive,
             "3" => What::Basket,
             "7" => What::Clearance,
-            "7" => What::BuyIn,
+            "8" => What::BuyIn,
             _ => unreachable!(),
         };
         Self(first, second)
@@ -474,41 +1069,339 @@ pub enum What {
     BuyIn,     // Buy-in(8)
 }
 
+/// FID 조건 시장 분류 코드
+/// 코스닥, 코넥스 종목은 Stock(J)으로 조회(코넥스 모의투자 지원 여부는 ListedMarket::is_supported_in으로 확인)
 #[derive(Clone, Debug, Deserialize, SerializeDisplay)]
 pub enum MarketCode {
     #[serde(rename = "J")]
-    Stock,
+    Stock, // 주식(코스피, 코스닥)
     #[serde(rename = "ETF")]
-    Etf,
+    Etf, // ETF
+    #[serde(rename = "NX")]
+    Nxt, // 넥스트레이드(NXT, 모의투자 미지원)
+    #[serde(rename = "UN")]
+    Unified, // 통합(KRX + NXT, 모의투자 미지원)
+    #[serde(rename = "W")]
+    Elw, // ELW(주식워런트증권, 모의투자 미지원)
+}
+impl MarketCode {
+    /// 요청에 보낼 시장 분류
+    pub fn division(&self) -> MarketDivision {
+        match self {
+            Self::Stock => MarketDivision::Krx,
+            Self::Etf => MarketDivision::Etf,
+            Self::Nxt => MarketDivision::Nxt,
+            Self::Unified => MarketDivision::Unified,
+            Self::Elw => MarketDivision::Elw,
+        }
+    }
+
+    /// 해당 투자환경에서 지원하는 시장인지 확인
+    pub fn is_supported_in(&self, environment: &Environment) -> bool {
+        !matches!(
+            (environment, self),
+            (Environment::Virtual, Self::Nxt | Self::Unified | Self::Elw)
+        )
+    }
 }
 impl std::fmt::Display for MarketCode {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        f.write_str(match self {
-            Self::Stock => "J",
+        self.division().fmt(f)
+    }
+}
+impl std::str::FromStr for MarketCode {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let division = s
+            .parse::<MarketDivision>()
+            .map_err(|_| ParseCodeError::new("MarketCode", s))?;
+        Ok(match division {
+            MarketDivision::Krx => Self::Stock,
+            MarketDivision::Etf => Self::Etf,
+            MarketDivision::Nxt => Self::Nxt,
+            MarketDivision::Unified => Self::Unified,
+            MarketDivision::Elw => Self::Elw,
+            _ => return Err(ParseCodeError::new("MarketCode", s)),
+        })
+    }
+}
+
+/// 요청에 실제로 보내는 FID 조건 시장 분류 코드 값
+/// MarketCode는 division()으로 변환하여 보내며, ELW/업종처럼 시장이 정해진 TR은 해당 값을 직접 사용
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MarketDivision {
+    Krx,     // 주식(KRX, J)
+    Nxt,     // 주식(넥스트레이드, NX)
+    Unified, // 통합(KRX + NXT, UN)
+    Etf,     // ETF(ETF)
+    Etn,     // ETN(ETN)
+    Elw,     // ELW(W)
+    Sector,  // 업종 지수(U)
+}
+impl MarketDivision {
+    /// FID_COND_MRKT_DIV_CODE 값
+    pub fn fid_code(&self) -> &'static str {
+        match self {
+            Self::Krx => "J",
+            Self::Nxt => "NX",
+            Self::Unified => "UN",
             Self::Etf => "ETF",
+            Self::Etn => "ETN",
+            Self::Elw => "W",
+            Self::Sector => "U",
+        }
+    }
+}
+impl std::fmt::Display for MarketDivision {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.fid_code())
+    }
+}
+impl std::str::FromStr for MarketDivision {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().to_ascii_uppercase().as_str() {
+            "J" => Self::Krx,
+            "NX" => Self::Nxt,
+            "UN" => Self::Unified,
+            "ETF" => Self::Etf,
+            "ETN" => Self::Etn,
+            "W" => Self::Elw,
+            "U" => Self::Sector,
+            _ => return Err(ParseCodeError::new("MarketDivision", s)),
         })
     }
 }
+impl From<&MarketCode> for MarketDivision {
+    fn from(market_code: &MarketCode) -> Self {
+        market_code.division()
+    }
+}
 
+/// 국내주식 주문을 보낼 거래소(거래소ID구분코드, EXCG_ID_DVSN_CD)
+/// 모의투자는 KRX만 지원
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, Hash, SerializeDisplay, DeserializeFromStr,
+)]
+pub enum Exchange {
+    #[default]
+    Krx, // 한국거래소(KRX)
+    Nxt, // 넥스트레이드(NXT)
+    Sor, // 최선집행(SOR, KRX와 NXT 중 유리한 거래소로 자동 주문)
+}
+impl std::fmt::Display for Exchange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Krx => "KRX",
+            Self::Nxt => "NXT",
+            Self::Sor => "SOR",
+        })
+    }
+}
+impl std::str::FromStr for Exchange {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().to_ascii_uppercase().as_str() {
+            "KRX" => Self::Krx,
+            "NXT" => Self::Nxt,
+            "SOR" => Self::Sor,
+            _ => return Err(ParseCodeError::new("Exchange", s)),
+        })
+    }
+}
+
+/// 상장 시장(시장ID코드)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ListedMarket {
+    Kospi,  // 유가증권(STK)
+    Kosdaq, // 코스닥(KSQ)
+    Konex,  // 코넥스(KNX)
+    Other,  // 그 외(ETF, ELW 등)
+}
+impl From<&str> for ListedMarket {
+    fn from(s: &str) -> Self {
+        match s {
+            "STK" => Self::Kospi,
+            "KSQ" => Self::Kosdaq,
+            "KNX" => Self::Konex,
+            _ => Self::Other,
+        }
+    }
+}
+impl ListedMarket {
+    /// 해당 투자환경에서 시세를 조회할 수 있는 상장 시장인지 확인(코넥스는 모의투자 미지원)
+    pub fn is_supported_in(&self, environment: &Environment) -> bool {
+        !matches!((environment, self), (Environment::Virtual, Self::Konex))
+    }
+}
+
+/// 주식현재가 일자별(daily_price) 기간 분류 코드(FID_PERIOD_DIV_CODE)
+/// 최근 30일/30주/30개월을 조회하며 년 단위는 지원하지 않음
+/// ```no_run
+/// # use korea_investment_api_bohyuk::{stock::quote::Quote, types::{DailyPeriod, MarketCode}, Error};
+/// # async fn run(quote: &Quote) -> Result<(), Error> {
+/// quote.daily_price(MarketCode::Stock, "005930", DailyPeriod::Days, false).await?;
+/// quote.daily_price(MarketCode::Stock, "005930", DailyPeriod::Weeks, false).await?;
+/// quote.daily_price(MarketCode::Stock, "005930", DailyPeriod::Months, false).await?;
+/// # Ok(())
+/// # }
+/// ```
+/// 년 단위 기간은 타입에 존재하지 않으므로 컴파일되지 않음
+/// ```compile_fail
+/// # use korea_investment_api_bohyuk::{stock::quote::Quote, types::{DailyPeriod, MarketCode}, Error};
+/// # async fn run(quote: &Quote) -> Result<(), Error> {
+/// quote.daily_price(MarketCode::Stock, "005930", DailyPeriod::Years, false).await?;
+/// # Ok(())
+/// # }
+/// ```
+/// 기간별시세용 ChartPeriod도 그대로 넘길 수 없음
+/// ```compile_fail
+/// # use korea_investment_api_bohyuk::{stock::quote::Quote, types::{ChartPeriod, MarketCode}, Error};
+/// # async fn run(quote: &Quote) -> Result<(), Error> {
+/// quote.daily_price(MarketCode::Stock, "005930", ChartPeriod::Years, false).await?;
+/// # Ok(())
+/// # }
+/// ```
+/// ChartPeriod로 받은 값은 TryFrom으로 변환하며, Years는 Error::UnsupportedPeriod
+/// ```
+/// use korea_investment_api_bohyuk::{types::{ChartPeriod, DailyPeriod}, Error};
+/// assert!(DailyPeriod::try_from(ChartPeriod::Weeks).is_ok());
+/// assert!(matches!(
+///     DailyPeriod::try_from(ChartPeriod::Years),
+///     Err(Error::UnsupportedPeriod(_, ChartPeriod::Years))
+/// ));
+/// ```
+#[derive(Clone, Debug, Deserialize, SerializeDisplay)]
+pub enum DailyPeriod {
+    #[serde(rename = "D")]
+    Days,
+    #[serde(rename = "W")]
+    Weeks,
+    #[serde(rename = "M")]
+    Months,
+}
+impl std::fmt::Display for DailyPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Days => "D",
+            Self::Weeks => "W",
+            Self::Months => "M",
+        })
+    }
+}
+impl std::str::FromStr for DailyPeriod {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().to_ascii_uppercase().as_str() {
+            "D" => Self::Days,
+            "W" => Self::Weeks,
+            "M" => Self::Months,
+            _ => return Err(ParseCodeError::new("DailyPeriod", s)),
+        })
+    }
+}
+impl From<DailyPeriod> for ChartPeriod {
+    fn from(period: DailyPeriod) -> Self {
+        match period {
+            DailyPeriod::Days => Self::Days,
+            DailyPeriod::Weeks => Self::Weeks,
+            DailyPeriod::Months => Self::Months,
+        }
+    }
+}
+impl TryFrom<ChartPeriod> for DailyPeriod {
+    type Error = crate::Error;
+    fn try_from(period: ChartPeriod) -> Result<Self, Self::Error> {
+        match period {
+            ChartPeriod::Days => Ok(Self::Days),
+            ChartPeriod::Weeks => Ok(Self::Weeks),
+            ChartPeriod::Months => Ok(Self::Months),
+            ChartPeriod::Years => Err(crate::Error::UnsupportedPeriod(
+                TrId::DailyPrice,
+                ChartPeriod::Years,
+            )),
+        }
+    }
+}
+
+/// 기간별시세(periodic_price 등) 기간 분류 코드(FID_PERIOD_DIV_CODE)
+/// 조회 TR마다 지원하는 기간과 의미가 다름
+/// | TR | D | W | M | Y |
+/// |---|---|---|---|---|
+/// | 주식현재가 일자별(daily_price, DailyPeriod) | 최근 30일 | 최근 30주 | 최근 30개월 | 미지원 |
+/// | 기간별시세(periodic_price 등) | 일봉 | 주봉 | 월봉 | 년봉 |
+/// ```no_run
+/// # use korea_investment_api_bohyuk::{stock::quote::Quote, types::{ChartPeriod, MarketCode}, Error};
+/// # async fn run(quote: &Quote) -> Result<(), Error> {
+/// for period in [ChartPeriod::Days, ChartPeriod::Weeks, ChartPeriod::Months, ChartPeriod::Years] {
+///     quote
+///         .periodic_price(MarketCode::Stock, "005930", period, "20240101", "20241231", false)
+///         .await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Clone, Debug, Deserialize, SerializeDisplay)]
-pub enum PeriodCode {
+pub enum ChartPeriod {
     #[serde(rename = "D")]
     Days,
     #[serde(rename = "W")]
     Weeks,
     #[serde(rename = "M")]
     Months,
+    #[serde(rename = "Y")]
+    Years,
 }
-impl std::fmt::Display for PeriodCode {
+impl ChartPeriod {
+    /// 해당 조회 TR에서 지원하는 기간인지 확인
+    pub fn is_supported_by(&self, tr_id: &TrId) -> bool {
+        !matches!((tr_id, self), (TrId::DailyPrice, Self::Years))
+    }
+}
+impl std::fmt::Display for ChartPeriod {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         f.write_str(match self {
             Self::Days => "D",
             Self::Weeks => "W",
             Self::Months => "M",
+            Self::Years => "Y",
+        })
+    }
+}
+impl std::str::FromStr for ChartPeriod {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().to_ascii_uppercase().as_str() {
+            "D" => Self::Days,
+            "W" => Self::Weeks,
+            "M" => Self::Months,
+            "Y" => Self::Years,
+            _ => return Err(ParseCodeError::new("ChartPeriod", s)),
         })
     }
 }
 
+/// 봉 재집계(resample) 단위
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Granularity {
+    Minutes(u32), // n분봉(장 시작 09:00 기준 구간)
+    Days,         // 일봉
+    Weeks,        // 주봉(월요일 시작)
+    Months,       // 월봉
+    Years,        // 년봉
+}
+
+impl From<ChartPeriod> for Granularity {
+    fn from(period: ChartPeriod) -> Self {
+        match period {
+            ChartPeriod::Days => Self::Days,
+            ChartPeriod::Weeks => Self::Weeks,
+            ChartPeriod::Months => Self::Months,
+            ChartPeriod::Years => Self::Years,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, SerializeDisplay)]
 pub enum ExCode {
     #[serde(rename = "00")]
@@ -542,6 +1435,22 @@ impl std::fmt::Display for ExCode {
         })
     }
 }
+impl std::str::FromStr for ExCode {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "00" => Self::None,
+            "01" => Self::ExRights,
+            "02" => Self::ExDividend,
+            "03" => Self::ExEtfDividend,
+            "04" => Self::ExRightsAndDividend,
+            "05" => Self::MidOrQtrExDividend,
+            "06" => Self::MidExRightsAndDividend,
+            "07" => Self::QtrExRightsAndDividend,
+            _ => return Err(ParseCodeError::new("ExCode", s)),
+        })
+    }
+}
 
 #[derive(Clone, Debug, Deserialize, SerializeDisplay)]
 pub enum BelongClassCode {
@@ -568,6 +1477,19 @@ impl std::fmt::Display for BelongClassCode {
         })
     }
 }
+impl std::str::FromStr for BelongClassCode {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "0" => Self::MeanVolume,
+            "1" => Self::IncreasedVolumeRate,
+            "2" => Self::MeanRotationRate,
+            "3" => Self::Amount,
+            "4" => Self::AmountRotationRate,
+            _ => return Err(ParseCodeError::new("BelongClassCode", s)),
+        })
+    }
+}
 
 #[derive(Debug, Clone, Copy, SerializeDisplay)]
 pub struct TargetClassCode {
@@ -582,6 +1504,23 @@ pub struct TargetClassCode {
     pub credit_60: bool,
 }
 
+impl Default for TargetClassCode {
+    /// 모든 증거금/신용보증금 대상 포함(111111111)
+    fn default() -> Self {
+        Self {
+            margin_30: true,
+            margin_40: true,
+            margin_50: true,
+            margin_60: true,
+            margin_100: true,
+            credit_30: true,
+            credit_40: true,
+            credit_50: true,
+            credit_60: true,
+        }
+    }
+}
+
 impl std::fmt::Display for TargetClassCode {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         f.write_fmt(format_args!(
@@ -599,7 +1538,8 @@ impl std::fmt::Display for TargetClassCode {
     }
 }
 
-#[derive(Debug, Clone, Copy, SerializeDisplay)]
+/// 기본값은 제외 대상 없음(000000)
+#[derive(Debug, Clone, Copy, Default, SerializeDisplay)]
 pub struct TargetExeceptClassCode {
     pub overheat: bool,             // 투자위험/경고/주의
     pub administrated: bool,        // 관리종목
@@ -642,3 +1582,783 @@ impl std::fmt::Display for ShareClassCode {
         })
     }
 }
+impl std::str::FromStr for ShareClassCode {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "0" => Self::Whole,
+            "1" => Self::Common,
+            "2" => Self::Preferred,
+            _ => return Err(ParseCodeError::new("ShareClassCode", s)),
+        })
+    }
+}
+
+/// 업종(지수) 코드
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, SerializeDisplay)]
+pub enum IndexCode {
+    #[serde(rename = "0001")]
+    Kospi, // 코스피(종합)
+    #[serde(rename = "0002")]
+    KospiLarge, // 코스피 대형주
+    #[serde(rename = "0003")]
+    KospiMedium, // 코스피 중형주
+    #[serde(rename = "0004")]
+    KospiSmall, // 코스피 소형주
+    #[serde(rename = "1001")]
+    Kosdaq, // 코스닥(종합)
+    #[serde(rename = "2001")]
+    Kospi200, // 코스피200
+}
+impl std::fmt::Display for IndexCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Kospi => "0001",
+            Self::KospiLarge => "0002",
+            Self::KospiMedium => "0003",
+            Self::KospiSmall => "0004",
+            Self::Kosdaq => "1001",
+            Self::Kospi200 => "2001",
+        })
+    }
+}
+impl std::str::FromStr for IndexCode {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "0001" => Self::Kospi,
+            "0002" => Self::KospiLarge,
+            "0003" => Self::KospiMedium,
+            "0004" => Self::KospiSmall,
+            "1001" => Self::Kosdaq,
+            "2001" => Self::Kospi200,
+            _ => return Err(ParseCodeError::new("IndexCode", s)),
+        })
+    }
+}
+
+/// 코스피 업종 코드(지수업종대분류)
+/// sector_index, sector_constituents에 code()를 전달
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SectorCode {
+    FoodBeverage,         // 음식료품(0005)
+    TextileApparel,       // 섬유의복(0006)
+    PaperWood,            // 종이목재(0007)
+    Chemicals,            // 화학(0008)
+    Pharmaceutical,       // 의약품(0009)
+    NonMetallicMineral,   // 비금속광물(0010)
+    IronMetal,            // 철강금속(0011)
+    Machinery,            // 기계(0012)
+    ElectricalElectronic, // 전기전자(0013)
+    MedicalPrecision,     // 의료정밀(0014)
+    TransportEquipment,   // 운수장비(0015)
+    Distribution,         // 유통업(0016)
+    ElectricityGas,       // 전기가스업(0017)
+    Construction,         // 건설업(0018)
+    TransportStorage,     // 운수창고업(0019)
+    Telecommunication,    // 통신업(0020)
+    Finance,              // 금융업(0021)
+    Securities,           // 증권(0024)
+    Insurance,            // 보험(0025)
+    Service,              // 서비스업(0026)
+    Manufacturing,        // 제조업(0027)
+}
+impl SectorCode {
+    /// 코스피 주요 업종
+    pub const ALL: [SectorCode; 21] = [
+        Self::FoodBeverage,
+        Self::TextileApparel,
+        Self::PaperWood,
+        Self::Chemicals,
+        Self::Pharmaceutical,
+        Self::NonMetallicMineral,
+        Self::IronMetal,
+        Self::Machinery,
+        Self::ElectricalElectronic,
+        Self::MedicalPrecision,
+        Self::TransportEquipment,
+        Self::Distribution,
+        Self::ElectricityGas,
+        Self::Construction,
+        Self::TransportStorage,
+        Self::Telecommunication,
+        Self::Finance,
+        Self::Securities,
+        Self::Insurance,
+        Self::Service,
+        Self::Manufacturing,
+    ];
+
+    /// 업종코드(4자리)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::FoodBeverage => "0005",
+            Self::TextileApparel => "0006",
+            Self::PaperWood => "0007",
+            Self::Chemicals => "0008",
+            Self::Pharmaceutical => "0009",
+            Self::NonMetallicMineral => "0010",
+            Self::IronMetal => "0011",
+            Self::Machinery => "0012",
+            Self::ElectricalElectronic => "0013",
+            Self::MedicalPrecision => "0014",
+            Self::TransportEquipment => "0015",
+            Self::Distribution => "0016",
+            Self::ElectricityGas => "0017",
+            Self::Construction => "0018",
+            Self::TransportStorage => "0019",
+            Self::Telecommunication => "0020",
+            Self::Finance => "0021",
+            Self::Securities => "0024",
+            Self::Insurance => "0025",
+            Self::Service => "0026",
+            Self::Manufacturing => "0027",
+        }
+    }
+
+    /// 업종명
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::FoodBeverage => "음식료품",
+            Self::TextileApparel => "섬유의복",
+            Self::PaperWood => "종이목재",
+            Self::Chemicals => "화학",
+            Self::Pharmaceutical => "의약품",
+            Self::NonMetallicMineral => "비금속광물",
+            Self::IronMetal => "철강금속",
+            Self::Machinery => "기계",
+            Self::ElectricalElectronic => "전기전자",
+            Self::MedicalPrecision => "의료정밀",
+            Self::TransportEquipment => "운수장비",
+            Self::Distribution => "유통업",
+            Self::ElectricityGas => "전기가스업",
+            Self::Construction => "건설업",
+            Self::TransportStorage => "운수창고업",
+            Self::Telecommunication => "통신업",
+            Self::Finance => "금융업",
+            Self::Securities => "증권",
+            Self::Insurance => "보험",
+            Self::Service => "서비스업",
+            Self::Manufacturing => "제조업",
+        }
+    }
+}
+impl std::fmt::Display for SectorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.code())
+    }
+}
+impl std::str::FromStr for SectorCode {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|sector| sector.code() == s.trim())
+            .ok_or_else(|| ParseCodeError::new("SectorCode", s))
+    }
+}
+
+/// 투자자 구분(투자자별 매매동향)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Investor {
+    Individual,  // 개인
+    Foreign,     // 외국인
+    Institution, // 기관계
+}
+
+/// 해외주식 기간별시세 구분(GUBN)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SerializeDisplay)]
+pub enum OverseasPeriod {
+    Daily,   // 일(0)
+    Weekly,  // 주(1)
+    Monthly, // 월(2)
+}
+impl std::fmt::Display for OverseasPeriod {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Daily => "0",
+            Self::Weekly => "1",
+            Self::Monthly => "2",
+        })
+    }
+}
+impl std::str::FromStr for OverseasPeriod {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim() {
+            "0" => Self::Daily,
+            "1" => Self::Weekly,
+            "2" => Self::Monthly,
+            _ => return Err(ParseCodeError::new("OverseasPeriod", s)),
+        })
+    }
+}
+
+/// 해외주식 거래통화 코드(TR_CRCY_CD)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Deserialize, SerializeDisplay)]
+pub enum Currency {
+    #[serde(rename = "USD")]
+    Usd, // 미국 달러
+    #[serde(rename = "HKD")]
+    Hkd, // 홍콩 달러
+    #[serde(rename = "CNY")]
+    Cny, // 중국 위안
+    #[serde(rename = "JPY")]
+    Jpy, // 일본 엔
+    #[serde(rename = "KRW")]
+    Krw, // 원화
+}
+impl std::fmt::Display for Currency {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Usd => "USD",
+            Self::Hkd => "HKD",
+            Self::Cny => "CNY",
+            Self::Jpy => "JPY",
+            Self::Krw => "KRW",
+        })
+    }
+}
+
+impl std::str::FromStr for Currency {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().to_uppercase().as_str() {
+            "USD" => Self::Usd,
+            "HKD" => Self::Hkd,
+            "CNY" => Self::Cny,
+            "JPY" => Self::Jpy,
+            "KRW" => Self::Krw,
+            _ => return Err(ParseCodeError::new("Currency", s)),
+        })
+    }
+}
+
+/// 환율
+/// pair.0 1단위의 pair.1 통화 가격(예: (Usd, Krw), 1350.5)
+#[cfg(feature = "decimal")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, getset::CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct FxRate {
+    pair: (Currency, Currency), // (기준 통화, 표시 통화)
+    rate: Decimal,              // 환율
+    as_of: chrono::NaiveDate,   // 적용 일자
+}
+
+#[cfg(feature = "decimal")]
+impl FxRate {
+    pub fn new(pair: (Currency, Currency), rate: Decimal, as_of: chrono::NaiveDate) -> Self {
+        Self { pair, rate, as_of }
+    }
+
+    /// pair.0 통화 금액을 pair.1 통화로 환산
+    pub fn convert(&self, amount: Decimal) -> Decimal {
+        amount * self.rate
+    }
+}
+
+/// 해외주식 거래소 코드(시세 조회 EXCD)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, SerializeDisplay)]
+pub enum OverseasExchange {
+    #[serde(rename = "NAS")]
+    Nasdaq, // 나스닥
+    #[serde(rename = "NYS")]
+    Nyse, // 뉴욕
+    #[serde(rename = "AMS")]
+    Amex, // 아멕스
+    #[serde(rename = "HKS")]
+    HongKong, // 홍콩
+    #[serde(rename = "TSE")]
+    Tokyo, // 도쿄
+    #[serde(rename = "SHS")]
+    Shanghai, // 상해
+    #[serde(rename = "SZS")]
+    Shenzhen, // 심천
+}
+impl OverseasExchange {
+    /// 소수점(1주 미만) 주문을 허용하는 거래소(미국: 나스닥, 뉴욕, 아멕스)
+    /// 홍콩, 중국, 일본은 정수 주(일본, 홍콩, 중국은 거래 단위의 배수)만 주문할 수 있음
+    pub fn allows_fractional(&self) -> bool {
+        matches!(self, Self::Nasdaq | Self::Nyse | Self::Amex)
+    }
+
+    /// 주문 시 사용하는 해외거래소코드(OVRS_EXCG_CD)
+    pub fn order_code(&self) -> &'static str {
+        match self {
+            Self::Nasdaq => "NASD",
+            Self::Nyse => "NYSE",
+            Self::Amex => "AMEX",
+            Self::HongKong => "SEHK",
+            Self::Tokyo => "TKSE",
+            Self::Shanghai => "SHAA",
+            Self::Shenzhen => "SZAA",
+        }
+    }
+
+    /// 거래소 거래통화
+    pub fn currency(&self) -> Currency {
+        match self {
+            Self::Nasdaq | Self::Nyse | Self::Amex => Currency::Usd,
+            Self::HongKong => Currency::Hkd,
+            Self::Tokyo => Currency::Jpy,
+            Self::Shanghai | Self::Shenzhen => Currency::Cny,
+        }
+    }
+
+    /// 거래소 현지 시간대(해외주식 시세의 일자는 현지 일자)
+    pub fn timezone(&self) -> chrono_tz::Tz {
+        match self {
+            Self::Nasdaq | Self::Nyse | Self::Amex => chrono_tz::America::New_York,
+            Self::HongKong => chrono_tz::Asia::Hong_Kong,
+            Self::Tokyo => chrono_tz::Asia::Tokyo,
+            Self::Shanghai | Self::Shenzhen => chrono_tz::Asia::Shanghai,
+        }
+    }
+
+    /// 거래소 현지 기준 오늘 일자
+    pub fn today(&self) -> chrono::NaiveDate {
+        chrono::Utc::now()
+            .with_timezone(&self.timezone())
+            .date_naive()
+    }
+}
+impl std::fmt::Display for OverseasExchange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Nasdaq => "NAS",
+            Self::Nyse => "NYS",
+            Self::Amex => "AMS",
+            Self::HongKong => "HKS",
+            Self::Tokyo => "TSE",
+            Self::Shanghai => "SHS",
+            Self::Shenzhen => "SZS",
+        })
+    }
+}
+impl std::str::FromStr for OverseasExchange {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().to_ascii_uppercase().as_str() {
+            "NAS" => Self::Nasdaq,
+            "NYS" => Self::Nyse,
+            "AMS" => Self::Amex,
+            "HKS" => Self::HongKong,
+            "TSE" => Self::Tokyo,
+            "SHS" => Self::Shanghai,
+            "SZS" => Self::Shenzhen,
+            _ => return Err(ParseCodeError::new("OverseasExchange", s)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn account_parses_valid_formats() {
+        for s in ["12345678-01", "1234567801", " 12345678-01 "] {
+            let account: Account = s.parse().unwrap();
+            assert_eq!(account.cano(), "12345678", "{}", s);
+            assert_eq!(account.product_code(), "01", "{}", s);
+            assert_eq!(account.to_string(), "12345678-01");
+        }
+    }
+
+    #[test]
+    fn account_rejects_malformed_input() {
+        for s in [
+            "",
+            "-",
+            "1234567-01",
+            "123456789-01",
+            "12345678-1",
+            "12345678-001",
+            "1234567a-01",
+            "12345678-0x",
+            "12345678",
+            "123456780123",
+            "12345678_01",
+            "１２３４５６７８-01",
+        ] {
+            assert!(
+                matches!(s.parse::<Account>(), Err(crate::Error::InvalidAccount(_))),
+                "{:?}",
+                s
+            );
+        }
+        assert!(matches!(
+            Account::new("12345678", ""),
+            Err(crate::Error::InvalidAccount(_))
+        ));
+    }
+
+    #[test]
+    fn market_code_fid_strings() {
+        let cases = [
+            (MarketCode::Stock, "J"),
+            (MarketCode::Etf, "ETF"),
+            (MarketCode::Nxt, "NX"),
+            (MarketCode::Unified, "UN"),
+            (MarketCode::Elw, "W"),
+        ];
+        for (market_code, fid) in cases {
+            assert_eq!(market_code.to_string(), fid, "{:?}", market_code);
+            assert_eq!(market_code.division().fid_code(), fid, "{:?}", market_code);
+        }
+    }
+
+    #[test]
+    fn market_code_virtual_support() {
+        for market_code in [MarketCode::Stock, MarketCode::Etf] {
+            assert!(market_code.is_supported_in(&Environment::Virtual));
+        }
+        for market_code in [MarketCode::Nxt, MarketCode::Unified, MarketCode::Elw] {
+            assert!(!market_code.is_supported_in(&Environment::Virtual));
+            assert!(market_code.is_supported_in(&Environment::Real));
+        }
+    }
+
+    #[test]
+    fn price_and_quantity_from_u32() {
+        let price: Price = 70_000u32.into();
+        assert_eq!(price, Price::from(70_000));
+        assert_eq!(String::from(price), "70000");
+        assert_eq!("70000".parse::<Price>().unwrap(), price);
+
+        let quantity: Quantity = 10u32.into();
+        assert_eq!(quantity, Quantity::from(10));
+        assert_eq!(String::from(quantity), "10");
+    }
+
+    #[test]
+    fn price_round_to_tick() {
+        let market = MarketCode::Stock;
+        assert_eq!(
+            Price::from(1_999).round_to_tick(&market),
+            Price::from(1_999)
+        );
+        assert_eq!(
+            Price::from(4_997).round_to_tick(&market),
+            Price::from(4_995)
+        );
+        assert_eq!(
+            Price::from(4_998).round_to_tick(&market),
+            Price::from(5_000)
+        );
+        assert_eq!(
+            Price::from(70_050).round_to_tick(&market),
+            Price::from(70_100)
+        );
+        assert!(Price::from(70_100).is_valid_tick(&market));
+        assert!(!Price::from(70_050).is_valid_tick(&market));
+        assert_eq!(
+            Price::from(12_347).round_to_tick(&MarketCode::Etf),
+            Price::from(12_345)
+        );
+    }
+
+    #[test]
+    fn order_class_codes() {
+        let cases = [
+            (OrderClass::Limit, "00"),
+            (OrderClass::Market, "01"),
+            (OrderClass::ConditionalLimit, "02"),
+            (OrderClass::Best, "03"),
+            (OrderClass::First, "04"),
+            (OrderClass::PreMarket, "05"),
+            (OrderClass::PostMarket, "06"),
+            (OrderClass::OutMarketSinglePrice, "07"),
+            (OrderClass::MyStock, "08"),
+            (OrderClass::MyStockSOption, "09"),
+            (OrderClass::MyStockMoneyTrust, "10"),
+            (OrderClass::IOCLimit, "11"),
+            (OrderClass::FOKLimit, "12"),
+            (OrderClass::IOCMarket, "13"),
+            (OrderClass::FOKMarket, "14"),
+            (OrderClass::IOCBest, "15"),
+            (OrderClass::FOKBest, "16"),
+        ];
+        for (order_class, code) in cases {
+            assert_eq!(order_class.to_string(), code);
+            assert_eq!(code.parse::<OrderClass>().unwrap(), order_class);
+            assert_eq!(
+                serde_json::to_string(&order_class).unwrap(),
+                format!("\"{}\"", code)
+            );
+        }
+        assert!("17".parse::<OrderClass>().is_err());
+    }
+
+    #[test]
+    fn price_sign_codes() {
+        let cases = [
+            ("1", PriceSign::UpperLimit, 1),
+            ("2", PriceSign::Increase, 1),
+            ("3", PriceSign::Steady, 0),
+            ("4", PriceSign::LowerLimit, -1),
+            ("5", PriceSign::Decrease, -1),
+        ];
+        for (code, sign, multiplier) in cases {
+            assert_eq!(PriceSign::from(code), sign);
+            assert_eq!(code.parse::<PriceSign>().unwrap(), sign);
+            assert_eq!(
+                serde_json::from_str::<PriceSign>(&format!("\"{}\"", code)).unwrap(),
+                sign
+            );
+            assert_eq!(sign.as_multiplier(), multiplier);
+        }
+    }
+
+    #[test]
+    fn price_sign_unknown_codes() {
+        for code in ["", " ", "0", "6", "9", "+", "A", "12"] {
+            assert_eq!(PriceSign::from(code), PriceSign::Unknown, "{:?}", code);
+            assert_eq!(
+                serde_json::from_str::<PriceSign>(&format!("\"{}\"", code)).unwrap(),
+                PriceSign::Unknown,
+                "{:?}",
+                code
+            );
+        }
+        assert_eq!(PriceSign::from(" 2 "), PriceSign::Increase);
+        assert_eq!(PriceSign::Unknown.as_multiplier(), 0);
+    }
+
+    /// Display가 출력한 코드를 FromStr로 되돌리면 같은 variant이고, variant마다 코드가 다름
+    fn assert_round_trip<T>(values: &[T])
+    where
+        T: std::fmt::Display + std::str::FromStr + std::fmt::Debug,
+        T::Err: std::fmt::Debug,
+    {
+        let mut codes = std::collections::HashSet::new();
+        for value in values {
+            let code = value.to_string();
+            let parsed: T = code.parse().unwrap();
+            assert_eq!(format!("{:?}", parsed), format!("{:?}", value), "{}", code);
+            assert!(codes.insert(code), "duplicate code of {:?}", value);
+        }
+    }
+
+    /// 정의되지 않은 코드는 ParseCodeError
+    fn assert_rejects<T>(kind: &str)
+    where
+        T: std::str::FromStr<Err = ParseCodeError> + std::fmt::Debug,
+    {
+        for s in ["", "?", "not-a-code"] {
+            let error = s.parse::<T>().unwrap_err();
+            assert_eq!(error.kind(), kind);
+            assert_eq!(error.value(), s);
+        }
+    }
+
+    #[test]
+    fn code_enums_round_trip() {
+        assert_round_trip(&[Environment::Real, Environment::Virtual]);
+        assert_round_trip(&[
+            OrderClass::Limit,
+            OrderClass::Market,
+            OrderClass::ConditionalLimit,
+            OrderClass::Best,
+            OrderClass::First,
+            OrderClass::PreMarket,
+            OrderClass::PostMarket,
+            OrderClass::OutMarketSinglePrice,
+            OrderClass::MyStock,
+            OrderClass::MyStockSOption,
+            OrderClass::MyStockMoneyTrust,
+            OrderClass::IOCLimit,
+            OrderClass::FOKLimit,
+            OrderClass::IOCMarket,
+            OrderClass::FOKMarket,
+            OrderClass::IOCBest,
+            OrderClass::FOKBest,
+        ]);
+        assert_round_trip(&[
+            CorrectionClass::None,
+            CorrectionClass::Correction,
+            CorrectionClass::Cancel,
+        ]);
+        assert_round_trip(&[Direction::Bid, Direction::Ask]);
+        assert_round_trip(&[CustomerType::Business, CustomerType::Personal]);
+        assert_round_trip(&[
+            MarketCode::Stock,
+            MarketCode::Etf,
+            MarketCode::Nxt,
+            MarketCode::Unified,
+            MarketCode::Elw,
+        ]);
+        assert_round_trip(&[
+            MarketDivision::Krx,
+            MarketDivision::Nxt,
+            MarketDivision::Unified,
+            MarketDivision::Etf,
+            MarketDivision::Etn,
+            MarketDivision::Elw,
+            MarketDivision::Sector,
+        ]);
+        assert_round_trip(&[Exchange::Krx, Exchange::Nxt, Exchange::Sor]);
+        assert_round_trip(&[
+            ChartPeriod::Days,
+            ChartPeriod::Weeks,
+            ChartPeriod::Months,
+            ChartPeriod::Years,
+        ]);
+        assert_round_trip(&[DailyPeriod::Days, DailyPeriod::Weeks, DailyPeriod::Months]);
+        assert_round_trip(&[
+            ExCode::None,
+            ExCode::ExRights,
+            ExCode::ExDividend,
+            ExCode::ExEtfDividend,
+            ExCode::ExRightsAndDividend,
+            ExCode::MidOrQtrExDividend,
+            ExCode::MidExRightsAndDividend,
+            ExCode::QtrExRightsAndDividend,
+        ]);
+        assert_round_trip(&[
+            BelongClassCode::MeanVolume,
+            BelongClassCode::IncreasedVolumeRate,
+            BelongClassCode::MeanRotationRate,
+            BelongClassCode::Amount,
+            BelongClassCode::AmountRotationRate,
+        ]);
+        assert_round_trip(&[
+            ShareClassCode::Whole,
+            ShareClassCode::Common,
+            ShareClassCode::Preferred,
+        ]);
+        assert_round_trip(&[
+            IndexCode::Kospi,
+            IndexCode::KospiLarge,
+            IndexCode::KospiMedium,
+            IndexCode::KospiSmall,
+            IndexCode::Kosdaq,
+            IndexCode::Kospi200,
+        ]);
+        assert_round_trip(&SectorCode::ALL);
+        assert_round_trip(&[
+            TrId::RealStockCashBidOrder,
+            TrId::RealStockCashAskOrder,
+            TrId::VirtualStockCashBidOrder,
+            TrId::VirtualStockCashAskOrder,
+            TrId::RealStockCorrection,
+            TrId::VirtualStockCorrection,
+            TrId::RealStockBalance,
+            TrId::VirtualStockBalance,
+            TrId::RealStockDailyCcld,
+            TrId::VirtualStockDailyCcld,
+            TrId::RealStockDailyCcldBefore3Months,
+            TrId::VirtualStockDailyCcldBefore3Months,
+            TrId::RealStockPsblRvsecncl,
+            TrId::RealStockPsblOrder,
+            TrId::RealStockCreditPsblOrder,
+            TrId::RealStockPeriodProfit,
+            TrId::RealStockReserveOrder,
+            TrId::RealStockReserveCancel,
+            TrId::RealStockReserveOrderList,
+            TrId::VirtualStockPsblOrder,
+            TrId::CurrentPrice,
+            TrId::DailyPrice,
+            TrId::PeriodicPrice,
+            TrId::MinuteChart,
+            TrId::AskingPrice,
+            TrId::InvestorTrend,
+            TrId::ElwPrice,
+            TrId::ElwSensitivity,
+            TrId::ElwUnderlyingPrice,
+            TrId::StockBasicInfo,
+            TrId::ProgramTradeTrend,
+            TrId::DividendSchedule,
+            TrId::WatchlistPrice,
+            TrId::TimeConclusion,
+            TrId::FluctuationRank,
+            TrId::VolumeRank,
+            TrId::MarketCapRank,
+            TrId::IndexPrice,
+            TrId::HolidayCheck,
+            TrId::RealOverseasUsBidOrder,
+            TrId::RealOverseasUsAskOrder,
+            TrId::RealOverseasHongKongBidOrder,
+            TrId::RealOverseasHongKongAskOrder,
+            TrId::RealOverseasShanghaiBidOrder,
+            TrId::RealOverseasShanghaiAskOrder,
+            TrId::RealOverseasShenzhenBidOrder,
+            TrId::RealOverseasShenzhenAskOrder,
+            TrId::RealOverseasJapanBidOrder,
+            TrId::RealOverseasJapanAskOrder,
+            TrId::VirtualOverseasUsBidOrder,
+            TrId::VirtualOverseasUsAskOrder,
+            TrId::VirtualOverseasHongKongBidOrder,
+            TrId::VirtualOverseasHongKongAskOrder,
+            TrId::VirtualOverseasShanghaiBidOrder,
+            TrId::VirtualOverseasShanghaiAskOrder,
+            TrId::VirtualOverseasShenzhenBidOrder,
+            TrId::VirtualOverseasShenzhenAskOrder,
+            TrId::VirtualOverseasJapanBidOrder,
+            TrId::VirtualOverseasJapanAskOrder,
+            TrId::RealOverseasBalance,
+            TrId::VirtualOverseasBalance,
+            TrId::RealOverseasBuyable,
+            TrId::VirtualOverseasBuyable,
+            TrId::OverseasCurrentPrice,
+            TrId::OverseasDailyChart,
+            TrId::FuturesPrice,
+            TrId::RealFuturesOrder,
+            TrId::VirtualFuturesOrder,
+            TrId::BondPrice,
+            TrId::BondBasicInfo,
+            TrId::RealBondBuyOrder,
+            TrId::RealBondSellOrder,
+            TrId::RealtimeExec,
+            TrId::RealtimeOrdb,
+            TrId::RealRealtimeMyExec,
+            TrId::VirtualRealtimeMyExec,
+            TrId::PingPong,
+        ]);
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn overseas_code_enums_round_trip() {
+        assert_round_trip(&[
+            OverseasPeriod::Daily,
+            OverseasPeriod::Weekly,
+            OverseasPeriod::Monthly,
+        ]);
+        assert_round_trip(&[
+            Currency::Usd,
+            Currency::Hkd,
+            Currency::Cny,
+            Currency::Jpy,
+            Currency::Krw,
+        ]);
+        assert_round_trip(&[
+            OverseasExchange::Nasdaq,
+            OverseasExchange::Nyse,
+            OverseasExchange::Amex,
+            OverseasExchange::HongKong,
+            OverseasExchange::Tokyo,
+            OverseasExchange::Shanghai,
+            OverseasExchange::Shenzhen,
+        ]);
+    }
+
+    #[test]
+    fn code_enums_reject_unknown_codes() {
+        assert_rejects::<OrderClass>("OrderClass");
+        assert_rejects::<CorrectionClass>("CorrectionClass");
+        assert_rejects::<Direction>("Direction");
+        assert_rejects::<CustomerType>("CustomerType");
+        assert_rejects::<MarketCode>("MarketCode");
+        assert_rejects::<MarketDivision>("MarketDivision");
+        assert_rejects::<Exchange>("Exchange");
+        assert_rejects::<ChartPeriod>("ChartPeriod");
+        assert_rejects::<DailyPeriod>("DailyPeriod");
+        assert_rejects::<ExCode>("ExCode");
+        assert_rejects::<BelongClassCode>("BelongClassCode");
+        assert_rejects::<ShareClassCode>("ShareClassCode");
+        assert_rejects::<IndexCode>("IndexCode");
+        assert_rejects::<SectorCode>("SectorCode");
+        assert_rejects::<TrId>("TrId");
+    }
+}
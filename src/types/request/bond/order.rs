@@ -0,0 +1,94 @@
+pub mod body {
+    use crate::types::{Price, Quantity};
+    use serde::{Deserialize, Serialize};
+
+    /// 장내채권 매수주문
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct Buy {
+        cano: String,              // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+        acnt_prdt_cd: String,      // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+        pdno: String,              // 상품번호(채권 표준코드)
+        ord_qty2: Quantity,        // 주문수량
+        bond_ord_unpr: Price,      // 채권주문단가
+        samt_mket_ptci_yn: String, // 소액시장참여여부
+        bond_rtl_mket_yn: String,  // 채권소매시장여부
+        idcr_stfno: String,        // 유치자직원번호
+        mgco_aptm_odno: String,    // 운용사지정주문번호
+        ord_svr_dvsn_cd: String,   // 주문서버구분코드(0)
+        ctac_tlno: String,         // 연락전화번호
+    }
+
+    impl Buy {
+        pub fn new(
+            cano: String,
+            acnt_prdt_cd: String,
+            pdno: String,
+            ord_qty2: Quantity,
+            bond_ord_unpr: Price,
+        ) -> Self {
+            Self {
+                cano,
+                acnt_prdt_cd,
+                pdno,
+                ord_qty2,
+                bond_ord_unpr,
+                samt_mket_ptci_yn: "N".to_string(),
+                bond_rtl_mket_yn: "N".to_string(),
+                idcr_stfno: "".to_string(),
+                mgco_aptm_odno: "".to_string(),
+                ord_svr_dvsn_cd: "0".to_string(),
+                ctac_tlno: "".to_string(),
+            }
+        }
+    }
+
+    /// 장내채권 매도주문
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct Sell {
+        cano: String,                 // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+        acnt_prdt_cd: String,         // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+        ord_dvsn: String,             // 주문구분(01: 일반)
+        pdno: String,                 // 상품번호(채권 표준코드)
+        ord_qty2: Quantity,           // 주문수량
+        bond_ord_unpr: Price,         // 채권주문단가
+        sprx_yn: String,              // 분리과세여부
+        buy_dt: String,               // 매수일자
+        buy_seq: String,              // 매수순번
+        samt_mket_ptci_yn: String,    // 소액시장참여여부
+        sll_agco_opps_sll_yn: String, // 매도대행사반대매도여부
+        bond_rtl_mket_yn: String,     // 채권소매시장여부
+        mgco_aptm_odno: String,       // 운용사지정주문번호
+        ord_svr_dvsn_cd: String,      // 주문서버구분코드(0)
+        ctac_tlno: String,            // 연락전화번호
+    }
+
+    impl Sell {
+        pub fn new(
+            cano: String,
+            acnt_prdt_cd: String,
+            pdno: String,
+            ord_qty2: Quantity,
+            bond_ord_unpr: Price,
+        ) -> Self {
+            Self {
+                cano,
+                acnt_prdt_cd,
+                ord_dvsn: "01".to_string(),
+                pdno,
+                ord_qty2,
+                bond_ord_unpr,
+                sprx_yn: "N".to_string(),
+                buy_dt: "".to_string(),
+                buy_seq: "".to_string(),
+                samt_mket_ptci_yn: "N".to_string(),
+                sll_agco_opps_sll_yn: "N".to_string(),
+                bond_rtl_mket_yn: "N".to_string(),
+                mgco_aptm_odno: "".to_string(),
+                ord_svr_dvsn_cd: "0".to_string(),
+                ctac_tlno: "".to_string(),
+            }
+        }
+    }
+}
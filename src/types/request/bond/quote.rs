@@ -0,0 +1,42 @@
+use getset::Getters;
+use serde::Serialize;
+
+/// 장내채권현재가(시세)
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct BondPriceParameter {
+    #[getset(get = "pub")]
+    fid_input_iscd: String, // FID 입력 종목코드(채권 표준코드, 예: KR2033022D33)
+}
+impl BondPriceParameter {
+    pub fn new(code: String) -> Self {
+        Self {
+            fid_input_iscd: code,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 2] {
+        [
+            ("FID_COND_MRKT_DIV_CODE", "B".to_string()), // 조건시장분류코드(B: 장내채권)
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+        ]
+    }
+}
+
+/// 장내채권 기본조회
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct BondBasicInfoParameter {
+    #[getset(get = "pub")]
+    pdno: String, // 상품번호(채권 표준코드)
+}
+impl BondBasicInfoParameter {
+    pub fn new(pdno: String) -> Self {
+        Self { pdno }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 2] {
+        [
+            ("PDNO", self.pdno.clone()),
+            ("PRDT_TYPE_CD", "302".to_string()), // 상품유형코드(302: 채권)
+        ]
+    }
+}
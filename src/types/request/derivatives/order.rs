@@ -0,0 +1,46 @@
+pub mod body {
+    use crate::types::{Direction, Price, Quantity};
+    use serde::{Deserialize, Serialize};
+
+    /// 선물옵션 주문
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct Order {
+        ord_prcs_dvsn_cd: String, // 주문처리구분코드(02: 주문전송)
+        cano: String,             // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+        acnt_prdt_cd: String,     // 계좌상품코드(선물옵션: 03)
+        sll_buy_dvsn_cd: String,  // 매도매수구분코드(01: 매도, 02: 매수)
+        shtn_pdno: String,        // 단축상품번호(선물옵션 종목코드)
+        ord_qty: Quantity,        // 주문수량
+        unit_price: Price,        // 주문가격(시장가는 0)
+        nmpr_type_cd: String,     // 호가유형코드(01: 지정가, 02: 시장가)
+        krx_nmpr_cndt_cd: String, // 한국거래소호가조건코드(0: 없음)
+        ord_dvsn_cd: String,      // 주문구분코드(01: 지정가, 02: 시장가)
+    }
+
+    impl Order {
+        pub fn new(
+            cano: String,
+            acnt_prdt_cd: String,
+            direction: Direction,
+            shtn_pdno: String,
+            ord_qty: Quantity,
+            unit_price: Price,
+        ) -> Self {
+            let is_market = unit_price == Price::from(0);
+            let nmpr_type_cd = if is_market { "02" } else { "01" }.to_string();
+            Self {
+                ord_prcs_dvsn_cd: "02".to_string(),
+                cano,
+                acnt_prdt_cd,
+                sll_buy_dvsn_cd: direction.to_string(),
+                shtn_pdno,
+                ord_qty,
+                unit_price,
+                ord_dvsn_cd: nmpr_type_cd.clone(),
+                nmpr_type_cd,
+                krx_nmpr_cndt_cd: "0".to_string(),
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use getset::Getters;
+use serde::Serialize;
+
+/// 선물옵션 시세
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct FuturesPriceParameter {
+    #[getset(get = "pub")]
+    fid_cond_mrkt_div_code: String, // FID 조건 시장 분류 코드(F: 지수선물, O: 지수옵션, JF: 주식선물, JO: 주식옵션)
+    #[getset(get = "pub")]
+    fid_input_iscd: String, // FID 입력 종목코드(예: 101W09)
+}
+impl FuturesPriceParameter {
+    pub fn new(market: &str, code: String) -> Self {
+        Self {
+            fid_cond_mrkt_div_code: market.to_string(),
+            fid_input_iscd: code,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 2] {
+        [
+            (
+                "FID_COND_MRKT_DIV_CODE",
+                self.fid_cond_mrkt_div_code.clone(),
+            ),
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+        ]
+    }
+}
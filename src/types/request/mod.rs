@@ -1,2 +1,6 @@
 pub mod auth;
+pub mod bond;
+pub mod derivatives;
+#[cfg(feature = "decimal")]
+pub mod overseas;
 pub mod stock;
@@ -0,0 +1,94 @@
+use crate::paging::ContinuationKey;
+use crate::types::{Account, Currency, OverseasExchange};
+use getset::{CopyGetters, Getters};
+use serde::Serialize;
+
+/// 해외주식 잔고
+#[derive(Debug, Clone, Getters, CopyGetters, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct OverseasBalanceParameter {
+    #[getset(get = "pub")]
+    cano: String, // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub")]
+    acnt_prdt_cd: String, // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub")]
+    ovrs_excg_cd: String, // 해외거래소코드(NASD, NYSE, AMEX, SEHK, SHAA, SZAA, TKSE)
+    #[getset(get_copy = "pub")]
+    tr_crcy_cd: Currency, // 거래통화코드(USD, HKD, CNY, JPY)
+    #[getset(get = "pub")]
+    ctx_area_fk200: String, // 연속조회검색조건200(최초 조회시는 공란, 다음 조회시 이전 응답의 값)
+    #[getset(get = "pub")]
+    ctx_area_nk200: String, // 연속조회키200(최초 조회시는 공란, 다음 조회시 이전 응답의 값)
+}
+impl OverseasBalanceParameter {
+    pub fn new(account: &Account, exchange: OverseasExchange, currency: Currency) -> Self {
+        Self {
+            cano: account.cano().to_string(),
+            acnt_prdt_cd: account.product_code().to_string(),
+            ovrs_excg_cd: exchange.order_code().to_string(),
+            tr_crcy_cd: currency,
+            ctx_area_fk200: "".to_string(),
+            ctx_area_nk200: "".to_string(),
+        }
+    }
+
+    /// 이전 응답의 연속조회키(CTX_AREA_FK200, CTX_AREA_NK200)로 다음 페이지 조회
+    pub fn with_continuation(mut self, key: &ContinuationKey) -> Self {
+        self.ctx_area_fk200 = key.fk100.clone();
+        self.ctx_area_nk200 = key.nk100.clone();
+        self
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 6] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("OVRS_EXCG_CD", self.ovrs_excg_cd.clone()),
+            ("TR_CRCY_CD", self.tr_crcy_cd.to_string()),
+            ("CTX_AREA_FK200", self.ctx_area_fk200.clone()),
+            ("CTX_AREA_NK200", self.ctx_area_nk200.clone()),
+        ]
+    }
+}
+
+/// 해외주식 매수가능금액조회
+#[derive(Debug, Clone, Getters, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct OverseasBuyableParameter {
+    #[getset(get = "pub")]
+    cano: String, // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub")]
+    acnt_prdt_cd: String, // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub")]
+    ovrs_excg_cd: String, // 해외거래소코드
+    #[getset(get = "pub")]
+    ovrs_ord_unpr: String, // 해외주문단가
+    #[getset(get = "pub")]
+    item_cd: String, // 종목코드
+}
+impl OverseasBuyableParameter {
+    pub fn new(
+        account: &Account,
+        exchange: OverseasExchange,
+        symbol: &str,
+        price: rust_decimal::Decimal,
+    ) -> Self {
+        Self {
+            cano: account.cano().to_string(),
+            acnt_prdt_cd: account.product_code().to_string(),
+            ovrs_excg_cd: exchange.order_code().to_string(),
+            ovrs_ord_unpr: price.to_string(),
+            item_cd: symbol.to_string(),
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 5] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("OVRS_EXCG_CD", self.ovrs_excg_cd.clone()),
+            ("OVRS_ORD_UNPR", self.ovrs_ord_unpr.clone()),
+            ("ITEM_CD", self.item_cd.clone()),
+        ]
+    }
+}
@@ -0,0 +1,47 @@
+pub mod body {
+    use rust_decimal::Decimal;
+    use serde::{Deserialize, Serialize};
+
+    /// 해외주식 주문
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct Order {
+        cano: String,          // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+        acnt_prdt_cd: String,  // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+        ovrs_excg_cd: String,  // 해외거래소코드(NASD, NYSE, AMEX, SEHK, SHAA, SZAA, TKSE)
+        pdno: String,          // 상품번호(종목코드)
+        ord_qty: String,       // 주문수량(소수점 주문은 0.5 형식)
+        ovrs_ord_unpr: String, // 해외주문단가(1주당 가격)
+        #[serde(skip_serializing_if = "Option::is_none")]
+        sll_type: Option<String>, // 판매유형(매수: 제외, 매도: 00)
+        ord_svr_dvsn_cd: String, // 주문서버구분코드("0")
+        ord_dvsn: String,      // 주문구분(00: 지정가)
+    }
+
+    impl Order {
+        pub fn new(
+            cano: String,
+            acnt_prdt_cd: String,
+            ovrs_excg_cd: String,
+            pdno: String,
+            ord_qty: impl Into<Decimal>,
+            ovrs_ord_unpr: Decimal,
+            is_sell: bool,
+        ) -> Self {
+            Self {
+                cano,
+                acnt_prdt_cd,
+                ovrs_excg_cd,
+                pdno,
+                ord_qty: ord_qty.into().normalize().to_string(),
+                ovrs_ord_unpr: ovrs_ord_unpr.to_string(),
+                sll_type: is_sell.then(|| "00".to_string()),
+                ord_svr_dvsn_cd: "0".to_string(),
+                ord_dvsn: "00".to_string(),
+            }
+        }
+        pub fn get_json_string(self) -> String {
+            serde_json::json!(self).to_string()
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use crate::types::{OverseasExchange, OverseasPeriod};
+use getset::{CopyGetters, Getters};
+use serde::Serialize;
+
+/// 해외주식 현재체결가
+#[derive(Debug, Clone, Getters, CopyGetters, Serialize)]
+pub struct CurrentPriceParameter {
+    #[getset(get_copy = "pub")]
+    excd: OverseasExchange, // 거래소코드
+    #[getset(get = "pub")]
+    symb: String, // 종목코드
+}
+impl CurrentPriceParameter {
+    pub fn new(exchange: OverseasExchange, symbol: String) -> Self {
+        Self {
+            excd: exchange,
+            symb: symbol,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 3] {
+        [
+            ("AUTH", "".to_string()), // 사용자권한정보(공란)
+            ("EXCD", format!("{}", self.excd)),
+            ("SYMB", self.symb.clone()),
+        ]
+    }
+}
+
+/// 해외주식 기간별시세
+#[derive(Debug, Clone, Getters, CopyGetters, Serialize)]
+pub struct DailyChartParameter {
+    #[getset(get_copy = "pub")]
+    excd: OverseasExchange, // 거래소코드
+    #[getset(get = "pub")]
+    symb: String, // 종목코드
+    #[getset(get_copy = "pub")]
+    gubn: OverseasPeriod, // 일/주/월구분
+    #[getset(get = "pub")]
+    bymd: String, // 조회기준일자(YYYYMMDD, 현지 일자. 이 일자부터 과거로 조회)
+    #[getset(get_copy = "pub")]
+    modp: bool, // 수정주가반영여부
+}
+impl DailyChartParameter {
+    pub fn new(
+        exchange: OverseasExchange,
+        symbol: String,
+        period: OverseasPeriod,
+        end_date: String,
+        is_adjust_price: bool,
+    ) -> Self {
+        Self {
+            excd: exchange,
+            symb: symbol,
+            gubn: period,
+            bymd: end_date,
+            modp: is_adjust_price,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 6] {
+        [
+            ("AUTH", "".to_string()), // 사용자권한정보(공란)
+            ("EXCD", format!("{}", self.excd)),
+            ("SYMB", self.symb.clone()),
+            ("GUBN", format!("{}", self.gubn)),
+            ("BYMD", self.bymd.clone()),
+            ("MODP", if self.modp { "1" } else { "0" }.to_string()),
+        ]
+    }
+}
@@ -0,0 +1,330 @@
+use crate::paging::ContinuationKey;
+use crate::types::{Account, DateRange, Direction, OrderClass};
+use getset::Getters;
+use serde::Serialize;
+
+/// 주식잔고조회
+#[derive(Debug, Clone, Getters, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct BalanceParameter {
+    #[getset(get = "pub")]
+    cano: String, // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub")]
+    acnt_prdt_cd: String, // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub")]
+    ctx_area_fk100: String, // 연속조회검색조건100(최초 조회시는 공란, 다음 조회시 이전 응답의 값)
+    #[getset(get = "pub")]
+    ctx_area_nk100: String, // 연속조회키100(최초 조회시는 공란, 다음 조회시 이전 응답의 값)
+    #[getset(get = "pub")]
+    inqr_dvsn: String, // 조회구분(01: 대출일별, 02: 종목별)
+}
+impl BalanceParameter {
+    pub fn new(account: &Account) -> Self {
+        Self {
+            cano: account.cano().to_string(),
+            acnt_prdt_cd: account.product_code().to_string(),
+            ctx_area_fk100: "".to_string(),
+            ctx_area_nk100: "".to_string(),
+            inqr_dvsn: "02".to_string(),
+        }
+    }
+
+    /// 대출일별 조회(신용 잔고를 대출일자별로 구분)
+    pub fn by_loan_date(mut self) -> Self {
+        self.inqr_dvsn = "01".to_string();
+        self
+    }
+
+    /// 이전 응답의 연속조회키로 다음 페이지 조회
+    pub fn with_continuation(mut self, key: &ContinuationKey) -> Self {
+        self.ctx_area_fk100 = key.fk100.clone();
+        self.ctx_area_nk100 = key.nk100.clone();
+        self
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 11] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("AFHR_FLPR_YN", "N".to_string()), // 시간외단일가여부(N: 기본값)
+            ("OFL_YN", "".to_string()),        // 오프라인여부(공란)
+            ("INQR_DVSN", self.inqr_dvsn.clone()),
+            ("UNPR_DVSN", "01".to_string()), // 단가구분(01: 기본값)
+            ("FUND_STTL_ICLD_YN", "N".to_string()), // 펀드결제분포함여부
+            ("FNCG_AMT_AUTO_RDPT_YN", "N".to_string()), // 융자금액자동상환여부
+            ("PRCS_DVSN", "00".to_string()), // 처리구분(00: 전일매매포함, 01: 전일매매미포함)
+            ("CTX_AREA_FK100", self.ctx_area_fk100.clone()),
+            ("CTX_AREA_NK100", self.ctx_area_nk100.clone()),
+        ]
+    }
+}
+
+/// 주식일별주문체결조회
+#[derive(Debug, Clone, Getters, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct DailyCcldParameter {
+    #[getset(get = "pub")]
+    cano: String, // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub")]
+    acnt_prdt_cd: String, // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub")]
+    inqr_strt_dt: String, // 조회시작일자(YYYYMMDD)
+    #[getset(get = "pub")]
+    inqr_end_dt: String, // 조회종료일자(YYYYMMDD)
+    #[getset(get = "pub")]
+    sll_buy_dvsn_cd: String, // 매도매수구분코드(00: 전체, 01: 매도, 02: 매수)
+    #[getset(get = "pub")]
+    ctx_area_fk100: String, // 연속조회검색조건100
+    #[getset(get = "pub")]
+    ctx_area_nk100: String, // 연속조회키100
+}
+impl DailyCcldParameter {
+    pub fn new(account: &Account, range: &DateRange, direction: Option<Direction>) -> Self {
+        Self {
+            cano: account.cano().to_string(),
+            acnt_prdt_cd: account.product_code().to_string(),
+            inqr_strt_dt: range.start_str(),
+            inqr_end_dt: range.end_str(),
+            sll_buy_dvsn_cd: match direction {
+                None => "00",
+                Some(Direction::Ask) => "01",
+                Some(Direction::Bid) => "02",
+            }
+            .to_string(),
+            ctx_area_fk100: "".to_string(),
+            ctx_area_nk100: "".to_string(),
+        }
+    }
+
+    /// 이전 응답의 연속조회키로 다음 페이지 조회
+    pub fn with_continuation(mut self, key: &ContinuationKey) -> Self {
+        self.ctx_area_fk100 = key.fk100.clone();
+        self.ctx_area_nk100 = key.nk100.clone();
+        self
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 15] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("INQR_STRT_DT", self.inqr_strt_dt.clone()),
+            ("INQR_END_DT", self.inqr_end_dt.clone()),
+            ("SLL_BUY_DVSN_CD", self.sll_buy_dvsn_cd.clone()),
+            ("INQR_DVSN", "00".to_string()), // 조회구분(00: 역순, 01: 정순)
+            ("PDNO", "".to_string()),        // 상품번호(공란: 전체)
+            ("CCLD_DVSN", "00".to_string()), // 체결구분(00: 전체, 01: 체결, 02: 미체결)
+            ("ORD_GNO_BRNO", "".to_string()), // 주문채번지점번호(공란)
+            ("ODNO", "".to_string()),        // 주문번호(공란: 전체)
+            ("INQR_DVSN_3", "00".to_string()), // 조회구분3(00: 전체)
+            ("INQR_DVSN_1", "".to_string()), // 조회구분1(공란: 전체)
+            ("EXCG_ID_DVSN_CD", "KRX".to_string()), // 거래소ID구분코드
+            ("CTX_AREA_FK100", self.ctx_area_fk100.clone()),
+            ("CTX_AREA_NK100", self.ctx_area_nk100.clone()),
+        ]
+    }
+}
+
+/// 주식정정취소가능주문조회
+#[derive(Debug, Clone, Getters, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct PsblRvsecnclParameter {
+    #[getset(get = "pub")]
+    cano: String, // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub")]
+    acnt_prdt_cd: String, // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub")]
+    ctx_area_fk100: String, // 연속조회검색조건100
+    #[getset(get = "pub")]
+    ctx_area_nk100: String, // 연속조회키100
+}
+impl PsblRvsecnclParameter {
+    pub fn new(account: &Account) -> Self {
+        Self {
+            cano: account.cano().to_string(),
+            acnt_prdt_cd: account.product_code().to_string(),
+            ctx_area_fk100: "".to_string(),
+            ctx_area_nk100: "".to_string(),
+        }
+    }
+
+    /// 이전 응답의 연속조회키로 다음 페이지 조회
+    pub fn with_continuation(mut self, key: &ContinuationKey) -> Self {
+        self.ctx_area_fk100 = key.fk100.clone();
+        self.ctx_area_nk100 = key.nk100.clone();
+        self
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 6] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("CTX_AREA_FK100", self.ctx_area_fk100.clone()),
+            ("CTX_AREA_NK100", self.ctx_area_nk100.clone()),
+            ("INQR_DVSN_1", "0".to_string()), // 조회구분1(0: 주문, 1: 종목)
+            ("INQR_DVSN_2", "0".to_string()), // 조회구분2(0: 전체, 1: 매도, 2: 매수)
+        ]
+    }
+}
+
+/// 매수가능조회
+#[derive(Debug, Clone, Getters, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct PsblOrderParameter {
+    #[getset(get = "pub")]
+    cano: String, // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub")]
+    acnt_prdt_cd: String, // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub")]
+    pdno: String, // 상품번호(종목코드)
+    #[getset(get = "pub")]
+    ord_unpr: String, // 주문단가(시장가 조회 시 공란, 주문가능수량 계산 단가는 현재가)
+    #[getset(get = "pub")]
+    ord_dvsn: OrderClass, // 주문구분
+    #[getset(get = "pub")]
+    crdt_type: Option<String>, // 신용유형(신용매수가능조회만 사용, 21: 자기융자신규)
+}
+impl PsblOrderParameter {
+    pub fn new(account: &Account, pdno: String, price: u32, ord_dvsn: OrderClass) -> Self {
+        let ord_unpr = if price == 0 {
+            "".to_string()
+        } else {
+            price.to_string()
+        };
+        Self {
+            cano: account.cano().to_string(),
+            acnt_prdt_cd: account.product_code().to_string(),
+            pdno,
+            ord_unpr,
+            ord_dvsn,
+            crdt_type: None,
+        }
+    }
+
+    /// 신용매수가능조회. crdt_type 21(자기융자신규)로 조회
+    pub fn credit(mut self) -> Self {
+        self.crdt_type = Some("21".to_string());
+        self
+    }
+
+    pub fn into_iter(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("PDNO", self.pdno.clone()),
+            ("ORD_UNPR", self.ord_unpr.clone()),
+            ("ORD_DVSN", self.ord_dvsn.to_string()),
+            ("CMA_EVLU_AMT_ICLD_YN", "N".to_string()), // CMA평가금액포함여부
+            ("OVRS_ICLD_YN", "N".to_string()),         // 해외포함여부
+        ];
+        if let Some(crdt_type) = &self.crdt_type {
+            params.push(("CRDT_TYPE", crdt_type.clone()));
+        }
+        params
+    }
+}
+
+/// 기간별매매손익현황조회
+#[derive(Debug, Clone, Getters, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct PeriodProfitParameter {
+    #[getset(get = "pub")]
+    cano: String, // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub")]
+    acnt_prdt_cd: String, // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub")]
+    inqr_strt_dt: String, // 조회시작일자(YYYYMMDD)
+    #[getset(get = "pub")]
+    inqr_end_dt: String, // 조회종료일자(YYYYMMDD)
+    #[getset(get = "pub")]
+    ctx_area_fk100: String, // 연속조회검색조건100
+    #[getset(get = "pub")]
+    ctx_area_nk100: String, // 연속조회키100
+}
+impl PeriodProfitParameter {
+    pub fn new(account: &Account, range: &DateRange) -> Self {
+        Self {
+            cano: account.cano().to_string(),
+            acnt_prdt_cd: account.product_code().to_string(),
+            inqr_strt_dt: range.start_str(),
+            inqr_end_dt: range.end_str(),
+            ctx_area_fk100: "".to_string(),
+            ctx_area_nk100: "".to_string(),
+        }
+    }
+
+    /// 이전 응답의 연속조회키로 다음 페이지 조회
+    pub fn with_continuation(mut self, key: &ContinuationKey) -> Self {
+        self.ctx_area_fk100 = key.fk100.clone();
+        self.ctx_area_nk100 = key.nk100.clone();
+        self
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 10] {
+        [
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("INQR_STRT_DT", self.inqr_strt_dt.clone()),
+            ("INQR_END_DT", self.inqr_end_dt.clone()),
+            ("SORT_DVSN", "00".to_string()), // 정렬구분(00: 최근순, 01: 과거순, 02: 최근순)
+            ("PDNO", "".to_string()),        // 상품번호(공란: 전체)
+            ("INQR_DVSN", "00".to_string()), // 조회구분(00)
+            ("CBLC_DVSN", "00".to_string()), // 잔고구분(00: 전체)
+            ("CTX_AREA_FK100", self.ctx_area_fk100.clone()),
+            ("CTX_AREA_NK100", self.ctx_area_nk100.clone()),
+        ]
+    }
+}
+
+/// 주식예약주문조회
+#[derive(Debug, Clone, Getters, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub struct ReserveOrderListParameter {
+    #[getset(get = "pub")]
+    cano: String, // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+    #[getset(get = "pub")]
+    acnt_prdt_cd: String, // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+    #[getset(get = "pub")]
+    rsvn_ord_ord_dt: String, // 예약주문시작일자(YYYYMMDD)
+    #[getset(get = "pub")]
+    rsvn_ord_end_dt: String, // 예약주문종료일자(YYYYMMDD)
+    #[getset(get = "pub")]
+    ctx_area_fk200: String, // 연속조회검색조건200
+    #[getset(get = "pub")]
+    ctx_area_nk200: String, // 연속조회키200
+}
+impl ReserveOrderListParameter {
+    pub fn new(account: &Account, range: &DateRange) -> Self {
+        Self {
+            cano: account.cano().to_string(),
+            acnt_prdt_cd: account.product_code().to_string(),
+            rsvn_ord_ord_dt: range.start_str(),
+            rsvn_ord_end_dt: range.end_str(),
+            ctx_area_fk200: "".to_string(),
+            ctx_area_nk200: "".to_string(),
+        }
+    }
+
+    /// 이전 응답의 연속조회키(CTX_AREA_FK200, CTX_AREA_NK200)로 다음 페이지 조회
+    pub fn with_continuation(mut self, key: &ContinuationKey) -> Self {
+        self.ctx_area_fk200 = key.fk100.clone();
+        self.ctx_area_nk200 = key.nk100.clone();
+        self
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 12] {
+        [
+            ("RSVN_ORD_ORD_DT", self.rsvn_ord_ord_dt.clone()),
+            ("RSVN_ORD_END_DT", self.rsvn_ord_end_dt.clone()),
+            ("RSVN_ORD_SEQ", "".to_string()), // 예약주문순번(공백: 전체)
+            ("TMNL_MDIA_KIND_CD", "00".to_string()), // 단말매체종류코드(00: 전체)
+            ("CANO", self.cano.clone()),
+            ("ACNT_PRDT_CD", self.acnt_prdt_cd.clone()),
+            ("PRCS_DVSN_CD", "0".to_string()), // 처리구분코드(0: 전체, 1: 처리내역, 2: 미처리내역)
+            ("CNCL_YN", "Y".to_string()),      // 취소여부(Y: 취소 내역 포함)
+            ("PDNO", "".to_string()),          // 상품번호(공백: 전체)
+            ("SLL_BUY_DVSN_CD", "".to_string()), // 매도매수구분코드(공백: 전체)
+            ("CTX_AREA_FK200", self.ctx_area_fk200.clone()),
+            ("CTX_AREA_NK200", self.ctx_area_nk200.clone()),
+        ]
+    }
+}
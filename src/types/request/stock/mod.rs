@@ -1,3 +1,4 @@
+pub mod account;
 pub mod order;
 pub mod quote;
 pub mod subscribe;
@@ -24,8 +24,12 @@ impl Header {
 }
 
 pub mod body {
-    use crate::types::{CorrectionClass, OrderClass, Price, Quantity};
-    use serde::{Deserialize, Serialize};
+    use crate::types::{CorrectionClass, Direction, Exchange, OrderClass, Price, Quantity};
+    use serde::{Deserialize, Serialize, Serializer};
+
+    fn serialize_yn<S: Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(if *value { "Y" } else { "N" })
+    }
 
     #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     #[serde(rename_all = "UPPERCASE")]
@@ -36,6 +40,8 @@ pub mod body {
         ord_dvsn: OrderClass, // 주문구분
         ord_qty: Quantity,    // 주문수량(주문주식수)
         ord_unpr: Price,      // 주문단가(1주당 가격; 시장가는 0으로)
+        #[serde(default)]
+        excg_id_dvsn_cd: Exchange, // 거래소ID구분코드(KRX, NXT, SOR)
     }
 
     impl Order {
@@ -54,8 +60,14 @@ pub mod body {
                 ord_dvsn,
                 ord_qty,
                 ord_unpr,
+                excg_id_dvsn_cd: Exchange::default(),
             }
         }
+        /// 주문을 보낼 거래소(기본 KRX)
+        pub fn with_exchange(mut self, exchange: Exchange) -> Self {
+            self.excg_id_dvsn_cd = exchange;
+            self
+        }
         pub fn get_json_string(self) -> String {
             serde_json::json!(self).to_string()
         }
@@ -74,9 +86,13 @@ pub mod body {
         rvse_cncl_dvsn_cd: CorrectionClass, // 정정취소구분코드
         ord_qty: Quantity,                  // 주문수량(주문주식수)
         ord_unpr: Price,                    // 주문단가([정정] 정정주문 1주당 가격, [취소] "0")
+        #[serde(serialize_with = "serialize_yn")]
         qty_all_ord_yn: bool, // 잔량전부주문여부([정정/취소] Y: 잔량전부, N: 잔량일부)
+        #[serde(default)]
+        excg_id_dvsn_cd: Exchange, // 거래소ID구분코드(KRX, NXT, SOR)
     }
     impl Correction {
+        #[allow(clippy::too_many_arguments)]
         pub fn new(
             cano: String,
             acnt_prdt_cd: String,
@@ -98,10 +114,75 @@ pub mod body {
                 ord_qty,
                 ord_unpr,
                 qty_all_ord_yn,
+                excg_id_dvsn_cd: Exchange::default(),
             }
         }
+        /// 정정/취소를 보낼 거래소(기본 KRX)
+        pub fn with_exchange(mut self, exchange: Exchange) -> Self {
+            self.excg_id_dvsn_cd = exchange;
+            self
+        }
         pub fn get_json_string(self) -> String {
             serde_json::json!(self).to_string()
         }
     }
+
+    /// 주식예약주문
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct ReserveOrder {
+        cano: String,                  // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+        acnt_prdt_cd: String,          // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+        pdno: String,                  // 종목코드(6자리)
+        ord_qty: Quantity,             // 주문수량
+        ord_unpr: Price,               // 주문단가(시장가는 0)
+        sll_buy_dvsn_cd: String,       // 매도매수구분코드(01: 매도, 02: 매수)
+        ord_dvsn_cd: String,           // 주문구분코드(00: 지정가, 01: 시장가)
+        ord_objt_cblc_dvsn_cd: String, // 주문대상잔고구분코드(10: 현금)
+        rsvn_ord_end_dt: String, // 예약주문종료일자(YYYYMMDD, 종료일까지 매 영업일 장 시작 시 주문)
+    }
+
+    impl ReserveOrder {
+        pub fn new(
+            cano: String,
+            acnt_prdt_cd: String,
+            direction: Direction,
+            pdno: String,
+            ord_qty: Quantity,
+            ord_unpr: Price,
+            rsvn_ord_end_dt: String,
+        ) -> Self {
+            let is_market = ord_unpr == Price::from(0);
+            Self {
+                cano,
+                acnt_prdt_cd,
+                pdno,
+                ord_qty,
+                ord_unpr,
+                sll_buy_dvsn_cd: direction.to_string(),
+                ord_dvsn_cd: if is_market { "01" } else { "00" }.to_string(),
+                ord_objt_cblc_dvsn_cd: "10".to_string(),
+                rsvn_ord_end_dt,
+            }
+        }
+    }
+
+    /// 주식예약주문정정취소(취소)
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct ReserveCancel {
+        cano: String,         // 종합계좌번호(계좌번호 체계(8-2)의 앞 8자리)
+        acnt_prdt_cd: String, // 계좌상품코드(계좌번호 체계(8-2)의 뒤 2자리)
+        rsvn_ord_seq: String, // 예약주문순번(예약주문 응답의 RSVN_ORD_SEQ)
+    }
+
+    impl ReserveCancel {
+        pub fn new(cano: String, acnt_prdt_cd: String, rsvn_ord_seq: String) -> Self {
+            Self {
+                cano,
+                acnt_prdt_cd,
+                rsvn_ord_seq,
+            }
+        }
+    }
 }
@@ -1,7 +1,9 @@
 use crate::types::{
-    BelongClassCode, CustomerType, MarketCode, PeriodCode, Price, ShareClassCode, TargetClassCode,
+    BelongClassCode, ChartPeriod, CustomerType, DailyPeriod, DateRange, IndexCode, ListedMarket,
+    MarketCode, MarketDivision, ParseCodeError, Price, ShareClassCode, TargetClassCode,
     TargetExeceptClassCode, TrId,
 };
+use crate::Error;
 use getset::{CopyGetters, Getters};
 use serde::{Deserialize, Serialize};
 
@@ -25,6 +27,92 @@ impl Header {
     }
 }
 
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct CurrentPriceParameter {
+    #[getset(get = "pub")]
+    fid_cond_mrkt_div_code: MarketCode, // FID 조건 시장 분류 코드
+    #[getset(get = "pub")]
+    fid_input_iscd: String, // FID 입력 종목코드
+}
+impl CurrentPriceParameter {
+    pub fn new(market_code: MarketCode, shortcode: String) -> Self {
+        Self {
+            fid_cond_mrkt_div_code: market_code,
+            fid_input_iscd: shortcode,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 2] {
+        [
+            (
+                "FID_COND_MRKT_DIV_CODE",
+                self.fid_cond_mrkt_div_code.division().to_string(),
+            ),
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Getters, CopyGetters, Serialize)]
+pub struct IndexPriceParameter {
+    #[getset(get = "pub")]
+    fid_cond_mrkt_div_code: String, // FID 조건 시장 분류 코드(U: 업종)
+    #[getset(get_copy = "pub")]
+    fid_input_iscd: IndexCode, // FID 입력 종목코드(업종코드)
+}
+impl IndexPriceParameter {
+    pub fn new(index: IndexCode) -> Self {
+        Self {
+            fid_cond_mrkt_div_code: MarketDivision::Sector.to_string(),
+            fid_input_iscd: index,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 2] {
+        [
+            (
+                "FID_COND_MRKT_DIV_CODE",
+                self.fid_cond_mrkt_div_code.clone(),
+            ),
+            ("FID_INPUT_ISCD", format!("{}", self.fid_input_iscd)),
+        ]
+    }
+}
+
+/// 국내업종 현재지수(업종코드 직접 입력)
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct SectorIndexParameter {
+    #[getset(get = "pub")]
+    fid_cond_mrkt_div_code: String, // FID 조건 시장 분류 코드(U: 업종)
+    #[getset(get = "pub")]
+    fid_input_iscd: String, // FID 입력 종목코드(업종코드 4자리)
+}
+impl SectorIndexParameter {
+    pub fn new(sector_code: &str) -> Result<Self, crate::Error> {
+        let sector_code = sector_code.trim();
+        if sector_code.len() != 4 || !sector_code.chars().all(|c| c.is_ascii_digit()) {
+            return Err(crate::Error::InvalidParam {
+                field: "sector_code",
+                reason: "must be 4 digits",
+            });
+        }
+        Ok(Self {
+            fid_cond_mrkt_div_code: MarketDivision::Sector.to_string(),
+            fid_input_iscd: sector_code.to_string(),
+        })
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 2] {
+        [
+            (
+                "FID_COND_MRKT_DIV_CODE",
+                self.fid_cond_mrkt_div_code.clone(),
+            ),
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+        ]
+    }
+}
+
 #[derive(Debug, Clone, Getters, CopyGetters, Serialize)]
 pub struct DailyPriceParameter {
     #[getset(get = "pub")]
@@ -32,7 +120,7 @@ pub struct DailyPriceParameter {
     #[getset(get = "pub")]
     fid_input_iscd: String, // FID 입력 종목코드
     #[getset(get = "pub")]
-    fid_period_div_code: PeriodCode, // FID 기간 분류 코드
+    fid_period_div_code: DailyPeriod, // FID 기간 분류 코드
     #[getset(get_copy = "pub")]
     fid_org_adj_prc: u8, // FID 수정주가 원주가 가격(수정주가 반영: 0, 수정주가 미반영: 1)
 }
@@ -40,7 +128,7 @@ impl DailyPriceParameter {
     pub fn new(
         market_code: MarketCode,
         shortcode: String,
-        period_code: PeriodCode,
+        period_code: DailyPeriod,
         is_adjust_price: bool,
     ) -> Self {
         Self {
@@ -58,7 +146,7 @@ impl DailyPriceParameter {
         [
             (
                 "FID_COND_MRKT_DIV_CODE",
-                format!("{}", self.fid_cond_mrkt_div_code),
+                self.fid_cond_mrkt_div_code.division().to_string(),
             ),
             ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
             (
@@ -81,7 +169,7 @@ pub struct PeriodicPriceParameter {
     #[getset(get = "pub")]
     fid_input_date_2: String, // 조회 종료일자 YYYYMMDD
     #[getset(get = "pub")]
-    fid_period_div_code: PeriodCode, // FID 기간 분류 코드
+    fid_period_div_code: ChartPeriod, // FID 기간 분류 코드
     #[getset(get = "pub")]
     fid_org_adj_prc: u8, // FID 수정주가 원주가 가격(수정주가 반영: 0, 수정주가 미반영: 1)
 }
@@ -92,7 +180,7 @@ impl PeriodicPriceParameter {
         shortcode: String,
         start_date: String,
         end_date: String,
-        period_code: PeriodCode,
+        period_code: ChartPeriod,
         is_adjust_price: bool,
     ) -> Self {
         Self {
@@ -112,7 +200,7 @@ impl PeriodicPriceParameter {
         [
             (
                 "FID_COND_MRKT_DIV_CODE",
-                format!("{}", self.fid_cond_mrkt_div_code),
+                self.fid_cond_mrkt_div_code.division().to_string(),
             ),
             ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
             ("FID_INPUT_DATE_1", self.fid_input_date_1.clone()),
@@ -126,6 +214,68 @@ impl PeriodicPriceParameter {
     }
 }
 
+#[derive(Debug, Clone, Getters, CopyGetters, Serialize)]
+pub struct MinuteChartParameter {
+    #[getset(get = "pub")]
+    fid_cond_mrkt_div_code: MarketCode, // FID 조건 시장 분류 코드
+    #[getset(get = "pub")]
+    fid_input_iscd: String, // FID 입력 종목코드
+    #[getset(get = "pub")]
+    fid_input_hour_1: String, // FID 입력 시간1(HHMMSS, 이 시각 이전 30건 조회)
+    #[getset(get_copy = "pub")]
+    fid_pw_data_incu_yn: bool, // FID 과거 데이터 포함 여부
+}
+impl MinuteChartParameter {
+    pub fn new(
+        market_code: MarketCode,
+        shortcode: String,
+        time: String,
+        include_past: bool,
+    ) -> Self {
+        Self {
+            fid_cond_mrkt_div_code: market_code,
+            fid_input_iscd: shortcode,
+            fid_input_hour_1: time,
+            fid_pw_data_incu_yn: include_past,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 5] {
+        [
+            ("FID_ETC_CLS_CODE", "".to_string()),
+            (
+                "FID_COND_MRKT_DIV_CODE",
+                self.fid_cond_mrkt_div_code.division().to_string(),
+            ),
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+            ("FID_INPUT_HOUR_1", self.fid_input_hour_1.clone()),
+            (
+                "FID_PW_DATA_INCU_YN",
+                if self.fid_pw_data_incu_yn { "Y" } else { "N" }.to_string(),
+            ),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct HolidayParameter {
+    #[getset(get = "pub")]
+    bass_dt: String, // 기준일자(YYYYMMDD, 이 날짜부터 조회)
+}
+impl HolidayParameter {
+    pub fn new(bass_dt: String) -> Self {
+        Self { bass_dt }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 3] {
+        [
+            ("BASS_DT", self.bass_dt.clone()),
+            ("CTX_AREA_NK", "".to_string()),
+            ("CTX_AREA_FK", "".to_string()),
+        ]
+    }
+}
+
 #[derive(Debug, Clone, Getters, CopyGetters, Serialize)]
 pub struct VolumeRankParameter {
     #[getset(get = "pub")]
@@ -155,6 +305,7 @@ pub struct VolumeRankParameter {
 }
 
 impl VolumeRankParameter {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         fid_input_iscd: String,           // 입력 종목코드(전체: 0000, 기타: 업종코드)
         fid_div_cls_code: ShareClassCode, // 분류구분코드(0: 전체, 1: 보통주, 2: 우선주)
@@ -180,17 +331,19 @@ impl VolumeRankParameter {
         }
     }
 
+    /// 기본값으로 채워진 builder(전체 종목, 평균거래량순, 가격/거래량 조건 없음)
+    pub fn builder() -> VolumeRankParameterBuilder {
+        VolumeRankParameterBuilder::default()
+    }
+
     pub fn into_iter(&self) -> [(&'static str, String); 11] {
         [
             (
                 "FID_COND_MRKT_DIV_CODE",
-                format!("{}", self.fid_cond_mrkt_div_code),
+                self.fid_cond_mrkt_div_code.division().to_string(),
             ),
-            (
-                "FID_COND_SCR_DIV_CODE",
-                format!("{}", self.fid_cond_scr_div_code),
-            ),
-            ("FID_INPUT_ISCD", format!("{}", self.fid_input_iscd)),
+            ("FID_COND_SCR_DIV_CODE", self.fid_cond_scr_div_code.clone()),
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
             ("FID_DIV_CLS_CODE", format!("{}", self.fid_div_cls_code)),
             ("FID_BLNG_CLS_CODE", format!("{}", self.fid_blng_cls_code)),
             ("FID_TRGT_CLS_CODE", format!("{}", self.fid_trgt_cls_code)),
@@ -222,7 +375,576 @@ impl VolumeRankParameter {
                     "".to_string()
                 },
             ),
-            ("FID_INPUT_DATE_1", format!("{}", self.fid_input_date_1)),
+            ("FID_INPUT_DATE_1", self.fid_input_date_1.clone()),
+        ]
+    }
+}
+
+/// VolumeRankParameter builder
+/// 설정하지 않은 FID는 KIS 기본값(전체/공란)으로 요청
+#[derive(Debug, Clone)]
+pub struct VolumeRankParameterBuilder {
+    market_code: MarketCode,
+    iscd: String,
+    share_class: ShareClassCode,
+    sort: BelongClassCode,
+    target: TargetClassCode,
+    exclude: TargetExeceptClassCode,
+    min_price: Option<Price>,
+    max_price: Option<Price>,
+    min_volume: Option<u32>,
+}
+
+impl Default for VolumeRankParameterBuilder {
+    fn default() -> Self {
+        Self {
+            market_code: MarketCode::Stock,
+            iscd: "0000".to_string(),
+            share_class: ShareClassCode::Whole,
+            sort: BelongClassCode::MeanVolume,
+            target: TargetClassCode::default(),
+            exclude: TargetExeceptClassCode::default(),
+            min_price: None,
+            max_price: None,
+            min_volume: None,
+        }
+    }
+}
+
+impl VolumeRankParameterBuilder {
+    /// 조건 시장 분류 코드(기본값: J)
+    pub fn market(mut self, market_code: MarketCode) -> Self {
+        self.market_code = market_code;
+        self
+    }
+
+    /// 입력 종목코드(기본값: 0000(전체), 기타: 업종코드)
+    pub fn sector(mut self, iscd: &str) -> Self {
+        self.iscd = iscd.to_string();
+        self
+    }
+
+    /// 분류구분코드(기본값: 전체)
+    pub fn share_class(mut self, share_class: ShareClassCode) -> Self {
+        self.share_class = share_class;
+        self
+    }
+
+    /// 정렬 기준(소속 구분 코드, 기본값: 평균거래량)
+    pub fn sort(mut self, sort: BelongClassCode) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    /// 대상구분코드(기본값: 전체 포함)
+    pub fn target(mut self, target: TargetClassCode) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// 대상제외구분코드(기본값: 제외 없음)
+    pub fn exclude(mut self, exclude: TargetExeceptClassCode) -> Self {
+        self.exclude = exclude;
+        self
+    }
+
+    /// 최소 가격
+    pub fn min_price(mut self, price: Price) -> Self {
+        self.min_price = Some(price);
+        self
+    }
+
+    /// 최대 가격
+    pub fn max_price(mut self, price: Price) -> Self {
+        self.max_price = Some(price);
+        self
+    }
+
+    /// 최소 거래량
+    pub fn min_volume(mut self, volume: u32) -> Self {
+        self.min_volume = Some(volume);
+        self
+    }
+
+    /// 서로 모순되는 조건이 있으면 Error::InvalidParam
+    pub fn build(self) -> Result<VolumeRankParameter, Error> {
+        if let (Some(min), Some(max)) = (self.min_price, self.max_price) {
+            if min.inner > max.inner {
+                return Err(Error::InvalidParam {
+                    field: "min_price",
+                    reason: "must not exceed max_price",
+                });
+            }
+        }
+        if matches!(self.share_class, ShareClassCode::Preferred) && self.exclude.preferred_share {
+            return Err(Error::InvalidParam {
+                field: "share_class",
+                reason: "Preferred conflicts with excluding preferred shares",
+            });
+        }
+        let mut param = VolumeRankParameter::new(
+            self.iscd,
+            self.share_class,
+            self.sort,
+            self.target,
+            self.exclude,
+            self.min_price,
+            self.max_price,
+            self.min_volume,
+        );
+        param.fid_cond_mrkt_div_code = self.market_code;
+        Ok(param)
+    }
+}
+
+/// ELW 기초자산별 종목시세
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct ElwUnderlyingParameter {
+    #[getset(get = "pub")]
+    fid_unas_input_iscd: String, // 기초자산 종목코드(예: 005930, 코스피200은 2001)
+}
+impl ElwUnderlyingParameter {
+    pub fn new(underlying: String) -> Self {
+        Self {
+            fid_unas_input_iscd: underlying,
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 16] {
+        [
+            ("FID_COND_MRKT_DIV_CODE", MarketDivision::Elw.to_string()), // 조건시장분류코드(W: ELW)
+            ("FID_COND_SCR_DIV_CODE", "11541".to_string()),              // 조건화면분류코드
+            ("FID_MRKT_CLS_CODE", "A".to_string()), // 시장구분코드(A: 전체, C: 콜, P: 풋)
+            ("FID_INPUT_ISCD", "00000".to_string()), // 입력종목코드(00000: 전체)
+            ("FID_UNAS_INPUT_ISCD", self.fid_unas_input_iscd.clone()),
+            ("FID_VOL_CNT", "".to_string()),             // 거래량수
+            ("FID_TRGT_EXLS_CLS_CODE", "0".to_string()), // 대상제외구분코드(0: 전체)
+            ("FID_INPUT_PRICE_1", "".to_string()),       // 가격(이상)
+            ("FID_INPUT_PRICE_2", "".to_string()),       // 가격(이하)
+            ("FID_INPUT_VOL_1", "".to_string()),         // 거래량(이상)
+            ("FID_INPUT_VOL_2", "".to_string()),         // 거래량(이하)
+            ("FID_INPUT_RMNN_DYNU_1", "".to_string()),   // 잔존일(이상)
+            ("FID_INPUT_RMNN_DYNU_2", "".to_string()),   // 잔존일(이하)
+            ("FID_OPTION", "0".to_string()),             // 옵션(0: 없음, 1: ATM, 2: ITM, 3: OTM)
+            ("FID_INPUT_OPTN_1", "".to_string()),        // 입력옵션1
+            ("FID_INPUT_OPTN_2", "".to_string()),        // 입력옵션2
+        ]
+    }
+}
+
+/// 주식기본조회
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct StockBasicInfoParameter {
+    #[getset(get = "pub")]
+    pdno: String, // 종목번호(6자리)
+}
+impl StockBasicInfoParameter {
+    pub fn new(pdno: String) -> Self {
+        Self { pdno }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 2] {
+        [
+            ("PRDT_TYPE_CD", "300".to_string()), // 상품유형코드(300: 주식, ETF, ETN, ELW)
+            ("PDNO", self.pdno.clone()),
+        ]
+    }
+}
+
+/// 등락률 순위 정렬(FID_RANK_SORT_CLS_CODE)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FluctuationSort {
+    Rise, // 상승율순(0)
+    Fall, // 하락율순(1)
+}
+
+/// 등락률 순위(전일 종가 대비)
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct FluctuationRankParameter {
+    #[getset(get = "pub")]
+    fid_cond_mrkt_div_code: MarketCode, // 조건 시장 분류 코드(J)
+    #[getset(get = "pub")]
+    fid_input_iscd: String, // 입력 종목코드(0000: 전체, 0001: 코스피, 1001: 코스닥)
+    #[getset(get = "pub")]
+    fid_rank_sort_cls_code: String, // 순위 정렬 구분 코드(0: 상승율순, 1: 하락율순)
+}
+impl FluctuationRankParameter {
+    pub fn new(market_code: MarketCode, sort: FluctuationSort) -> Self {
+        let sort = match sort {
+            FluctuationSort::Rise => "0",
+            FluctuationSort::Fall => "1",
+        };
+        Self {
+            fid_cond_mrkt_div_code: market_code,
+            fid_input_iscd: "0000".to_string(),
+            fid_rank_sort_cls_code: sort.to_string(),
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 14] {
+        [
+            (
+                "FID_COND_MRKT_DIV_CODE",
+                self.fid_cond_mrkt_div_code.division().to_string(),
+            ),
+            ("FID_COND_SCR_DIV_CODE", "20170".to_string()), // 조건 화면 분류 코드
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+            (
+                "FID_RANK_SORT_CLS_CODE",
+                self.fid_rank_sort_cls_code.clone(),
+            ),
+            ("FID_INPUT_CNT_1", "0".to_string()), // 누적일수(0: 전체)
+            ("FID_PRC_CLS_CODE", "1".to_string()), // 가격구분(1: 종가대비)
+            ("FID_INPUT_PRICE_1", "".to_string()),
+            ("FID_INPUT_PRICE_2", "".to_string()),
+            ("FID_VOL_CNT", "".to_string()),
+            ("FID_TRGT_CLS_CODE", "0".to_string()),
+            ("FID_TRGT_EXLS_CLS_CODE", "0".to_string()),
+            ("FID_DIV_CLS_CODE", "0".to_string()), // 분류구분(0: 전체)
+            ("FID_RSFL_RATE1", "".to_string()),    // 등락비율1
+            ("FID_RSFL_RATE2", "".to_string()),    // 등락비율2
+        ]
+    }
+}
+
+/// 순위 조회 종류(Ranking)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RankingKind {
+    Rise,         // 상승율 순위(FHPST01700000)
+    Fall,         // 하락율 순위(FHPST01700000)
+    MarketCap,    // 시가총액 상위(FHPST01740000)
+    TradingValue, // 거래대금 순위(거래량순위 FHPST01710000, 거래금액순)
+}
+
+impl RankingKind {
+    pub fn tr_id(&self) -> TrId {
+        match self {
+            Self::Rise | Self::Fall => TrId::FluctuationRank,
+            Self::MarketCap => TrId::MarketCapRank,
+            Self::TradingValue => TrId::VolumeRank,
+        }
+    }
+
+    pub fn path(&self) -> &'static str {
+        match self {
+            Self::Rise | Self::Fall => "/uapi/domestic-stock/v1/ranking/fluctuation",
+            Self::MarketCap => "/uapi/domestic-stock/v1/ranking/market-cap",
+            Self::TradingValue => "/uapi/domestic-stock/v1/quotations/volume-rank",
+        }
+    }
+}
+
+/// 설정 파일 등에서 사용하는 이름(rise, fall, market_cap, trading_value)
+impl std::fmt::Display for RankingKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Rise => "rise",
+            Self::Fall => "fall",
+            Self::MarketCap => "market_cap",
+            Self::TradingValue => "trading_value",
+        })
+    }
+}
+impl std::str::FromStr for RankingKind {
+    type Err = ParseCodeError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.trim().to_ascii_lowercase().as_str() {
+            "rise" => Self::Rise,
+            "fall" => Self::Fall,
+            "market_cap" => Self::MarketCap,
+            "trading_value" => Self::TradingValue,
+            _ => return Err(ParseCodeError::new("RankingKind", s)),
+        })
+    }
+}
+
+/// 순위 조회 공통 조건
+/// 조회 종류마다 FID 구성이 달라 into_iter에서 kind에 맞게 변환하며, 해당 조회에 없는 조건은 무시됨
+#[derive(Debug, Clone, Getters, CopyGetters, Serialize)]
+pub struct RankingParameter {
+    #[getset(get = "pub")]
+    market_code: MarketCode, // 조건 시장 분류 코드(J)
+    #[getset(get = "pub")]
+    iscd: String, // 입력 종목코드(0000: 전체, 0001: 코스피, 1001: 코스닥)
+    #[getset(get = "pub")]
+    share_class: ShareClassCode, // 분류구분코드(시가총액, 거래대금)
+    #[getset(get_copy = "pub")]
+    min_price: Option<Price>, // 입력 가격1(최소)
+    #[getset(get_copy = "pub")]
+    max_price: Option<Price>, // 입력 가격2(최대)
+    #[getset(get_copy = "pub")]
+    min_volume: Option<u32>, // 거래량 수
+}
+
+impl RankingParameter {
+    /// 기본값으로 채워진 builder(주식 전체 종목, 가격/거래량 조건 없음)
+    pub fn builder() -> RankingParameterBuilder {
+        RankingParameterBuilder::default()
+    }
+
+    pub fn into_iter(&self, kind: RankingKind) -> Vec<(&'static str, String)> {
+        let price = |price: Option<Price>| price.map(|p| p.inner.to_string()).unwrap_or_default();
+        let volume = self.min_volume.map(|v| v.to_string()).unwrap_or_default();
+        match kind {
+            RankingKind::Rise | RankingKind::Fall => vec![
+                (
+                    "FID_COND_MRKT_DIV_CODE",
+                    self.market_code.division().to_string(),
+                ),
+                ("FID_COND_SCR_DIV_CODE", "20170".to_string()), // 조건 화면 분류 코드
+                ("FID_INPUT_ISCD", self.iscd.clone()),
+                (
+                    "FID_RANK_SORT_CLS_CODE",
+                    if kind == RankingKind::Rise { "0" } else { "1" }.to_string(),
+                ),
+                ("FID_INPUT_CNT_1", "0".to_string()), // 누적일수(0: 전체)
+                ("FID_PRC_CLS_CODE", "1".to_string()), // 가격구분(1: 종가대비)
+                ("FID_INPUT_PRICE_1", price(self.min_price)),
+                ("FID_INPUT_PRICE_2", price(self.max_price)),
+                ("FID_VOL_CNT", volume),
+                ("FID_TRGT_CLS_CODE", "0".to_string()),
+                ("FID_TRGT_EXLS_CLS_CODE", "0".to_string()),
+                ("FID_DIV_CLS_CODE", "0".to_string()), // 분류구분(0: 전체)
+                ("FID_RSFL_RATE1", "".to_string()),    // 등락비율1
+                ("FID_RSFL_RATE2", "".to_string()),    // 등락비율2
+            ],
+            RankingKind::MarketCap => vec![
+                ("FID_INPUT_PRICE_2", price(self.max_price)),
+                (
+                    "FID_COND_MRKT_DIV_CODE",
+                    self.market_code.division().to_string(),
+                ),
+                ("FID_COND_SCR_DIV_CODE", "20174".to_string()), // 조건 화면 분류 코드
+                ("FID_DIV_CLS_CODE", format!("{}", self.share_class)),
+                ("FID_INPUT_ISCD", self.iscd.clone()),
+                ("FID_TRGT_CLS_CODE", "0".to_string()),
+                ("FID_TRGT_EXLS_CLS_CODE", "0".to_string()),
+                ("FID_INPUT_PRICE_1", price(self.min_price)),
+                ("FID_VOL_CNT", volume),
+            ],
+            RankingKind::TradingValue => {
+                let mut param = VolumeRankParameter::new(
+                    self.iscd.clone(),
+                    self.share_class.clone(),
+                    BelongClassCode::Amount,
+                    TargetClassCode::default(),
+                    TargetExeceptClassCode::default(),
+                    self.min_price,
+                    self.max_price,
+                    self.min_volume,
+                );
+                param.fid_cond_mrkt_div_code = self.market_code.clone();
+                param.into_iter().to_vec()
+            }
+        }
+    }
+}
+
+/// RankingParameter builder
+#[derive(Debug, Clone)]
+pub struct RankingParameterBuilder {
+    market_code: MarketCode,
+    iscd: String,
+    share_class: ShareClassCode,
+    min_price: Option<Price>,
+    max_price: Option<Price>,
+    min_volume: Option<u32>,
+}
+
+impl Default for RankingParameterBuilder {
+    fn default() -> Self {
+        Self {
+            market_code: MarketCode::Stock,
+            iscd: "0000".to_string(),
+            share_class: ShareClassCode::Whole,
+            min_price: None,
+            max_price: None,
+            min_volume: None,
+        }
+    }
+}
+
+impl RankingParameterBuilder {
+    /// 조건 시장 분류 코드(기본값: J)
+    pub fn market(mut self, market_code: MarketCode) -> Self {
+        self.market_code = market_code;
+        self
+    }
+
+    /// 입력 종목코드(기본값: 0000(전체), 0001: 코스피, 1001: 코스닥, 기타: 업종코드)
+    pub fn sector(mut self, iscd: &str) -> Self {
+        self.iscd = iscd.to_string();
+        self
+    }
+
+    /// 분류구분코드(기본값: 전체). 등락률 순위에서는 무시
+    pub fn share_class(mut self, share_class: ShareClassCode) -> Self {
+        self.share_class = share_class;
+        self
+    }
+
+    /// 최소 가격
+    pub fn min_price(mut self, price: Price) -> Self {
+        self.min_price = Some(price);
+        self
+    }
+
+    /// 최대 가격
+    pub fn max_price(mut self, price: Price) -> Self {
+        self.max_price = Some(price);
+        self
+    }
+
+    /// 최소 거래량
+    pub fn min_volume(mut self, volume: u32) -> Self {
+        self.min_volume = Some(volume);
+        self
+    }
+
+    /// min_price가 max_price보다 크면 Error::InvalidParam
+    pub fn build(self) -> Result<RankingParameter, Error> {
+        if let (Some(min), Some(max)) = (self.min_price, self.max_price) {
+            if min.inner > max.inner {
+                return Err(Error::InvalidParam {
+                    field: "min_price",
+                    reason: "must not exceed max_price",
+                });
+            }
+        }
+        Ok(RankingParameter {
+            market_code: self.market_code,
+            iscd: self.iscd,
+            share_class: self.share_class,
+            min_price: self.min_price,
+            max_price: self.max_price,
+            min_volume: self.min_volume,
+        })
+    }
+}
+
+/// 주식현재가 시간대별체결
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct TimeConclusionParameter {
+    #[getset(get = "pub")]
+    fid_input_iscd: String, // 입력 종목코드
+    #[getset(get = "pub")]
+    fid_input_hour_1: String, // 입력 시간(HHMMSS, 이 시각 이전 체결부터 조회)
+}
+impl TimeConclusionParameter {
+    pub fn new(shortcode: &str, hour: &str) -> Self {
+        Self {
+            fid_input_iscd: shortcode.to_string(),
+            fid_input_hour_1: hour.to_string(),
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 3] {
+        [
+            ("FID_COND_MRKT_DIV_CODE", MarketDivision::Krx.to_string()), // 조건시장분류코드
+            ("FID_INPUT_ISCD", self.fid_input_iscd.clone()),
+            ("FID_INPUT_HOUR_1", self.fid_input_hour_1.clone()),
         ]
     }
 }
+
+/// 프로그램매매 종합현황(시간)
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct ProgramTradeParameter {
+    #[getset(get = "pub")]
+    fid_mrkt_cls_code: String, // 시장구분코드(K: 코스피, Q: 코스닥)
+}
+impl ProgramTradeParameter {
+    pub fn new(market: ListedMarket) -> Result<Self, Error> {
+        let fid_mrkt_cls_code = match market {
+            ListedMarket::Kospi => "K",
+            ListedMarket::Kosdaq => "Q",
+            _ => {
+                return Err(Error::InvalidParam {
+                    field: "market",
+                    reason: "program trading is only available for Kospi and Kosdaq",
+                })
+            }
+        };
+        Ok(Self {
+            fid_mrkt_cls_code: fid_mrkt_cls_code.to_string(),
+        })
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 6] {
+        [
+            ("FID_COND_MRKT_DIV_CODE", MarketDivision::Krx.to_string()), // 조건시장분류코드
+            ("FID_MRKT_CLS_CODE", self.fid_mrkt_cls_code.clone()),
+            ("FID_SCTN_CLS_CODE", "".to_string()), // 구간구분코드
+            ("FID_INPUT_ISCD", "".to_string()),    // 입력종목코드
+            ("FID_COND_MRKT_DIV_CODE1", "".to_string()),
+            ("FID_INPUT_HOUR_1", "".to_string()), // 입력시간(공백: 현재 시각까지)
+        ]
+    }
+}
+
+/// 예탁원정보(배당일정)
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct DividendScheduleParameter {
+    #[getset(get = "pub")]
+    sht_cd: String, // 종목코드(공백: 전체)
+    #[getset(get = "pub")]
+    f_dt: String, // 조회일자From(YYYYMMDD)
+    #[getset(get = "pub")]
+    t_dt: String, // 조회일자To(YYYYMMDD)
+}
+impl DividendScheduleParameter {
+    pub fn new(sht_cd: String, range: &DateRange) -> Self {
+        Self {
+            sht_cd,
+            f_dt: range.start_str(),
+            t_dt: range.end_str(),
+        }
+    }
+
+    pub fn into_iter(&self) -> [(&'static str, String); 6] {
+        [
+            ("CTS", "".to_string()),  // 연속조회키
+            ("GB1", "0".to_string()), // 조회구분(0: 배당전체, 1: 결산배당, 2: 중간배당)
+            ("F_DT", self.f_dt.clone()),
+            ("T_DT", self.t_dt.clone()),
+            ("SHT_CD", self.sht_cd.clone()),
+            ("HIGH_GB", "".to_string()), // 고배당여부(공백: 전체)
+        ]
+    }
+}
+
+/// 관심종목(멀티종목) 시세조회 한 번에 조회할 수 있는 종목 수
+pub const WATCHLIST_MAX_SYMBOLS: usize = 30;
+
+/// 관심종목(멀티종목) 시세조회
+#[derive(Debug, Clone, Getters, Serialize)]
+pub struct WatchlistPriceParameter {
+    #[getset(get = "pub")]
+    codes: Vec<String>, // 종목코드(최대 30개)
+}
+impl WatchlistPriceParameter {
+    /// 종목이 30개를 넘으면 Error::TooManySymbols
+    pub fn new(codes: Vec<String>) -> Result<Self, Error> {
+        if codes.len() > WATCHLIST_MAX_SYMBOLS {
+            return Err(Error::TooManySymbols(codes.len(), WATCHLIST_MAX_SYMBOLS));
+        }
+        Ok(Self { codes })
+    }
+
+    /// 종목마다 FID_COND_MRKT_DIV_CODE_n(J: 주식), FID_INPUT_ISCD_n
+    pub fn into_iter(&self) -> Vec<(String, String)> {
+        self.codes
+            .iter()
+            .enumerate()
+            .flat_map(|(i, code)| {
+                [
+                    (
+                        format!("FID_COND_MRKT_DIV_CODE_{}", i + 1),
+                        MarketDivision::Krx.to_string(),
+                    ),
+                    (format!("FID_INPUT_ISCD_{}", i + 1), code.clone()),
+                ]
+            })
+            .collect()
+    }
+}
@@ -64,6 +64,12 @@ impl SubscribeRequest {
         }
     }
 
+    /// 등록(1) / 해제(2) 구분 설정
+    pub fn with_tr_type(mut self, tr_type: TrType) -> Self {
+        self.header.tr_type = tr_type;
+        self
+    }
+
     pub fn header(&self) -> &Header {
         &self.header
     }
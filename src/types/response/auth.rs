@@ -36,6 +36,16 @@ pub mod Body {
         pub fn get_access_token(&self) -> String {
             self.access_token.clone()
         }
+        pub fn get_expires_in(&self) -> u32 {
+            self.expires_in
+        }
+    }
+
+    /// 인증 API 실패 응답(접근토큰발급 등)
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct OAuthError {
+        pub error_code: String,        // 에러 코드(EGW00133 등)
+        pub error_description: String, // 에러 메시지
     }
 
     /// 접근토큰폐기(P)
@@ -0,0 +1,41 @@
+use crate::types::response::RawBody;
+use getset::Getters;
+use serde::Deserialize;
+
+/// 장내채권 매수/매도주문 응답
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct BondOrderResponse {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[getset(get = "pub")]
+    output: Option<output::Order>, // 응답 상세
+}
+
+impl RawBody for BondOrderResponse {}
+
+impl BondOrderResponse {
+    /// 주문번호(ODNO)
+    pub fn order_no(&self) -> Option<&str> {
+        self.output.as_ref().map(|output| output.odno().as_str())
+    }
+}
+
+pub mod output {
+    use getset::Getters;
+    use serde::Deserialize;
+
+    #[derive(Clone, Debug, Deserialize, Getters)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct Order {
+        #[getset(get = "pub")]
+        krx_fwdg_ord_orgno: String, // 한국거래소전송주문조직번호
+        #[getset(get = "pub")]
+        odno: String, // 주문번호
+        #[getset(get = "pub")]
+        ord_tmd: String, // 주문시각
+    }
+}
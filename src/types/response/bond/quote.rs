@@ -0,0 +1,146 @@
+use crate::types::response::RawBody;
+use getset::Getters;
+use serde::Deserialize;
+
+/// 장내채권현재가(시세) 응답
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct BondPriceResponse {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[getset(get = "pub")]
+    output: Option<output::BondPrice>, // 응답 상세
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl RawBody for BondPriceResponse {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
+
+/// 장내채권 기본조회 응답
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct BondBasicInfoResponse {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[getset(get = "pub")]
+    output: Option<output::BondBasicInfo>, // 응답 상세
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl RawBody for BondBasicInfoResponse {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
+
+pub mod output {
+    use crate::types::de::{de_decimal_from_str, de_i64_from_str, parse_yyyymmdd};
+    use crate::types::VsPriceSign;
+    use getset::{CopyGetters, Getters};
+    use rust_decimal::Decimal;
+    use serde::Deserialize;
+
+    /// 장내채권 현재가
+    /// 채권 가격(액면 10,000원 기준)과 수익률은 소수점이 있어 Decimal로 역직렬화
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct BondPrice {
+        #[serde(default)]
+        #[getset(get = "pub")]
+        stnd_iscd: String, // 표준종목코드
+        #[serde(default)]
+        #[getset(get = "pub")]
+        hts_kor_isnm: String, // HTS 한글 종목명
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        bond_prpr: Option<Decimal>, // 채권 현재가
+        #[getset(get = "pub")]
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        bond_prdy_vrss: Option<Decimal>, // 채권 전일 대비
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_ctrt: Option<Decimal>, // 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        bond_prdy_clpr: Option<Decimal>, // 채권 전일 종가
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        bond_oprc: Option<Decimal>, // 채권 시가
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        bond_hgpr: Option<Decimal>, // 채권 고가
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        bond_lwpr: Option<Decimal>, // 채권 저가
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        ernn_rate: Option<Decimal>, // 수익률(%)
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        oprc_ert: Option<Decimal>, // 시가 수익률(%)
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        hgpr_ert: Option<Decimal>, // 고가 수익률(%)
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        lwpr_ert: Option<Decimal>, // 저가 수익률(%)
+    }
+
+    /// 장내채권 기본 정보
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct BondBasicInfo {
+        #[getset(get = "pub")]
+        pdno: String, // 상품번호
+        #[serde(default)]
+        #[getset(get = "pub")]
+        prdt_name: String, // 상품명
+        #[serde(default)]
+        #[getset(get = "pub")]
+        issu_dt: String, // 발행일자(YYYYMMDD)
+        #[serde(default)]
+        #[getset(get = "pub")]
+        expd_dt: String, // 만기일자(YYYYMMDD)
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        srfc_inrt: Option<Decimal>, // 표면이율(%)
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        papr: Option<Decimal>, // 액면가
+        #[serde(default)]
+        #[getset(get = "pub")]
+        int_pay_cycl_ctt: String, // 이자지급주기내용
+    }
+
+    impl BondBasicInfo {
+        /// 발행일
+        pub fn issue_date(&self) -> Option<chrono::NaiveDate> {
+            parse_yyyymmdd(&self.issu_dt)
+        }
+
+        /// 만기일
+        pub fn maturity_date(&self) -> Option<chrono::NaiveDate> {
+            parse_yyyymmdd(&self.expd_dt)
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::types::response::RawBody;
+use getset::Getters;
+use serde::Deserialize;
+
+/// 선물옵션 주문 응답
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct FuturesOrderResponse {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[getset(get = "pub")]
+    output: Option<output::Order>, // 응답 상세
+}
+
+impl RawBody for FuturesOrderResponse {}
+
+impl FuturesOrderResponse {
+    /// 주문번호(ODNO)
+    pub fn order_no(&self) -> Option<&str> {
+        self.output.as_ref().map(|output| output.odno().as_str())
+    }
+}
+
+pub mod output {
+    use getset::Getters;
+    use serde::Deserialize;
+
+    #[derive(Clone, Debug, Deserialize, Getters)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct Order {
+        #[getset(get = "pub")]
+        acnt_name: String, // 계좌명
+        #[getset(get = "pub")]
+        trad_dvsn_name: String, // 매매구분명
+        #[getset(get = "pub")]
+        item_name: String, // 종목명
+        #[getset(get = "pub")]
+        ord_tmd: String, // 주문시각
+        #[getset(get = "pub")]
+        ord_gno_brno: String, // 주문채번지점번호
+        #[getset(get = "pub")]
+        odno: String, // 주문번호
+    }
+}
@@ -0,0 +1,96 @@
+use crate::types::response::RawBody;
+use getset::Getters;
+use serde::Deserialize;
+
+/// 선물옵션 시세 응답
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct FuturesPriceResponse {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[getset(get = "pub")]
+    output1: Option<output::FuturesPrice>, // 응답 상세
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl RawBody for FuturesPriceResponse {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
+
+pub mod output {
+    use crate::types::de::{de_f64_from_str, de_i64_from_str};
+    use crate::types::VsPriceSign;
+    use getset::{CopyGetters, Getters};
+    use serde::Deserialize;
+
+    /// 선물옵션 현재가
+    /// 지수선물 가격은 소수점(0.05pt 단위)이 있어 f64로 역직렬화
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct FuturesPrice {
+        #[getset(get = "pub")]
+        hts_kor_isnm: String, // HTS 한글 종목명
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        futs_prpr: Option<f64>, // 선물 현재가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        futs_prdy_vrss: Option<f64>, // 선물 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        futs_prdy_ctrt: Option<f64>, // 선물 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_tr_pbmn: Option<i64>, // 누적 거래 대금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        hts_otst_stpl_qty: Option<i64>, // HTS 미결제 약정 수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        otst_stpl_qty_icdc: Option<i64>, // 미결제 약정 수량 증감
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        futs_oprc: Option<f64>, // 선물 시가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        futs_hgpr: Option<f64>, // 선물 최고가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        futs_lwpr: Option<f64>, // 선물 최저가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        futs_mxpr: Option<f64>, // 선물 상한가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        futs_llam: Option<f64>, // 선물 하한가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        basis: Option<f64>, // 베이시스
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        futs_sdpr: Option<f64>, // 선물 기준가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        hts_thpr: Option<f64>, // HTS 이론가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        dprt: Option<f64>, // 괴리율
+        #[getset(get = "pub")]
+        hts_rmnn_dynu: String, // HTS 잔존 일수
+        #[getset(get = "pub")]
+        futs_last_tr_date: String, // 선물 최종 거래 일자
+    }
+}
@@ -1,2 +1,20 @@
 pub mod auth;
+#[cfg(feature = "decimal")]
+pub mod bond;
+pub mod derivatives;
+#[cfg(feature = "decimal")]
+pub mod overseas;
 pub mod stock;
+
+/// 응답 원문 보관(raw-response feature)
+pub(crate) trait RawBody {
+    fn set_raw(&mut self, _raw: String) {}
+}
+
+/// 타입이 정의되지 않은 응답(raw_get)
+impl RawBody for serde_json::Value {}
+
+/// 연속조회키(CTX_AREA_FK100, CTX_AREA_NK100)를 돌려주는 응답
+pub(crate) trait Continuation {
+    fn continuation_key(&self) -> crate::paging::ContinuationKey;
+}
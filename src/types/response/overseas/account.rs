@@ -0,0 +1,236 @@
+use crate::paging::ContinuationKey;
+use crate::types::de::de_one_or_many;
+use crate::types::response::stock::quote::SingleQuoteResponse;
+use crate::types::response::{Continuation, RawBody};
+use crate::types::{Currency, FxRate};
+use crate::Error;
+use getset::Getters;
+use rust_decimal::Decimal;
+use serde::Deserialize;
+
+pub type OverseasBuyableResponse = SingleQuoteResponse<output::OverseasBuyable>;
+
+/// 해외주식 잔고 응답
+/// output1: 보유 종목, output2: 계좌 합계(외화 기준)
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct OverseasBalanceResponse {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[serde(default)]
+    #[getset(get = "pub")]
+    ctx_area_fk200: String, // 연속조회검색조건200
+    #[serde(default)]
+    #[getset(get = "pub")]
+    ctx_area_nk200: String, // 연속조회키200
+    #[serde(default)]
+    #[getset(get = "pub")]
+    output1: Vec<output::OverseasHolding>, // 보유 종목
+    #[serde(default = "Vec::new", deserialize_with = "de_one_or_many")]
+    #[getset(get = "pub")]
+    output2: Vec<output::OverseasBalanceSummary>, // 계좌 합계
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl OverseasBalanceResponse {
+    /// output2의 첫 번째 항목(계좌 합계)
+    pub fn summary(&self) -> Option<&output::OverseasBalanceSummary> {
+        self.output2.first()
+    }
+
+    /// 연속조회한 페이지의 보유 종목을 첫 페이지에 합침. 계좌 합계는 첫 페이지의 값
+    pub(crate) fn from_pages(pages: Vec<Self>) -> Option<Self> {
+        let mut pages = pages.into_iter();
+        let mut first = pages.next()?;
+        for page in pages {
+            first.output1.extend(page.output1);
+            if first.output2.is_empty() {
+                first.output2 = page.output2;
+            }
+        }
+        first.ctx_area_fk200.clear();
+        first.ctx_area_nk200.clear();
+        Some(first)
+    }
+
+    /// 보유 종목 평가금액(ovrs_stck_evlu_amt) 합계를 rate로 원화 환산
+    /// rate는 (보유 종목 거래통화, KRW) 쌍이어야 함
+    pub fn to_krw(&self, rate: &FxRate) -> Result<Decimal, Error> {
+        let (base, quote) = rate.pair();
+        if quote != Currency::Krw {
+            return Err(Error::InvalidParam {
+                field: "rate",
+                reason: "must be quoted in KRW",
+            });
+        }
+        let mut total = Decimal::ZERO;
+        for holding in &self.output1 {
+            if holding.tr_crcy_cd().parse::<Currency>().ok() != Some(base) {
+                return Err(Error::InvalidParam {
+                    field: "rate",
+                    reason: "currency does not match holding currency",
+                });
+            }
+            total += holding.ovrs_stck_evlu_amt().unwrap_or_default();
+        }
+        Ok(rate.convert(total))
+    }
+}
+
+impl Continuation for OverseasBalanceResponse {
+    fn continuation_key(&self) -> ContinuationKey {
+        ContinuationKey {
+            fk100: self.ctx_area_fk200.clone(),
+            nk100: self.ctx_area_nk200.clone(),
+        }
+    }
+}
+
+impl RawBody for OverseasBalanceResponse {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
+
+pub mod output {
+    use crate::types::de::{de_decimal_from_str, de_f64_from_str};
+    use crate::types::{Currency, FxRate, Time};
+    use getset::{CopyGetters, Getters};
+    use rust_decimal::Decimal;
+    use serde::Deserialize;
+
+    /// 해외주식 보유 종목
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct OverseasHolding {
+        #[getset(get = "pub")]
+        ovrs_pdno: String, // 해외상품번호(종목코드)
+        #[getset(get = "pub")]
+        ovrs_item_name: String, // 해외종목명
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        ovrs_cblc_qty: Option<Decimal>, // 해외잔고수량
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_psbl_qty: Option<Decimal>, // 주문가능수량
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        pchs_avg_pric: Option<Decimal>, // 매입평균가격
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        now_pric2: Option<Decimal>, // 현재가격2
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        frcr_pchs_amt1: Option<Decimal>, // 외화매입금액1
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        ovrs_stck_evlu_amt: Option<Decimal>, // 해외주식평가금액
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        frcr_evlu_pfls_amt: Option<Decimal>, // 외화평가손익금액
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        evlu_pfls_rt: Option<f64>, // 평가손익율(%)
+        #[getset(get = "pub")]
+        tr_crcy_cd: String, // 거래통화코드
+        #[getset(get = "pub")]
+        ovrs_excg_cd: String, // 해외거래소코드
+        #[serde(default)]
+        #[getset(get = "pub")]
+        loan_type_cd: String, // 대출유형코드
+        #[serde(default)]
+        #[getset(get = "pub")]
+        loan_dt: String, // 대출일자
+        #[serde(default)]
+        #[getset(get = "pub")]
+        expd_dt: String, // 만기일자
+    }
+
+    /// 해외주식 잔고 합계(외화 기준)
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct OverseasBalanceSummary {
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        frcr_pchs_amt1: Option<Decimal>, // 외화매입금액1
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        ovrs_rlzt_pfls_amt: Option<Decimal>, // 해외실현손익금액
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        ovrs_tot_pfls: Option<Decimal>, // 해외총손익
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        rlzt_erng_rt: Option<f64>, // 실현수익율(%)
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_evlu_pfls_amt: Option<Decimal>, // 총평가손익금액
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_pftrt: Option<f64>, // 총수익률(%)
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        frcr_buy_amt_smtl1: Option<Decimal>, // 외화매수금액합계1
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        ovrs_rlzt_pfls_amt2: Option<Decimal>, // 해외실현손익금액2
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        frcr_buy_amt_smtl2: Option<Decimal>, // 외화매수금액합계2
+    }
+
+    /// 해외주식 매수가능금액
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct OverseasBuyable {
+        #[getset(get = "pub")]
+        tr_crcy_cd: String, // 거래통화코드
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_psbl_frcr_amt: Option<Decimal>, // 주문가능외화금액
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        sll_ruse_psbl_amt: Option<Decimal>, // 매도재사용가능금액
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        ovrs_ord_psbl_amt: Option<Decimal>, // 해외주문가능금액
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        max_ord_psbl_qty: Option<Decimal>, // 최대주문가능수량
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        echm_af_ord_psbl_amt: Option<Decimal>, // 환전이후주문가능금액
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        echm_af_ord_psbl_qty: Option<Decimal>, // 환전이후주문가능수량
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_psbl_qty: Option<Decimal>, // 주문가능수량
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        exrt: Option<Decimal>, // 환율
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        frcr_ord_psbl_amt1: Option<Decimal>, // 외화주문가능금액1
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        ovrs_max_ord_psbl_qty: Option<Decimal>, // 해외최대주문가능수량
+    }
+
+    impl OverseasBuyable {
+        /// 응답의 환율(exrt)을 (거래통화, KRW) FxRate로 변환. 적용 일자는 조회한 날짜(KST)
+        pub fn fx_rate(&self) -> Option<FxRate> {
+            let currency: Currency = self.tr_crcy_cd.parse().ok()?;
+            let rate = self.exrt.filter(|rate| !rate.is_zero())?;
+            Some(FxRate::new(
+                (currency, Currency::Krw),
+                rate,
+                Time::now().inner().date_naive(),
+            ))
+        }
+    }
+}
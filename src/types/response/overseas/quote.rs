@@ -0,0 +1,135 @@
+use crate::types::response::stock::quote::SingleQuoteResponse;
+use crate::types::response::RawBody;
+use getset::Getters;
+use serde::Deserialize;
+
+pub type OverseasPriceResponse = SingleQuoteResponse<output::CurrentPrice>;
+
+/// 해외주식 기간별시세 응답
+/// output1: 종목 정보, output2: 기준일자부터 과거 순으로 최대 100건
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct OverseasChartResponse {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[getset(get = "pub")]
+    output1: Option<output::ChartInfo>, // 종목 정보
+    #[serde(default)]
+    #[getset(get = "pub")]
+    output2: Vec<output::OverseasCandle>, // 기간별 시세
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl RawBody for OverseasChartResponse {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
+
+pub mod output {
+    use crate::types::de::{de_decimal_from_str, de_f64_from_str, de_i64_from_str, parse_yyyymmdd};
+    use getset::{CopyGetters, Getters};
+    use rust_decimal::Decimal;
+    use serde::Deserialize;
+
+    /// 해외주식 현재체결가
+    /// 가격은 소수점 이하 자리수(zdiv)가 거래소/종목마다 달라 Decimal로 역직렬화
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct CurrentPrice {
+        #[getset(get = "pub")]
+        rsym: String, // 실시간조회종목코드(D+시장구분(3자리)+종목코드, 예: DNASAAPL)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        zdiv: Option<i64>, // 소수점자리수
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        base: Option<Decimal>, // 전일종가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        pvol: Option<i64>, // 전일거래량
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        last: Option<Decimal>, // 현재가
+        #[getset(get = "pub")]
+        sign: String, // 대비기호(1: 상한, 2: 상승, 3: 보합, 4: 하한, 5: 하락)
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        diff: Option<Decimal>, // 대비
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        rate: Option<f64>, // 등락율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tvol: Option<i64>, // 거래량
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        tamt: Option<Decimal>, // 거래대금
+        #[getset(get = "pub")]
+        ordy: String, // 매수가능여부
+    }
+    impl CurrentPrice {
+        /// 지연시세 여부(rsym이 D로 시작하면 지연시세, 실시간시세 신청 시 R로 시작)
+        pub fn is_delayed(&self) -> bool {
+            self.rsym.starts_with('D')
+        }
+    }
+
+    /// 해외주식 기간별시세 - 종목 정보
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct ChartInfo {
+        #[getset(get = "pub")]
+        rsym: String, // 실시간조회종목코드
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        zdiv: Option<i64>, // 소수점자리수
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nrec: Option<i64>, // 조회건수
+    }
+
+    /// 해외주식 기간별시세(일/주/월봉)
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct OverseasCandle {
+        #[getset(get = "pub")]
+        xymd: String, // 일자(YYYYMMDD, 거래소 현지 일자)
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        open: Option<Decimal>, // 시가
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        high: Option<Decimal>, // 고가
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        low: Option<Decimal>, // 저가
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        clos: Option<Decimal>, // 종가
+        #[getset(get = "pub")]
+        sign: String, // 대비기호
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        diff: Option<Decimal>, // 대비
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        rate: Option<f64>, // 등락율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tvol: Option<i64>, // 거래량
+        #[serde(default, deserialize_with = "de_decimal_from_str")]
+        #[getset(get_copy = "pub")]
+        tamt: Option<Decimal>, // 거래대금
+    }
+    impl OverseasCandle {
+        /// 거래소 현지 일자
+        pub fn date(&self) -> Option<chrono::NaiveDate> {
+            parse_yyyymmdd(&self.xymd)
+        }
+    }
+}
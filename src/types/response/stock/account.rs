@@ -0,0 +1,747 @@
+use crate::paging::ContinuationKey;
+use crate::types::de::de_one_or_many;
+use crate::types::response::stock::quote::SingleQuoteResponse;
+use crate::types::response::{Continuation, RawBody};
+use getset::{CopyGetters, Getters};
+use serde::Deserialize;
+
+pub type BalanceResponse = AccountResponse<output::HoldingItem, output::BalanceSummary>;
+pub type DailyCcldResponse = AccountResponse<output::DailyCcldItem, output::DailyCcldSummary>;
+pub type PsblRvsecnclResponse = AccountListResponse<output::OpenOrder>;
+pub type BuyableResponse = SingleQuoteResponse<output::Buyable>;
+pub type PeriodProfitPage =
+    AccountResponse<output::PeriodProfitItem, output::PeriodProfitPageSummary>;
+
+/// 기간별매매손익현황 전체 페이지 결과
+/// summary는 모든 페이지의 종목별 행을 합산한 값
+#[derive(Clone, Debug, Getters)]
+pub struct PeriodProfitResponse {
+    #[getset(get = "pub")]
+    items: Vec<output::PeriodProfitItem>, // 종목별 매매손익
+    #[getset(get = "pub")]
+    summary: PeriodProfitSummary, // 합계
+}
+
+impl PeriodProfitResponse {
+    pub(crate) fn from_pages(pages: Vec<PeriodProfitPage>) -> Self {
+        let items: Vec<output::PeriodProfitItem> = pages
+            .into_iter()
+            .flat_map(|page| page.into_items())
+            .collect();
+        let sum = |f: fn(&output::PeriodProfitItem) -> Option<i64>| {
+            items.iter().filter_map(f).sum::<i64>()
+        };
+        let buy_amt = sum(|item| item.buy_amt());
+        let rlzt_pfls = sum(|item| item.rlzt_pfls());
+        let summary = PeriodProfitSummary {
+            buy_amt,
+            sll_amt: sum(|item| item.sll_amt()),
+            rlzt_pfls,
+            fee: sum(|item| item.fee()),
+            tl_tax: sum(|item| item.tl_tax()),
+            pfls_rt: (buy_amt != 0).then(|| rlzt_pfls as f64 / buy_amt as f64 * 100.0),
+        };
+        Self { items, summary }
+    }
+}
+
+/// 기간별매매손익현황 합계
+#[derive(Clone, Debug, Default, CopyGetters)]
+pub struct PeriodProfitSummary {
+    #[getset(get_copy = "pub")]
+    buy_amt: i64, // 매수금액 합계
+    #[getset(get_copy = "pub")]
+    sll_amt: i64, // 매도금액 합계
+    #[getset(get_copy = "pub")]
+    rlzt_pfls: i64, // 실현손익 합계
+    #[getset(get_copy = "pub")]
+    fee: i64, // 수수료 합계
+    #[getset(get_copy = "pub")]
+    tl_tax: i64, // 제세금 합계
+    #[getset(get_copy = "pub")]
+    pfls_rt: Option<f64>, // 수익률(실현손익 / 매수금액, %)
+}
+
+/// 예수금과 출금/주문 가능 금액
+/// 주식잔고조회 계좌 합계와 매수가능조회에서 현금 항목만 모은 값. 응답에 없는 항목은 0
+#[derive(Clone, Debug, Default, CopyGetters)]
+pub struct DepositDetail {
+    #[getset(get_copy = "pub")]
+    d0: i64, // D+0 예수금(예수금총금액)
+    #[getset(get_copy = "pub")]
+    d1: i64, // D+1 예수금(익일정산금액)
+    #[getset(get_copy = "pub")]
+    d2: i64, // D+2 예수금(가수도정산금액)
+    #[getset(get_copy = "pub")]
+    withdrawable: i64, // 출금가능금액(D+2 예수금 기준, 미수/대출 상환액은 차감하지 않음)
+    #[getset(get_copy = "pub")]
+    orderable_cash: i64, // 주문가능현금
+}
+
+impl DepositDetail {
+    pub(crate) fn new(summary: Option<&output::BalanceSummary>, buyable: &output::Buyable) -> Self {
+        let amount =
+            |f: fn(&output::BalanceSummary) -> Option<i64>| summary.and_then(f).unwrap_or_default();
+        let d2 = amount(|s| s.prvs_rcdl_excc_amt());
+        Self {
+            d0: amount(|s| s.dnca_tot_amt()),
+            d1: amount(|s| s.nxdy_excc_amt()),
+            d2,
+            withdrawable: d2.max(0),
+            orderable_cash: buyable.ord_psbl_cash().unwrap_or_default(),
+        }
+    }
+}
+
+/// output1(목록), output2(합계) 두 블록으로 응답하는 계좌 조회
+#[derive(Clone, Debug, Deserialize, Getters)]
+#[serde(bound(deserialize = "T: Deserialize<'de>, S: Deserialize<'de>"))]
+pub struct AccountResponse<T, S> {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[serde(default)]
+    #[getset(get = "pub")]
+    ctx_area_fk100: String, // 연속조회검색조건100
+    #[serde(default)]
+    #[getset(get = "pub")]
+    ctx_area_nk100: String, // 연속조회키100
+    #[serde(default = "Vec::new")]
+    #[getset(get = "pub")]
+    output1: Vec<T>, // 응답 상세1(목록)
+    #[serde(default = "Vec::new", deserialize_with = "de_one_or_many")]
+    #[getset(get = "pub")]
+    output2: Vec<S>, // 응답 상세2(합계, API에 따라 배열 또는 객체)
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl<T, S> AccountResponse<T, S> {
+    /// output2의 첫 번째 항목(계좌 합계)
+    pub fn summary(&self) -> Option<&S> {
+        self.output2.first()
+    }
+
+    /// output1(목록)
+    pub fn into_items(self) -> Vec<T> {
+        self.output1
+    }
+}
+
+impl<T, S> Continuation for AccountResponse<T, S> {
+    fn continuation_key(&self) -> ContinuationKey {
+        ContinuationKey {
+            fk100: self.ctx_area_fk100.clone(),
+            nk100: self.ctx_area_nk100.clone(),
+        }
+    }
+}
+
+impl<T, S> RawBody for AccountResponse<T, S> {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
+
+/// output 한 블록(목록)으로 응답하는 계좌 조회
+#[derive(Clone, Debug, Deserialize, Getters)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+pub struct AccountListResponse<T> {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[serde(default)]
+    #[getset(get = "pub")]
+    ctx_area_fk100: String, // 연속조회검색조건100
+    #[serde(default)]
+    #[getset(get = "pub")]
+    ctx_area_nk100: String, // 연속조회키100
+    #[serde(default = "Vec::new")]
+    #[getset(get = "pub")]
+    output: Vec<T>, // 응답 상세(목록)
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl<T> AccountListResponse<T> {
+    /// output(목록)
+    pub fn into_items(self) -> Vec<T> {
+        self.output
+    }
+}
+
+impl<T> Continuation for AccountListResponse<T> {
+    fn continuation_key(&self) -> ContinuationKey {
+        ContinuationKey {
+            fk100: self.ctx_area_fk100.clone(),
+            nk100: self.ctx_area_nk100.clone(),
+        }
+    }
+}
+
+impl<T> RawBody for AccountListResponse<T> {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
+
+/// 주식예약주문조회 응답(한 페이지)
+/// 연속조회키는 CTX_AREA_FK200/NK200이며 ContinuationKey의 fk100/nk100에 담아 전달
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct ReserveOrderListResponse {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[serde(default)]
+    #[getset(get = "pub")]
+    ctx_area_fk200: String, // 연속조회검색조건200
+    #[serde(default)]
+    #[getset(get = "pub")]
+    ctx_area_nk200: String, // 연속조회키200
+    #[serde(default, deserialize_with = "de_one_or_many")]
+    #[getset(get = "pub")]
+    output: Vec<output::ReserveOrderItem>, // 응답 상세(목록)
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl ReserveOrderListResponse {
+    /// output(목록)
+    pub fn into_items(self) -> Vec<output::ReserveOrderItem> {
+        self.output
+    }
+}
+
+impl Continuation for ReserveOrderListResponse {
+    fn continuation_key(&self) -> ContinuationKey {
+        ContinuationKey {
+            fk100: self.ctx_area_fk200.clone(),
+            nk100: self.ctx_area_nk200.clone(),
+        }
+    }
+}
+
+impl RawBody for ReserveOrderListResponse {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
+
+pub mod output {
+    use crate::types::de::{de_f64_from_str, de_i64_from_str, parse_yyyymmdd};
+    use crate::types::{Direction, OrderClass, Price, Quantity};
+    use getset::{CopyGetters, Getters};
+    use serde::Deserialize;
+
+    /// 주식잔고조회 - 종목별 보유 내역
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct HoldingItem {
+        #[getset(get = "pub")]
+        pdno: String, // 상품번호(종목번호 뒤 6자리)
+        #[getset(get = "pub")]
+        prdt_name: String, // 상품명(종목명)
+        #[getset(get = "pub")]
+        trad_dvsn_name: String, // 매매구분명(매수매도구분)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        bfdy_buy_qty: Option<i64>, // 전일매수수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        bfdy_sll_qty: Option<i64>, // 전일매도수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        thdt_buyqty: Option<i64>, // 금일매수수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        thdt_sll_qty: Option<i64>, // 금일매도수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        hldg_qty: Option<i64>, // 보유수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_psbl_qty: Option<i64>, // 주문가능수량
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        pchs_avg_pric: Option<f64>, // 매입평균가격(매입금액 / 보유수량)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        pchs_amt: Option<i64>, // 매입금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prpr: Option<i64>, // 현재가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        evlu_amt: Option<i64>, // 평가금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        evlu_pfls_amt: Option<i64>, // 평가손익금액(평가금액 - 매입금액)
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        evlu_pfls_rt: Option<f64>, // 평가손익율
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        evlu_erng_rt: Option<f64>, // 평가수익율
+        #[getset(get = "pub")]
+        loan_dt: String, // 대출일자
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        loan_amt: Option<i64>, // 대출금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stln_slng_chgs: Option<i64>, // 대주매각대금
+        #[getset(get = "pub")]
+        expd_dt: String, // 만기일자
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        fltt_rt: Option<f64>, // 등락율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        bfdy_cprs_icdc: Option<i64>, // 전일대비증감
+        #[getset(get = "pub")]
+        item_mgna_rt_name: String, // 종목증거금율명
+        #[getset(get = "pub")]
+        grta_rt_name: String, // 보증금율명
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        sbst_pric: Option<i64>, // 대용가격
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_loan_unpr: Option<f64>, // 주식대출단가
+    }
+
+    impl HoldingItem {
+        /// 신용(대출) 잔고 여부
+        pub fn is_credit(&self) -> bool {
+            self.loan_amt.unwrap_or(0) > 0
+        }
+
+        /// 대출일자
+        pub fn loan_date(&self) -> Option<chrono::NaiveDate> {
+            parse_yyyymmdd(&self.loan_dt)
+        }
+
+        /// 만기일자(상환 기한)
+        pub fn expiry_date(&self) -> Option<chrono::NaiveDate> {
+            parse_yyyymmdd(&self.expd_dt)
+        }
+    }
+
+    /// 주식잔고조회 - 계좌 합계
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct BalanceSummary {
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        dnca_tot_amt: Option<i64>, // 예수금총금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nxdy_excc_amt: Option<i64>, // 익일정산금액(D+1 예수금)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prvs_rcdl_excc_amt: Option<i64>, // 가수도정산금액(D+2 예수금)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        cma_evlu_amt: Option<i64>, // CMA평가금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        bfdy_buy_amt: Option<i64>, // 전일매수금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        thdt_buy_amt: Option<i64>, // 금일매수금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nxdy_auto_rdpt_amt: Option<i64>, // 익일자동상환금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        bfdy_sll_amt: Option<i64>, // 전일매도금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        thdt_sll_amt: Option<i64>, // 금일매도금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        d2_auto_rdpt_amt: Option<i64>, // D+2자동상환금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        bfdy_tlex_amt: Option<i64>, // 전일제비용금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        thdt_tlex_amt: Option<i64>, // 금일제비용금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_loan_amt: Option<i64>, // 총대출금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        scts_evlu_amt: Option<i64>, // 유가평가금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_evlu_amt: Option<i64>, // 총평가금액(유가증권 평가금액 합계 + D+2 예수금)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nass_amt: Option<i64>, // 순자산금액
+        #[getset(get = "pub")]
+        fncg_gld_auto_rdpt_yn: String, // 융자금자동상환여부
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        pchs_amt_smtl_amt: Option<i64>, // 매입금액합계금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        evlu_amt_smtl_amt: Option<i64>, // 평가금액합계금액(유가증권 평가금액 합계)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        evlu_pfls_smtl_amt: Option<i64>, // 평가손익합계금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_stln_slng_chgs: Option<i64>, // 총대주매각대금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        bfdy_tot_asst_evlu_amt: Option<i64>, // 전일총자산평가금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        asst_icdc_amt: Option<i64>, // 자산증감액
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        asst_icdc_erng_rt: Option<f64>, // 자산증감수익율
+    }
+
+    /// 주식일별주문체결조회 - 주문별 체결 내역
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct DailyCcldItem {
+        #[getset(get = "pub")]
+        ord_dt: String, // 주문일자
+        #[getset(get = "pub")]
+        ord_gno_brno: String, // 주문채번지점번호
+        #[getset(get = "pub")]
+        odno: String, // 주문번호
+        #[getset(get = "pub")]
+        orgn_odno: String, // 원주문번호
+        #[getset(get = "pub")]
+        ord_dvsn_name: String, // 주문구분명
+        #[getset(get = "pub")]
+        sll_buy_dvsn_cd: String, // 매도매수구분코드(01: 매도, 02: 매수)
+        #[getset(get = "pub")]
+        sll_buy_dvsn_cd_name: String, // 매도매수구분코드명
+        #[getset(get = "pub")]
+        pdno: String, // 상품번호(종목코드)
+        #[getset(get = "pub")]
+        prdt_name: String, // 상품명(종목명)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_qty: Option<i64>, // 주문수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_unpr: Option<i64>, // 주문단가
+        #[getset(get = "pub")]
+        ord_tmd: String, // 주문시각(HHMMSS)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_ccld_qty: Option<i64>, // 총체결수량
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        avg_prvs: Option<f64>, // 평균가(총체결금액 / 총체결수량)
+        #[getset(get = "pub")]
+        cncl_yn: String, // 취소여부
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_ccld_amt: Option<i64>, // 총체결금액
+        #[getset(get = "pub")]
+        loan_dt: String, // 대출일자
+        #[getset(get = "pub")]
+        ord_dvsn_cd: String, // 주문구분코드
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        cncl_cfrm_qty: Option<i64>, // 취소확인수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        rmn_qty: Option<i64>, // 잔여수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        rjct_qty: Option<i64>, // 거부수량
+        #[getset(get = "pub")]
+        ccld_cndt_name: String, // 체결조건명
+        #[getset(get = "pub")]
+        infm_tmd: String, // 통보시각
+        #[getset(get = "pub")]
+        ctac_tlno: String, // 연락전화번호
+        #[getset(get = "pub")]
+        prdt_type_cd: String, // 상품유형코드
+        #[getset(get = "pub")]
+        excg_dvsn_cd: String, // 거래소구분코드
+    }
+
+    /// 주식일별주문체결조회 - 합계
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct DailyCcldSummary {
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_ord_qty: Option<i64>, // 총주문수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_ccld_qty: Option<i64>, // 총체결수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_ccld_amt: Option<i64>, // 총체결금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prsm_tlex_smtl: Option<i64>, // 추정제비용합계
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        pchs_avg_pric: Option<f64>, // 매입평균가격
+    }
+
+    /// 주식정정취소가능주문조회 - 정정/취소 가능한 미체결 주문
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct OpenOrder {
+        #[getset(get = "pub")]
+        ord_gno_brno: String, // 주문채번지점번호(정정/취소 시 KRX_FWDG_ORD_ORGNO)
+        #[getset(get = "pub")]
+        odno: String, // 주문번호(정정/취소 시 ORGN_ODNO)
+        #[getset(get = "pub")]
+        orgn_odno: String, // 원주문번호
+        #[getset(get = "pub")]
+        ord_dvsn_name: String, // 주문구분명
+        #[getset(get = "pub")]
+        pdno: String, // 상품번호(종목코드)
+        #[getset(get = "pub")]
+        prdt_name: String, // 상품명(종목명)
+        #[getset(get = "pub")]
+        rvse_cncl_dvsn_name: String, // 정정취소구분명
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_qty: Option<i64>, // 주문수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_unpr: Option<i64>, // 주문단가
+        #[getset(get = "pub")]
+        ord_tmd: String, // 주문시각(HHMMSS)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_ccld_qty: Option<i64>, // 총체결수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_ccld_amt: Option<i64>, // 총체결금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        psbl_qty: Option<i64>, // 가능수량(정정/취소 가능한 잔량)
+        #[getset(get = "pub")]
+        sll_buy_dvsn_cd: String, // 매도매수구분코드(01: 매도, 02: 매수)
+        #[getset(get = "pub")]
+        ord_dvsn_cd: String, // 주문구분코드
+    }
+
+    impl OpenOrder {
+        /// 정정/취소 시 krx_fwdg_ord_orgno로 사용
+        pub fn krx_fwdg_ord_orgno(&self) -> &str {
+            &self.ord_gno_brno
+        }
+
+        /// 매도/매수 구분
+        pub fn direction(&self) -> Option<Direction> {
+            match self.sll_buy_dvsn_cd.as_str() {
+                "01" => Some(Direction::Ask),
+                "02" => Some(Direction::Bid),
+                _ => None,
+            }
+        }
+
+        /// 주문구분(정정/취소 시 order_division으로 사용)
+        pub fn order_class(&self) -> Option<OrderClass> {
+            match self.ord_dvsn_cd.trim().parse::<u8>() {
+                Ok(0..=16) => Some(OrderClass::from(self.ord_dvsn_cd.trim())),
+                _ => None,
+            }
+        }
+
+        /// 정정/취소 가능한 잔량
+        pub fn remaining_qty(&self) -> Quantity {
+            Quantity::from(self.psbl_qty.unwrap_or(0).max(0) as u32)
+        }
+
+        /// 주문단가
+        pub fn price(&self) -> Price {
+            Price::from(self.ord_unpr.unwrap_or(0).max(0) as u32)
+        }
+
+        /// 정정/취소 가능 여부(가능수량이 남아있는지)
+        pub fn is_revisable(&self) -> bool {
+            self.psbl_qty.unwrap_or(0) > 0
+        }
+    }
+
+    /// 매수가능조회
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct Buyable {
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_psbl_cash: Option<i64>, // 주문가능현금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_psbl_sbst: Option<i64>, // 주문가능대용
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        ruse_psbl_amt: Option<i64>, // 재사용가능금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        psbl_qty_calc_unpr: Option<i64>, // 가능수량계산단가(시장가 조회 시 현재가 기준)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nrcvb_buy_amt: Option<i64>, // 미수없는매수금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nrcvb_buy_qty: Option<i64>, // 미수없는매수수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        max_buy_amt: Option<i64>, // 최대매수금액(미수 포함)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        max_buy_qty: Option<i64>, // 최대매수수량(미수 포함)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        cma_evlu_amt: Option<i64>, // CMA평가금액
+    }
+
+    /// 기간별매매손익현황 - 종목별 매매손익
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct PeriodProfitItem {
+        #[getset(get = "pub")]
+        trad_dt: String, // 매매일자
+        #[getset(get = "pub")]
+        pdno: String, // 상품번호(종목코드)
+        #[getset(get = "pub")]
+        prdt_name: String, // 상품명(종목명)
+        #[getset(get = "pub")]
+        trad_dvsn_name: String, // 매매구분명
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        buy_qty: Option<i64>, // 매수수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        buy_amt: Option<i64>, // 매수금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        sll_qty: Option<i64>, // 매도수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        sll_amt: Option<i64>, // 매도금액
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        rlzt_pfls: Option<i64>, // 실현손익
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        pfls_rt: Option<f64>, // 손익률
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        fee: Option<i64>, // 수수료
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tl_tax: Option<i64>, // 제세금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        loan_int: Option<i64>, // 대출이자
+    }
+
+    /// 기간별매매손익현황 - 페이지 합계(output2)
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct PeriodProfitPageSummary {
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        buy_tr_amt_smtl: Option<i64>, // 매수거래금액합계
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        sll_tr_amt_smtl: Option<i64>, // 매도거래금액합계
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_fee: Option<i64>, // 총수수료
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_tltx: Option<i64>, // 총제세금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_rlzt_pfls: Option<i64>, // 총실현손익
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_pftrt: Option<f64>, // 총수익률
+    }
+
+    /// 주식예약주문조회 - 예약주문 내역
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct ReserveOrderItem {
+        #[getset(get = "pub")]
+        rsvn_ord_seq: String, // 예약주문순번(취소 시 사용)
+        #[getset(get = "pub")]
+        rsvn_ord_ord_dt: String, // 예약주문주문일자
+        #[serde(default)]
+        #[getset(get = "pub")]
+        rsvn_ord_rcit_dt: String, // 예약주문접수일자
+        #[getset(get = "pub")]
+        pdno: String, // 상품번호
+        #[serde(default)]
+        #[getset(get = "pub")]
+        kor_item_shtn_name: String, // 한글종목단축명
+        #[serde(default)]
+        #[getset(get = "pub")]
+        sll_buy_dvsn_cd: String, // 매도매수구분코드(01: 매도, 02: 매수)
+        #[serde(default)]
+        #[getset(get = "pub")]
+        ord_dvsn_cd: String, // 주문구분코드
+        #[serde(default)]
+        #[getset(get = "pub")]
+        ord_dvsn_name: String, // 주문구분명
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_rsvn_qty: Option<i64>, // 주문예약수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        ord_rsvn_unpr: Option<i64>, // 주문예약단가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_ccld_qty: Option<i64>, // 총체결수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tot_ccld_amt: Option<i64>, // 총체결금액
+        #[serde(default)]
+        #[getset(get = "pub")]
+        cncl_ord_dt: String, // 취소주문일자(취소된 예약주문만)
+        #[serde(default)]
+        #[getset(get = "pub")]
+        odno: String, // 주문번호(장 시작 시 실제 주문된 경우)
+        #[serde(default)]
+        #[getset(get = "pub")]
+        prcs_rslt: String, // 처리결과
+        #[serde(default)]
+        #[getset(get = "pub")]
+        rsvn_end_dt: String, // 예약종료일자
+    }
+
+    impl ReserveOrderItem {
+        /// 매도/매수 구분
+        pub fn direction(&self) -> Option<Direction> {
+            match self.sll_buy_dvsn_cd.as_str() {
+                "01" => Some(Direction::Ask),
+                "02" => Some(Direction::Bid),
+                _ => None,
+            }
+        }
+
+        /// 취소된 예약주문 여부
+        pub fn is_cancelled(&self) -> bool {
+            !self.cncl_ord_dt.trim().is_empty()
+        }
+    }
+}
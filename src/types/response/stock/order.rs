@@ -16,7 +16,7 @@ pub mod Header {
 
 pub mod Body {
     use super::Output;
-    use getset::Getters;
+    use getset::{CopyGetters, Getters};
     use serde::{Deserialize, Serialize};
 
     /// 주식주문(현금, 신용, 정정취소)
@@ -31,6 +31,70 @@ pub mod Body {
         #[getset(get = "pub")]
         output: Option<Output::Order>, // 응답 상세
     }
+    impl crate::types::response::RawBody for Order {}
+    impl Order {
+        /// 주문번호(ODNO). 정정/취소 시 원주문번호로 사용
+        pub fn order_no(&self) -> Option<&str> {
+            self.output.as_ref().map(|output| output.odno().as_str())
+        }
+
+        /// 한국거래소전송주문조직번호(KRX_FWDG_ORD_ORGNO)
+        pub fn krx_fwdg_ord_orgno(&self) -> Option<&str> {
+            self.output
+                .as_ref()
+                .map(|output| output.krx_fwdg_ord_orgno().as_str())
+        }
+    }
+
+    /// 중복 방지 주식주문 결과(Korea::order_cash_idempotent)
+    #[derive(Debug, Clone, PartialEq, Getters, CopyGetters)]
+    pub struct IdempotentOrder {
+        #[getset(get = "pub")]
+        order: Order, // 주문 응답
+        #[getset(get_copy = "pub")]
+        duplicate: bool, // 같은 idempotency key로 이미 전송한 주문의 응답이면 true(다시 전송하지 않음)
+    }
+    impl IdempotentOrder {
+        pub(crate) fn new(order: Order, duplicate: bool) -> Self {
+            Self { order, duplicate }
+        }
+    }
+
+    /// 주식예약주문
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct ReserveOrder {
+        #[getset(get = "pub")]
+        rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        msg_cd: String, // 응답코드
+        #[getset(get = "pub")]
+        msg1: String, // 응답메시지
+        #[getset(get = "pub")]
+        output: Option<Output::ReserveOrder>, // 응답 상세
+    }
+    impl crate::types::response::RawBody for ReserveOrder {}
+    impl ReserveOrder {
+        /// 예약주문순번(RSVN_ORD_SEQ). 예약주문 취소 시 사용
+        pub fn reserve_seq(&self) -> Option<&str> {
+            self.output
+                .as_ref()
+                .map(|output| output.rsvn_ord_seq().as_str())
+        }
+    }
+
+    /// 주식예약주문정정취소
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    pub struct ReserveCancel {
+        #[getset(get = "pub")]
+        rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+        #[getset(get = "pub")]
+        msg_cd: String, // 응답코드
+        #[getset(get = "pub")]
+        msg1: String, // 응답메시지
+        #[getset(get = "pub")]
+        output: Option<Output::ReserveCancel>, // 응답 상세
+    }
+    impl crate::types::response::RawBody for ReserveCancel {}
 
     /// 주식정정취소가능주문조회
     #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
@@ -66,6 +130,23 @@ pub mod Output {
         ord_tmd: String, // 주문시각(시분초HHMMSS)
     }
 
+    /// 주식예약주문
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct ReserveOrder {
+        #[getset(get = "pub")]
+        rsvn_ord_seq: String, // 예약주문순번
+    }
+
+    /// 주식예약주문정정취소
+    #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+    #[serde(rename_all = "UPPERCASE")]
+    pub struct ReserveCancel {
+        #[serde(default)]
+        #[getset(get = "pub")]
+        nrml_prcs_yn: String, // 정상처리여부
+    }
+
     /// 주식정정취소가능주문조회
     #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
     pub struct InquirePsblRvsecncl {
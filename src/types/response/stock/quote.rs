@@ -1,9 +1,100 @@
-use getset::Getters;
+use crate::types::response::RawBody;
+use crate::types::{Direction, ListedMarket, Price, PriceSign, PriceValue};
+use chrono::{NaiveDate, NaiveTime};
+use getset::{CopyGetters, Getters};
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
 use serde::Deserialize;
 
+pub type CurrentPriceResponse = SingleQuoteResponse<output::CurrentPrice>;
 pub type DailyPriceResponse = QuoteResponse<output::DailyPrice>;
 pub type PeriodicPriceResponse = QuoteResponse2<output::PeriodicPrice>;
 pub type VolumeRankResponse = QuoteResponse<output::VolumeRank>;
+pub type IndexPriceResponse = SingleQuoteResponse<output::IndexPrice>;
+pub type MinuteChartResponse = QuoteResponse2<output::MinuteCandle>;
+pub type HolidayResponse = QuoteResponse<output::Holiday>;
+pub type InvestorTrendResponse = QuoteResponse<output::InvestorTrend>;
+pub type ElwPriceResponse = SingleQuoteResponse<output::ElwPrice>;
+pub type ElwSensitivityResponse = QuoteResponse<output::ElwSensitivity>;
+pub type ElwUnderlyingResponse = QuoteResponse<output::ElwUnderlying>;
+pub type StockBasicInfoResponse = SingleQuoteResponse<output::StockBasicInfo>;
+pub type ProgramTradeResponse = QuoteResponse<output::ProgramTrade>;
+pub type WatchlistPriceResponse = QuoteResponse<output::WatchlistPrice>;
+pub type FluctuationRankResponse = QuoteResponse<output::FluctuationRank>;
+pub type RankingResponse = QuoteResponse<output::RankingItem>;
+
+/// 예탁원정보(배당일정) 응답
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct DividendScheduleResponse {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[serde(default)]
+    #[getset(get = "pub")]
+    output1: Vec<output::DividendSchedule>, // 배당일정
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl RawBody for DividendScheduleResponse {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
+
+/// 주식현재가 시간대별체결 응답
+/// output1: 현재가 요약, output2: 시간대별 체결(최근 시각부터)
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct ConclusionStrengthResponse {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[getset(get = "pub")]
+    output1: Option<output::ConclusionSummary>, // 현재가 요약
+    #[serde(default)]
+    #[getset(get = "pub")]
+    output2: Vec<output::TimeConclusion>, // 시간대별 체결
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl ConclusionStrengthResponse {
+    /// 가장 최근 시각의 당일 체결강도
+    pub fn latest_strength(&self) -> Option<f64> {
+        self.output2.first().and_then(|row| row.tday_rltv())
+    }
+
+    /// 조회된 체결량을 (매수, 매도)로 추정
+    /// KIS가 체결 방향을 제공하지 않으므로 체결가가 매도호가 이상이면 매수, 매수호가 이하이면 매도로 보고
+    /// 호가 사이에서 체결된 수량은 어느 쪽에도 더하지 않음
+    pub fn estimated_buy_sell_volume(&self) -> (i64, i64) {
+        self.output2
+            .iter()
+            .fold((0, 0), |(buy, sell), row| match row.estimated_side() {
+                Some(Direction::Bid) => (buy + row.cnqn().unwrap_or_default(), sell),
+                Some(Direction::Ask) => (buy, sell + row.cnqn().unwrap_or_default()),
+                None => (buy, sell),
+            })
+    }
+}
+
+impl RawBody for ConclusionStrengthResponse {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
 
 #[derive(Clone, Debug, Deserialize, Getters)]
 pub struct QuoteResponse<T> {
@@ -15,6 +106,40 @@ pub struct QuoteResponse<T> {
     msg1: String, // 응답메시지
     #[getset(get = "pub")]
     output: Option<Vec<T>>, // 응답 상세
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl<T> RawBody for QuoteResponse<T> {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct SingleQuoteResponse<T> {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[getset(get = "pub")]
+    output: Option<T>, // 응답 상세
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl<T> RawBody for SingleQuoteResponse<T> {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Getters)]
@@ -27,102 +152,1432 @@ pub struct QuoteResponse2<T> {
     msg1: String, // 응답메시지
     #[getset(get = "pub")]
     output2: Option<Vec<T>>, // 응답 상세
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl<T> RawBody for QuoteResponse2<T> {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
+}
+
+/// 주식현재가 호가/예상체결 응답
+/// output1: 10단계 매도/매수 호가, output2: 예상체결 정보
+#[derive(Clone, Debug, Deserialize, Getters)]
+pub struct AskingPriceResponse {
+    #[getset(get = "pub")]
+    rt_cd: String, // 0: 성공, 0 이외의 값: 실패
+    #[getset(get = "pub")]
+    msg_cd: String, // 응답코드
+    #[getset(get = "pub")]
+    msg1: String, // 응답메시지
+    #[getset(get = "pub")]
+    output1: Option<output::Orderbook>, // 호가
+    #[getset(get = "pub")]
+    output2: Option<output::ExpectedConclusion>, // 예상체결
+    #[cfg(feature = "raw-response")]
+    #[serde(skip)]
+    #[getset(get = "pub")]
+    raw: String, // 응답 원문(JSON)
+}
+
+impl RawBody for AskingPriceResponse {
+    #[cfg(feature = "raw-response")]
+    fn set_raw(&mut self, raw: String) {
+        self.raw = raw;
+    }
 }
 
 pub mod output {
-    use crate::types::{ExCode, VsPriceSign};
-    use getset::Getters;
+    use crate::types::de::{de_f64_from_str, de_i64_from_str};
+    use crate::types::{Direction, ExCode, Investor, KisDate, VsPriceSign};
+    use getset::{CopyGetters, Getters};
     use serde::Deserialize;
+    use std::collections::HashMap;
 
-    #[derive(Clone, Debug, Deserialize, Getters)]
-    pub struct DailyPrice {
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct CurrentPrice {
         #[getset(get = "pub")]
-        stck_bsop_date: String, // 주식 영업 일자
+        iscd_stat_cls_code: String, // 종목 상태 구분 코드
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        marg_rate: Option<f64>, // 증거금 비율
         #[getset(get = "pub")]
-        stck_oprc: String, // 주식 시가
+        rprs_mrkt_kor_name: String, // 대표 시장 한글 명
         #[getset(get = "pub")]
-        stck_hgpr: String, // 주식 최고가
+        bstp_kor_isnm: String, // 업종 한글 종목명
         #[getset(get = "pub")]
-        stck_lwpr: String, // 주식 최저가
+        temp_stop_yn: String, // 임시 정지 여부
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_prpr: Option<i64>, // 주식 현재가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vrss: Option<i64>, // 전일 대비
         #[getset(get = "pub")]
-        stck_clpr: String, // 주식 종가
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_ctrt: Option<f64>, // 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_tr_pbmn: Option<i64>, // 누적 거래 대금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vrss_vol_rate: Option<f64>, // 전일 대비 거래량 비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_oprc: Option<i64>, // 주식 시가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_hgpr: Option<i64>, // 주식 최고가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_lwpr: Option<i64>, // 주식 최저가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_mxpr: Option<i64>, // 주식 상한가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_llam: Option<i64>, // 주식 하한가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_sdpr: Option<i64>, // 주식 기준가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        wghn_avrg_stck_prc: Option<f64>, // 가중 평균 주식 가격
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        hts_frgn_ehrt: Option<f64>, // HTS 외국인 소진율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        frgn_ntby_qty: Option<i64>, // 외국인 순매수 수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        pgtr_ntby_qty: Option<i64>, // 프로그램매매 순매수 수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        aspr_unit: Option<i64>, // 호가단위
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        lstn_stcn: Option<i64>, // 상장 주수
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        hts_avls: Option<i64>, // HTS 시가총액
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        per: Option<f64>, // PER
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        pbr: Option<f64>, // PBR
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        eps: Option<f64>, // EPS
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        bps: Option<f64>, // BPS
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        vol_tnrt: Option<f64>, // 거래량 회전율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        w52_hgpr: Option<i64>, // 52주일 최고가
         #[getset(get = "pub")]
-        acml_vol: String, // 누적 거래량
+        w52_hgpr_date: KisDate, // 52주일 최고가 일자
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        w52_lwpr: Option<i64>, // 52주일 최저가
         #[getset(get = "pub")]
-        prdy_vrss_vol_rate: String, // 전일 대비 거래량 비율
+        w52_lwpr_date: KisDate, // 52주일 최저가 일자
         #[getset(get = "pub")]
-        prdy_vrss: String, // 전일 대비
+        stck_shrn_iscd: String, // 주식 단축 종목코드
+        #[serde(default)]
         #[getset(get = "pub")]
-        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        mrkt_warn_cls_code: String, // 시장경고코드(00: 없음, 01: 투자주의, 02: 투자경고, 03: 투자위험)
+        #[serde(default)]
         #[getset(get = "pub")]
-        prdy_ctrt: String, // 전일 대비율
+        mang_issu_cls_code: String, // 관리종목여부
+        #[serde(default)]
         #[getset(get = "pub")]
-        hts_frgn_ehrt: String, // HTS 외국인 소진율
+        short_over_yn: String, // 단기과열여부
+        #[serde(default)]
         #[getset(get = "pub")]
-        frgn_ntby_qty: String, // 외국인 순매수 수량
+        sltr_yn: String, // 정리매매여부
+    }
+
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct DailyPrice {
         #[getset(get = "pub")]
-        flng_cls_code: ExCode, // 락 구분 코드
+        stck_bsop_date: KisDate, // 주식 영업 일자
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_oprc: Option<i64>, // 주식 시가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_hgpr: Option<i64>, // 주식 최고가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_lwpr: Option<i64>, // 주식 최저가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_clpr: Option<i64>, // 주식 종가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vrss_vol_rate: Option<f64>, // 전일 대비 거래량 비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vrss: Option<i64>, // 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_ctrt: Option<f64>, // 전일 대비율
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        hts_frgn_ehrt: Option<f64>, // HTS 외국인 소진율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        frgn_ntby_qty: Option<i64>, // 외국인 순매수 수량
         #[getset(get = "pub")]
-        acml_prtt_rate: String, // 누적 분할 비율
+        flng_cls_code: ExCode, // 락 구분 코드
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_prtt_rate: Option<f64>, // 누적 분할 비율
     }
 
-    #[derive(Clone, Debug, Deserialize, Getters)]
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
     pub struct PeriodicPrice {
+        #[getset(get = "pub")]
+        stck_bsop_date: String, // 주식 영업 일자
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_oprc: Option<i64>, // 주식 시가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_hgpr: Option<i64>, // 주식 최고가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_lwpr: Option<i64>, // 주식 최저가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_clpr: Option<i64>, // 주식 종가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_tr_pbmn: Option<i64>, // 누적 거래 대금
+    }
+
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct MinuteCandle {
         #[getset(get = "pub")]
         stck_bsop_date: String, // 주식 영업 일자
         #[getset(get = "pub")]
-        stck_oprc: String, // 주식 시가
+        stck_cntg_hour: String, // 주식 체결 시간(HHMMSS)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_prpr: Option<i64>, // 주식 현재가(종가)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_oprc: Option<i64>, // 주식 시가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_hgpr: Option<i64>, // 주식 최고가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_lwpr: Option<i64>, // 주식 최저가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        cntg_vol: Option<i64>, // 체결 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_tr_pbmn: Option<i64>, // 누적 거래 대금
+    }
+
+    #[derive(Clone, Debug, Deserialize, Getters)]
+    pub struct Holiday {
+        #[getset(get = "pub")]
+        bass_dt: String, // 기준일자(YYYYMMDD)
         #[getset(get = "pub")]
-        stck_hgpr: String, // 주식 최고가
+        wday_dvsn_cd: String, // 요일구분코드(01: 일요일 ~ 07: 토요일)
         #[getset(get = "pub")]
-        stck_lwpr: String, // 주식 최저가
+        bzdy_yn: String, // 영업일여부(금융기관 업무일)
         #[getset(get = "pub")]
-        stck_clpr: String, // 주식 종가
+        tr_day_yn: String, // 거래일여부(입출금 가능일)
         #[getset(get = "pub")]
-        acml_vol: String, // 누적 거래량
+        opnd_yn: String, // 개장일여부(주식시장 개장일, 주문 가능일)
         #[getset(get = "pub")]
-        acml_tr_pbmn: String, // 누적 거래 대금
+        sttl_day_yn: String, // 결제일여부
     }
 
-    #[derive(Clone, Debug, Deserialize, Getters)]
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
     pub struct VolumeRank {
         #[getset(get = "pub")]
         hts_kor_isnm: String, // HTS 한글 종목명
         #[getset(get = "pub")]
         mksc_shrn_iscd: String, // 유가증권 단축 종목코드
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        data_rank: Option<i64>, // 데이터 순위
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_prpr: Option<i64>, // 주식 현재가
         #[getset(get = "pub")]
-        data_rank: String, // 데이터 순위
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vrss: Option<i64>, // 전일 대비
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_ctrt: Option<f64>, // 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vol: Option<i64>, // 전일 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        lstn_stcn: Option<i64>, // 상장 주수
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        avrg_vol: Option<i64>, // 평균 거래량
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        n_befr_clpr_vrss_prpr_rate: Option<f64>, // N일전종가대비현재가대비율
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        vol_inrt: Option<f64>, // 거래량 증가율
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        vol_tnrt: Option<f64>, // 거래량 회전율
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        nday_vol_tnrt: Option<f64>, // N일 거래량 회전율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        avrg_tr_pbmn: Option<i64>, // 평균 거래 대금
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        tr_pbmn_tnrt: Option<f64>, // 거래대금회전율
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        nday_tr_pbmn_tnrt: Option<f64>, // N일 거래대금 회전율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_tr_pbmn: Option<i64>, // 누적 거래 대금
+    }
+
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct IndexPrice {
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        bstp_nmix_prpr: Option<f64>, // 업종 지수 현재가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        bstp_nmix_prdy_vrss: Option<f64>, // 업종 지수 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        bstp_nmix_prdy_ctrt: Option<f64>, // 업종 지수 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vol: Option<i64>, // 전일 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_tr_pbmn: Option<i64>, // 누적 거래 대금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_tr_pbmn: Option<i64>, // 전일 거래 대금
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        bstp_nmix_oprc: Option<f64>, // 업종 지수 시가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        bstp_nmix_hgpr: Option<f64>, // 업종 지수 최고가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        bstp_nmix_lwpr: Option<f64>, // 업종 지수 최저가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        ascn_issu_cnt: Option<i64>, // 상승 종목 수
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        uplm_issu_cnt: Option<i64>, // 상한 종목 수
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stnr_issu_cnt: Option<i64>, // 보합 종목 수
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        down_issu_cnt: Option<i64>, // 하락 종목 수
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        lslm_issu_cnt: Option<i64>, // 하한 종목 수
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        dryy_bstp_nmix_hgpr: Option<f64>, // 연중업종지수최고가
         #[getset(get = "pub")]
-        stck_prpr: String, // 주식 현재가
+        dryy_bstp_nmix_hgpr_date: String, // 연중업종지수최고가일자
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        dryy_bstp_nmix_lwpr: Option<f64>, // 연중업종지수최저가
+        #[getset(get = "pub")]
+        dryy_bstp_nmix_lwpr_date: String, // 연중업종지수최저가일자
+    }
+
+    /// 호가 한 단계(가격, 잔량)
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, CopyGetters)]
+    pub struct PriceLevel {
+        #[getset(get_copy = "pub")]
+        price: Option<i64>, // 호가
+        #[getset(get_copy = "pub")]
+        quantity: Option<i64>, // 호가 잔량
+    }
+
+    /// 10단계 매도/매수 호가
+    /// `asks[0]`, `bids[0]`이 각각 매도1호가, 매수1호가
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    #[serde(try_from = "HashMap<String, String>")]
+    pub struct Orderbook {
+        #[getset(get = "pub")]
+        aspr_acpt_hour: String, // 호가 접수 시간(HHMMSS)
+        #[getset(get = "pub")]
+        asks: [PriceLevel; 10], // 매도호가1~10(askp1~10, askp_rsqn1~10)
+        #[getset(get = "pub")]
+        bids: [PriceLevel; 10], // 매수호가1~10(bidp1~10, bidp_rsqn1~10)
+        #[getset(get_copy = "pub")]
+        total_askp_rsqn: Option<i64>, // 총 매도호가 잔량
+        #[getset(get_copy = "pub")]
+        total_bidp_rsqn: Option<i64>, // 총 매수호가 잔량
+    }
+
+    impl TryFrom<HashMap<String, String>> for Orderbook {
+        type Error = String;
+
+        fn try_from(fields: HashMap<String, String>) -> Result<Self, Self::Error> {
+            let number = |key: &str| -> Result<Option<i64>, String> {
+                match fields.get(key).map(|v| v.trim()) {
+                    None | Some("") => Ok(None),
+                    Some(v) => v.parse().map(Some).map_err(|e| format!("{}: {}", key, e)),
+                }
+            };
+            let mut asks = [PriceLevel::default(); 10];
+            let mut bids = [PriceLevel::default(); 10];
+            for i in 0..10 {
+                asks[i] = PriceLevel {
+                    price: number(&format!("askp{}", i + 1))?,
+                    quantity: number(&format!("askp_rsqn{}", i + 1))?,
+                };
+                bids[i] = PriceLevel {
+                    price: number(&format!("bidp{}", i + 1))?,
+                    quantity: number(&format!("bidp_rsqn{}", i + 1))?,
+                };
+            }
+            Ok(Self {
+                aspr_acpt_hour: fields.get("aspr_acpt_hour").cloned().unwrap_or_default(),
+                asks,
+                bids,
+                total_askp_rsqn: number("total_askp_rsqn")?,
+                total_bidp_rsqn: number("total_bidp_rsqn")?,
+            })
+        }
+    }
+
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct ExpectedConclusion {
+        #[getset(get = "pub")]
+        antc_mkop_cls_code: String, // 예상 장운영 구분 코드
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_prpr: Option<i64>, // 주식 현재가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_sdpr: Option<i64>, // 주식 기준가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        antc_cnpr: Option<i64>, // 예상 체결가
+        #[getset(get = "pub")]
+        antc_cntg_vrss_sign: VsPriceSign, // 예상 체결 대비 부호
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        antc_cntg_vrss: Option<i64>, // 예상 체결 대비
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        antc_cntg_prdy_ctrt: Option<f64>, // 예상 체결 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        antc_vol: Option<i64>, // 예상 거래량(예상 체결 수량)
+        #[getset(get = "pub")]
+        stck_shrn_iscd: String, // 주식 단축 종목코드
+        #[getset(get = "pub")]
+        vi_cls_code: String, // VI적용구분코드
+    }
+
+    /// 일자별 투자자 매매동향(순매수는 음수이면 순매도)
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct InvestorTrend {
+        #[getset(get = "pub")]
+        stck_bsop_date: String, // 주식 영업 일자
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_clpr: Option<i64>, // 주식 종가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vrss: Option<i64>, // 전일 대비
         #[getset(get = "pub")]
         prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prsn_ntby_qty: Option<i64>, // 개인 순매수 수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        frgn_ntby_qty: Option<i64>, // 외국인 순매수 수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        orgn_ntby_qty: Option<i64>, // 기관계 순매수 수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prsn_ntby_tr_pbmn: Option<i64>, // 개인 순매수 거래 대금(백만원)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        frgn_ntby_tr_pbmn: Option<i64>, // 외국인 순매수 거래 대금(백만원)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        orgn_ntby_tr_pbmn: Option<i64>, // 기관계 순매수 거래 대금(백만원)
+    }
+
+    impl InvestorTrend {
+        /// 투자자별 순매수 수량
+        pub fn net_buy_qty(&self, investor: Investor) -> Option<i64> {
+            match investor {
+                Investor::Individual => self.prsn_ntby_qty,
+                Investor::Foreign => self.frgn_ntby_qty,
+                Investor::Institution => self.orgn_ntby_qty,
+            }
+        }
+
+        /// 투자자별 순매수 거래 대금(백만원)
+        pub fn net_buy_value(&self, investor: Investor) -> Option<i64> {
+            match investor {
+                Investor::Individual => self.prsn_ntby_tr_pbmn,
+                Investor::Foreign => self.frgn_ntby_tr_pbmn,
+                Investor::Institution => self.orgn_ntby_tr_pbmn,
+            }
+        }
+    }
+
+    /// ELW 현재가 시세
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct ElwPrice {
+        #[serde(default)]
+        #[getset(get = "pub")]
+        elw_shrn_iscd: String, // ELW 단축 종목코드
+        #[getset(get = "pub")]
+        hts_kor_isnm: String, // HTS 한글 종목명
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        elw_prpr: Option<i64>, // ELW 현재가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vrss: Option<i64>, // 전일 대비
         #[getset(get = "pub")]
-        prdy_vrss: String, // 전일 대비
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_ctrt: Option<f64>, // 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[getset(get = "pub")]
+        unas_shrn_iscd: String, // 기초자산 단축 종목코드
         #[getset(get = "pub")]
-        prdy_ctrt: String, // 전일 대비율
+        unas_isnm: String, // 기초자산 종목명
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        unas_prpr: Option<f64>, // 기초자산 현재가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        hts_thpr: Option<f64>, // HTS 이론가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        dprt: Option<f64>, // 괴리율
         #[getset(get = "pub")]
-        acml_vol: String, // 누적 거래량
+        atm_cls_name: String, // ATM 구분 명
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        hts_ints_vltl: Option<f64>, // HTS 내재 변동성
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        acpr: Option<f64>, // 행사가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        hts_lvrg: Option<f64>, // HTS 레버리지
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        gear: Option<f64>, // 기어링
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        tmvl_val: Option<i64>, // 시간가치 값
+        #[getset(get = "pub")]
+        stck_last_tr_date: String, // 최종 거래일
+    }
+
+    /// ELW 민감도 추이(일별)
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct ElwSensitivity {
         #[getset(get = "pub")]
-        prdy_vol: String, // 전일 거래량
+        stck_bsop_date: String, // 주식 영업 일자
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        elw_prpr: Option<i64>, // ELW 현재가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        hts_thpr: Option<f64>, // HTS 이론가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        delta_val: Option<f64>, // 델타
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        gama: Option<f64>, // 감마
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        theta: Option<f64>, // 세타
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        vega: Option<f64>, // 베가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        rho: Option<f64>, // 로우
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        hts_ints_vltl: Option<f64>, // HTS 내재 변동성
+    }
+
+    /// ELW 기초자산별 종목
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct ElwUnderlying {
+        #[getset(get = "pub")]
+        elw_shrn_iscd: String, // ELW 단축 종목코드
+        #[getset(get = "pub")]
+        hts_kor_isnm: String, // HTS 한글 종목명
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        elw_prpr: Option<i64>, // ELW 현재가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        acpr: Option<f64>, // 행사가
+        #[getset(get = "pub")]
+        hts_rmnn_dynu: String, // HTS 잔존 일수
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        hts_ints_vltl: Option<f64>, // HTS 내재 변동성
         #[getset(get = "pub")]
         lstn_stcn: String, // 상장 주수
+    }
+
+    /// 프로그램매매 종합현황(시간대별)
+    /// 순매수는 음수이면 순매도
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct ProgramTrade {
+        #[getset(get = "pub")]
+        bsop_hour: String, // 영업 시간(HHMMSS)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        arbt_smtn_seln_vol: Option<i64>, // 차익 합계 매도 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        arbt_smtn_shnu_vol: Option<i64>, // 차익 합계 매수 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        arbt_smtn_ntby_qty: Option<i64>, // 차익 합계 순매수 수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        arbt_smtn_seln_tr_pbmn: Option<i64>, // 차익 합계 매도 거래대금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        arbt_smtn_shnu_tr_pbmn: Option<i64>, // 차익 합계 매수 거래대금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        arbt_smtn_ntby_tr_pbmn: Option<i64>, // 차익 합계 순매수 거래대금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nabt_smtn_seln_vol: Option<i64>, // 비차익 합계 매도 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nabt_smtn_shnu_vol: Option<i64>, // 비차익 합계 매수 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nabt_smtn_ntby_qty: Option<i64>, // 비차익 합계 순매수 수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nabt_smtn_seln_tr_pbmn: Option<i64>, // 비차익 합계 매도 거래대금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nabt_smtn_shnu_tr_pbmn: Option<i64>, // 비차익 합계 매수 거래대금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        nabt_smtn_ntby_tr_pbmn: Option<i64>, // 비차익 합계 순매수 거래대금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        whol_smtn_ntby_qty: Option<i64>, // 전체 합계 순매수 수량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        whol_smtn_ntby_tr_pbmn: Option<i64>, // 전체 합계 순매수 거래대금
+    }
+
+    impl ProgramTrade {
+        /// 차익거래
+        pub fn arbitrage(&self) -> ProgramTradeFlow {
+            ProgramTradeFlow {
+                sell_qty: self.arbt_smtn_seln_vol,
+                buy_qty: self.arbt_smtn_shnu_vol,
+                net_buy_qty: self.arbt_smtn_ntby_qty,
+                sell_value: self.arbt_smtn_seln_tr_pbmn,
+                buy_value: self.arbt_smtn_shnu_tr_pbmn,
+                net_buy_value: self.arbt_smtn_ntby_tr_pbmn,
+            }
+        }
+
+        /// 비차익거래
+        pub fn non_arbitrage(&self) -> ProgramTradeFlow {
+            ProgramTradeFlow {
+                sell_qty: self.nabt_smtn_seln_vol,
+                buy_qty: self.nabt_smtn_shnu_vol,
+                net_buy_qty: self.nabt_smtn_ntby_qty,
+                sell_value: self.nabt_smtn_seln_tr_pbmn,
+                buy_value: self.nabt_smtn_shnu_tr_pbmn,
+                net_buy_value: self.nabt_smtn_ntby_tr_pbmn,
+            }
+        }
+    }
+
+    /// 차익/비차익 프로그램매매 수량과 거래대금
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, CopyGetters)]
+    #[getset(get_copy = "pub")]
+    pub struct ProgramTradeFlow {
+        sell_qty: Option<i64>,      // 매도 거래량
+        buy_qty: Option<i64>,       // 매수 거래량
+        net_buy_qty: Option<i64>,   // 순매수 수량
+        sell_value: Option<i64>,    // 매도 거래대금
+        buy_value: Option<i64>,     // 매수 거래대금
+        net_buy_value: Option<i64>, // 순매수 거래대금
+    }
+
+    /// 관심종목(멀티종목) 시세
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct WatchlistPrice {
+        #[getset(get = "pub")]
+        inter_shrn_iscd: String, // 관심 단축 종목코드
+        #[getset(get = "pub")]
+        inter_kor_isnm: String, // 관심 한글 종목명
+        #[serde(default)]
+        #[getset(get = "pub")]
+        kospi_kosdaq_cls_name: String, // 코스피 코스닥 구분 명
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        inter2_prpr: Option<i64>, // 관심2 현재가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        inter2_prdy_vrss: Option<i64>, // 관심2 전일 대비
         #[getset(get = "pub")]
-        avrg_vol: String, // 평균 거래량
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_ctrt: Option<f64>, // 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_tr_pbmn: Option<i64>, // 누적 거래 대금
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        inter2_oprc: Option<i64>, // 관심2 시가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        inter2_hgpr: Option<i64>, // 관심2 고가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        inter2_lwpr: Option<i64>, // 관심2 저가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        inter2_prdy_clpr: Option<i64>, // 관심2 전일 종가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        inter2_askp: Option<i64>, // 관심2 매도호가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        inter2_bidp: Option<i64>, // 관심2 매수호가
+    }
+
+    /// 등락률 순위
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct FluctuationRank {
         #[getset(get = "pub")]
-        n_befr_clpr_vrss_prpr_rate: String, // N일전종가대비현재가대비율
+        stck_shrn_iscd: String, // 주식 단축 종목코드
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        data_rank: Option<i64>, // 데이터 순위
         #[getset(get = "pub")]
-        vol_inrt: String, // 거래량 증가율
+        hts_kor_isnm: String, // HTS 한글 종목명
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_prpr: Option<i64>, // 주식 현재가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vrss: Option<i64>, // 전일 대비
         #[getset(get = "pub")]
-        vol_tnrt: String, // 거래량 회전율
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_ctrt: Option<f64>, // 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+    }
+
+    /// 순위 조회(등락률, 시가총액, 거래대금) 공통 항목
+    /// 조회 종류에 없는 항목은 None
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct RankingItem {
+        #[serde(alias = "stck_shrn_iscd")]
         #[getset(get = "pub")]
-        nday_vol_tnrt: String, // N일 거래량 회전율
+        mksc_shrn_iscd: String, // 단축 종목코드
         #[getset(get = "pub")]
-        avrg_tr_pbmn: String, // 평균 거래 대금
+        hts_kor_isnm: String, // HTS 한글 종목명
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        data_rank: Option<i64>, // 데이터 순위
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_prpr: Option<i64>, // 주식 현재가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vrss: Option<i64>, // 전일 대비
         #[getset(get = "pub")]
-        tr_pbmn_tnrt: String, // 거래대금회전율
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_ctrt: Option<f64>, // 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_tr_pbmn: Option<i64>, // 누적 거래 대금(거래대금 순위)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        lstn_stcn: Option<i64>, // 상장 주수(거래대금, 시가총액 순위)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_avls: Option<i64>, // 시가 총액(억원, 시가총액 순위)
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        mrkt_whol_avls_rlim: Option<f64>, // 시장 전체 시가총액 비중(시가총액 순위)
+    }
+
+    /// 시간대별체결 현재가 요약
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct ConclusionSummary {
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_prpr: Option<i64>, // 주식 현재가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vrss: Option<i64>, // 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_ctrt: Option<f64>, // 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vol: Option<i64>, // 전일 거래량
+        #[serde(default)]
         #[getset(get = "pub")]
-        nday_tr_pbmn_tnrt: String, // N일 거래대금 회전율
+        rprs_mrkt_kor_name: String, // 대표 시장 한글 명
+    }
+
+    /// 시간대별 체결
+    #[derive(Clone, Debug, Deserialize, Getters, CopyGetters)]
+    pub struct TimeConclusion {
         #[getset(get = "pub")]
-        acml_tr_pbmn: String, // 누적 거래 대금
+        stck_cntg_hour: String, // 주식 체결 시간(HHMMSS)
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        stck_prpr: Option<i64>, // 주식 현재가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_vrss: Option<i64>, // 전일 대비
+        #[getset(get = "pub")]
+        prdy_vrss_sign: VsPriceSign, // 전일 대비 부호
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        prdy_ctrt: Option<f64>, // 전일 대비율
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        askp: Option<i64>, // 매도호가
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        bidp: Option<i64>, // 매수호가
+        #[serde(default, deserialize_with = "de_f64_from_str")]
+        #[getset(get_copy = "pub")]
+        tday_rltv: Option<f64>, // 당일 체결강도
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        acml_vol: Option<i64>, // 누적 거래량
+        #[serde(default, deserialize_with = "de_i64_from_str")]
+        #[getset(get_copy = "pub")]
+        cnqn: Option<i64>, // 체결량
+    }
+
+    impl TimeConclusion {
+        /// 호가 기준 추정 체결 방향(매도호가 이상: Bid(매수), 매수호가 이하: Ask(매도))
+        pub fn estimated_side(&self) -> Option<Direction> {
+            let price = self.stck_prpr?;
+            match (self.askp, self.bidp) {
+                (Some(askp), _) if askp > 0 && price >= askp => Some(Direction::Bid),
+                (_, Some(bidp)) if bidp > 0 && price <= bidp => Some(Direction::Ask),
+                _ => None,
+            }
+        }
+    }
+
+    /// 배당일정(한국예탁결제원 제공)
+    #[derive(Clone, Debug, Deserialize, Getters)]
+    #[getset(get = "pub")]
+    pub struct DividendSchedule {
+        record_date: String,      // 기준일
+        sht_cd: String,           // 종목코드
+        isin_name: String,        // 종목명
+        divi_kind: String,        // 배당종류(결산, 중간, 분기)
+        face_val: String,         // 액면가
+        per_sto_divi_amt: String, // 현금배당금(주당)
+        divi_rate: String,        // 현금배당률(%, 액면가 기준)
+        stk_divi_rate: String,    // 주식배당률(%)
+        divi_pay_dt: String,      // 배당금지급일
+        #[serde(default)]
+        stk_div_pay_dt: String, // 주식배당지급일
+        #[serde(default)]
+        odd_pay_dt: String, // 단주대금지급일
+        #[serde(default)]
+        stk_kind: String, // 주식종류
+        #[serde(default)]
+        high_divi_gb: String, // 고배당종목여부
+    }
+
+    /// 주식 기본 정보
+    #[derive(Clone, Debug, Deserialize, Getters)]
+    #[getset(get = "pub")]
+    pub struct StockBasicInfo {
+        pdno: String,           // 상품번호
+        prdt_type_cd: String,   // 상품유형코드
+        mket_id_cd: String,     // 시장ID코드(STK: 유가증권, KSQ: 코스닥, KNX: 코넥스)
+        scty_grp_id_cd: String, // 증권그룹ID코드(ST: 주권, EF: ETF, EW: ELW 등)
+        prdt_name: String,      // 상품명
+        prdt_abrv_name: String, // 상품약어명
+        prdt_eng_name: String,  // 상품영문명
+        #[serde(default)]
+        std_idst_clsf_cd_name: String, // 표준산업분류코드명
+        #[serde(default)]
+        idx_bztp_lcls_cd_name: String, // 지수업종대분류코드명
+        #[serde(default)]
+        scts_mket_lstg_dt: String, // 유가증권시장상장일자
+        #[serde(default)]
+        kosdaq_mket_lstg_dt: String, // 코스닥시장상장일자
+        #[serde(default)]
+        lstg_abol_dt: String, // 상장폐지일자
+        #[serde(default)]
+        tr_stop_yn: String, // 거래정지여부
+        #[serde(default)]
+        admn_item_yn: String, // 관리종목여부
+    }
+}
+
+/// 종목 검색 결과
+/// 주식기본조회 또는 종목 마스터 파일에서 만들어짐
+#[derive(Clone, Debug, Getters, CopyGetters)]
+pub struct StockInfo {
+    #[getset(get = "pub")]
+    code: String, // 단축 종목코드
+    #[getset(get = "pub")]
+    name: String, // 한글 종목명
+    #[getset(get_copy = "pub")]
+    market: ListedMarket, // 상장 시장
+    #[getset(get = "pub")]
+    sector: String, // 업종(주식기본조회: 지수업종대분류명, 마스터 파일: 지수업종대분류코드)
+    #[getset(get_copy = "pub")]
+    listed: bool, // 상장 여부
+    #[getset(get_copy = "pub")]
+    suspended: Option<bool>, // 거래정지 여부(마스터 파일은 None)
+}
+
+impl StockInfo {
+    pub fn new(
+        code: String,
+        name: String,
+        market: ListedMarket,
+        sector: String,
+        listed: bool,
+        suspended: Option<bool>,
+    ) -> Self {
+        Self {
+            code,
+            name,
+            market,
+            sector,
+            listed,
+            suspended,
+        }
+    }
+}
+
+impl From<&output::StockBasicInfo> for StockInfo {
+    fn from(info: &output::StockBasicInfo) -> Self {
+        Self {
+            code: info.pdno().trim().to_string(),
+            name: info.prdt_abrv_name().trim().to_string(),
+            market: ListedMarket::from(info.mket_id_cd().as_str()),
+            sector: info.idx_bztp_lcls_cd_name().trim().to_string(),
+            listed: info.lstg_abol_dt().trim().trim_matches('0').is_empty(),
+            suspended: Some(info.tr_stop_yn() == "Y"),
+        }
+    }
+}
+
+/// 종목 상태(관리/시장경고/거래정지/단기과열)와 외국인 소진율
+/// 주식현재가 시세 응답의 상태 코드를 해석한 값
+#[derive(Clone, Copy, Debug, Default, PartialEq, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct StockStatus {
+    administrated: bool,            // 관리종목
+    investment_caution: bool,       // 투자주의
+    investment_warning: bool,       // 투자경고
+    investment_danger: bool,        // 투자위험
+    suspended: bool,                // 거래정지(임시정지 포함)
+    short_term_overheat: bool,      // 단기과열
+    settlement_trading: bool,       // 정리매매
+    foreign_ownership: Option<f64>, // 외국인 소진율(%)
+}
+
+impl From<&output::CurrentPrice> for StockStatus {
+    fn from(price: &output::CurrentPrice) -> Self {
+        // 종목 상태 구분 코드(51: 관리종목, 52: 투자위험, 53: 투자경고, 54: 투자주의, 58: 거래정지, 59: 단기과열)
+        let stat = price.iscd_stat_cls_code().as_str();
+        let warn = price.mrkt_warn_cls_code().as_str();
+        Self {
+            administrated: price.mang_issu_cls_code() == "Y" || stat == "51",
+            investment_caution: warn == "01" || stat == "54",
+            investment_warning: warn == "02" || stat == "53",
+            investment_danger: warn == "03" || stat == "52",
+            suspended: price.temp_stop_yn() == "Y" || stat == "58",
+            short_term_overheat: price.short_over_yn() == "Y" || stat == "59",
+            settlement_trading: price.sltr_yn() == "Y",
+            foreign_ownership: price.hts_frgn_ehrt(),
+        }
+    }
+}
+
+/// 가격제한폭 도달 구분
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum LimitKind {
+    Upper, // 상한가
+    Lower, // 하한가
+}
+
+/// 상한가/하한가 종목
+#[derive(Clone, Debug, PartialEq, Getters, CopyGetters)]
+pub struct LimitStock {
+    #[getset(get = "pub")]
+    code: String, // 단축 종목코드
+    #[getset(get = "pub")]
+    name: String, // 종목명
+    #[getset(get_copy = "pub")]
+    price: Option<i64>, // 현재가
+    #[getset(get_copy = "pub")]
+    change_rate: Option<f64>, // 전일 대비율(%)
+    #[getset(get_copy = "pub")]
+    kind: LimitKind, // 상한가/하한가
+}
+
+impl LimitStock {
+    /// 전일 대비 부호가 상한(1) 또는 하한(4)인 순위 항목. 그 외는 None
+    pub fn from_rank(rank: &output::FluctuationRank) -> Option<Self> {
+        let kind = match rank.prdy_vrss_sign() {
+            PriceSign::UpperLimit => LimitKind::Upper,
+            PriceSign::LowerLimit => LimitKind::Lower,
+            _ => return None,
+        };
+        Some(Self {
+            code: rank.stck_shrn_iscd().clone(),
+            name: rank.hts_kor_isnm().clone(),
+            price: rank.stck_prpr(),
+            change_rate: rank.prdy_ctrt(),
+            kind,
+        })
+    }
+}
+
+/// 배당 일정
+/// 예탁원정보(배당일정)의 날짜와 금액을 NaiveDate, Decimal로 변환한 값. 비어 있거나 형식이 다르면 None
+#[cfg(feature = "decimal")]
+#[derive(Clone, Debug, PartialEq, Getters, CopyGetters)]
+pub struct DividendEvent {
+    #[getset(get = "pub")]
+    shortcode: String, // 종목코드
+    #[getset(get = "pub")]
+    name: String, // 종목명
+    #[getset(get = "pub")]
+    kind: String, // 배당종류(결산, 중간, 분기)
+    #[getset(get_copy = "pub")]
+    record_date: Option<NaiveDate>, // 배당기준일
+    #[getset(get_copy = "pub")]
+    payment_date: Option<NaiveDate>, // 배당금지급일
+    #[getset(get_copy = "pub")]
+    per_share: Option<Decimal>, // 주당 현금배당금
+    #[getset(get_copy = "pub")]
+    dividend_rate: Option<Decimal>, // 현금배당률(%, 액면가 기준)
+    #[getset(get_copy = "pub")]
+    stock_dividend_rate: Option<Decimal>, // 주식배당률(%)
+}
+
+#[cfg(feature = "decimal")]
+impl From<&output::DividendSchedule> for DividendEvent {
+    fn from(schedule: &output::DividendSchedule) -> Self {
+        // 날짜는 YYYYMMDD 또는 YYYY/MM/DD, 금액은 천 단위 구분 기호가 있을 수 있음
+        let date = |s: &str| {
+            let s: String = s.chars().filter(|c| c.is_ascii_digit()).collect();
+            crate::types::de::parse_yyyymmdd(&s)
+        };
+        let decimal = |s: &str| s.trim().replace(',', "").parse::<Decimal>().ok();
+        Self {
+            shortcode: schedule.sht_cd().trim().to_string(),
+            name: schedule.isin_name().trim().to_string(),
+            kind: schedule.divi_kind().trim().to_string(),
+            record_date: date(schedule.record_date()),
+            payment_date: date(schedule.divi_pay_dt()),
+            per_share: decimal(schedule.per_sto_divi_amt()),
+            dividend_rate: decimal(schedule.divi_rate()),
+            stock_dividend_rate: decimal(schedule.stk_divi_rate()),
+        }
+    }
+}
+
+/// 국내주식기간별시세/분봉 봉
+/// PeriodicPrice, MinuteCandle의 일자와 가격을 NaiveDate, PriceValue(Decimal)로 변환한 값
+#[derive(Clone, Debug, PartialEq, Getters, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct Candle {
+    date: NaiveDate,         // 영업 일자(주/월봉은 기간의 마지막 영업일)
+    time: Option<NaiveTime>, // 분봉의 구간 시작 시각(일/주/월봉은 None)
+    open: PriceValue,        // 시가
+    high: PriceValue,        // 고가
+    low: PriceValue,         // 저가
+    close: PriceValue,       // 종가
+    volume: i64,             // 누적 거래량
+    value: PriceValue,       // 누적 거래 대금
+    filled: bool,            // 시세가 없어 직전 종가로 채운 봉
+}
+
+impl Candle {
+    /// 시세가 없는 개장일을 직전 봉의 종가로 채운 봉(거래량 0)
+    pub fn forward_filled(previous: &Candle, date: NaiveDate) -> Self {
+        Self {
+            date,
+            time: None,
+            open: previous.close,
+            high: previous.close,
+            low: previous.close,
+            close: previous.close,
+            volume: 0,
+            value: PriceValue::default(),
+            filled: true,
+        }
+    }
+}
+
+/// 일자나 종가가 없는 행은 None. 시가/고가/저가가 없으면 종가로 채움
+impl output::PeriodicPrice {
+    /// KIS 응답과 같은 형식(숫자는 문자열)의 JSON. 다시 역직렬화하면 같은 값
+    pub(crate) fn to_json(&self) -> serde_json::Value {
+        let s = |v: Option<i64>| v.map(|v| v.to_string()).unwrap_or_default();
+        serde_json::json!({
+            "stck_bsop_date": self.stck_bsop_date(),
+            "stck_oprc": s(self.stck_oprc()),
+            "stck_hgpr": s(self.stck_hgpr()),
+            "stck_lwpr": s(self.stck_lwpr()),
+            "stck_clpr": s(self.stck_clpr()),
+            "acml_vol": s(self.acml_vol()),
+            "acml_tr_pbmn": s(self.acml_tr_pbmn()),
+        })
+    }
+}
+
+impl TryFrom<&output::PeriodicPrice> for Candle {
+    type Error = ();
+
+    fn try_from(price: &output::PeriodicPrice) -> Result<Self, Self::Error> {
+        let date = crate::types::de::parse_yyyymmdd(price.stck_bsop_date()).ok_or(())?;
+        let close = PriceValue::from(price.stck_clpr().ok_or(())?);
+        let or_close = |v: Option<i64>| v.map_or(close, PriceValue::from);
+        Ok(Self {
+            date,
+            time: None,
+            open: or_close(price.stck_oprc()),
+            high: or_close(price.stck_hgpr()),
+            low: or_close(price.stck_lwpr()),
+            close,
+            volume: price.acml_vol().unwrap_or_default(),
+            value: PriceValue::from(price.acml_tr_pbmn().unwrap_or_default()),
+            filled: false,
+        })
+    }
+}
+
+/// 일자, 시각이나 종가가 없는 행은 None. 시가/고가/저가가 없으면 종가로 채움
+/// volume은 분봉 체결 거래량, value는 해당 시각까지의 누적 거래 대금
+impl TryFrom<&output::MinuteCandle> for Candle {
+    type Error = ();
+
+    fn try_from(candle: &output::MinuteCandle) -> Result<Self, Self::Error> {
+        let date = crate::types::de::parse_yyyymmdd(candle.stck_bsop_date()).ok_or(())?;
+        let time = crate::types::de::parse_hhmmss(candle.stck_cntg_hour()).ok_or(())?;
+        let close = PriceValue::from(candle.stck_prpr().ok_or(())?);
+        let or_close = |v: Option<i64>| v.map_or(close, PriceValue::from);
+        Ok(Self {
+            date,
+            time: Some(time),
+            open: or_close(candle.stck_oprc()),
+            high: or_close(candle.stck_hgpr()),
+            low: or_close(candle.stck_lwpr()),
+            close,
+            volume: candle.cntg_vol().unwrap_or_default(),
+            value: PriceValue::from(candle.acml_tr_pbmn().unwrap_or_default()),
+            filled: false,
+        })
+    }
+}
+
+/// 봉 CSV의 열(Candle::write_csv_row)
+pub(crate) const CANDLE_CSV_HEADER: &str = "date,open,high,low,close,volume,value\n";
+
+impl PeriodicPriceResponse {
+    /// output2의 봉을 CSV(date, open, high, low, close, volume, value)로 기록. 행 순서는 응답 순서(최신 일자부터)
+    /// 일자나 종가가 없는 행은 제외
+    pub fn write_csv<W: std::io::Write>(&self, mut w: W) -> Result<(), crate::Error> {
+        w.write_all(CANDLE_CSV_HEADER.as_bytes())?;
+        for candle in self
+            .output2
+            .iter()
+            .flatten()
+            .filter_map(|c| Candle::try_from(c).ok())
+        {
+            candle.write_csv_row(&mut w)?;
+        }
+        w.flush()?;
+        Ok(())
+    }
+}
+
+impl Candle {
+    /// CSV 한 행(CANDLE_CSV_HEADER의 열 순서). date는 YYYY-MM-DD
+    pub(crate) fn write_csv_row<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        writeln!(
+            w,
+            "{},{},{},{},{},{},{}",
+            self.date.format("%Y-%m-%d"),
+            self.open,
+            self.high,
+            self.low,
+            self.close,
+            self.volume,
+            self.value
+        )
+    }
+
+    /// 시간순으로 정렬된 candles를 하나의 봉으로 합침(시가는 처음, 고가는 최대, 저가는 최소, 종가는 마지막, 거래량은 합계)
+    /// 분봉의 거래 대금은 누적 값이므로 마지막 봉의 값, 일봉은 합계
+    pub(crate) fn merge(candles: &[Candle], time: Option<NaiveTime>) -> Option<Self> {
+        let first = candles.first()?;
+        let last = candles.last()?;
+        let value = match first.time {
+            Some(_) => last.value,
+            None => candles.iter().map(|c| c.value).sum(),
+        };
+        Some(Self {
+            date: last.date,
+            time,
+            open: first.open,
+            high: candles.iter().map(|c| c.high).max()?,
+            low: candles.iter().map(|c| c.low).min()?,
+            close: last.close,
+            volume: candles.iter().map(|c| c.volume).sum(),
+            value,
+            filled: candles.iter().all(|c| c.filled),
+        })
+    }
+}
+
+/// 동시호가 예상체결
+/// 주식현재가 호가/예상체결 응답 output2(output::ExpectedConclusion)의 예상 체결가, 수량, 전일 대비 부호
+#[derive(Clone, Copy, Debug, PartialEq, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct ExpectedConclusion {
+    price: Price,    // 예상 체결가
+    quantity: u64,   // 예상 체결 수량
+    sign: PriceSign, // 예상 체결 대비 부호
+}
+
+/// 예상 체결가가 없으면(동시호가 시간이 아니면) None
+impl TryFrom<&output::ExpectedConclusion> for ExpectedConclusion {
+    type Error = ();
+
+    fn try_from(expected: &output::ExpectedConclusion) -> Result<Self, Self::Error> {
+        let price = expected.antc_cnpr().filter(|p| *p > 0).ok_or(())?;
+        Ok(Self {
+            price: Price::from_value(PriceValue::from(price)),
+            quantity: expected
+                .antc_vol()
+                .and_then(|v| u64::try_from(v).ok())
+                .unwrap_or_default(),
+            sign: *expected.antc_cntg_vrss_sign(),
+        })
+    }
+}
+
+/// 관심종목 시세조회 결과(Quote::watchlist_prices)
+/// 30종목 단위 요청 중 일부가 실패해도 성공한 요청의 시세는 prices에, 실패한 요청의 종목과 에러는 failures에 담음
+#[derive(Debug, Getters)]
+#[getset(get = "pub")]
+pub struct WatchlistPrices {
+    prices: Vec<output::WatchlistPrice>, // codes 순서의 시세
+    failures: Vec<WatchlistFailure>,     // 실패한 요청
+}
+
+impl WatchlistPrices {
+    pub(crate) fn new(
+        prices: Vec<output::WatchlistPrice>,
+        failures: Vec<WatchlistFailure>,
+    ) -> Self {
+        Self { prices, failures }
+    }
+
+    /// 모든 요청이 성공했는지 여부
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// 시세만 반환(실패한 요청은 버림)
+    pub fn into_prices(self) -> Vec<output::WatchlistPrice> {
+        self.prices
+    }
+}
+
+/// 관심종목 시세조회에서 실패한 요청
+#[derive(Debug, Getters)]
+#[getset(get = "pub")]
+pub struct WatchlistFailure {
+    codes: Vec<String>,  // 요청한 종목코드
+    error: crate::Error, // 요청 에러
+}
+
+impl WatchlistFailure {
+    pub(crate) fn new(codes: Vec<String>, error: crate::Error) -> Self {
+        Self { codes, error }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 주식현재가 일자별[v1_국내주식-010] 응답(두 번째 행은 KIS가 빈 값으로 보내는 필드 포함)
+    const DAILY_PRICE: &str = r#"{"output":[
+        {"stck_bsop_date":"20240105","stck_oprc":"71200","stck_hgpr":"71800","stck_lwpr":"71000","stck_clpr":"71500","acml_vol":"14719459","prdy_vrss_vol_rate":"-7.80","prdy_vrss":"-500","prdy_vrss_sign":"5","prdy_ctrt":"-0.69","hts_frgn_ehrt":"55.45","frgn_ntby_qty":"-1040774","flng_cls_code":"00","acml_prtt_rate":"1.00"},
+        {"stck_bsop_date":"20240104","stck_oprc":"","stck_hgpr":"","stck_lwpr":"","stck_clpr":"72000","acml_vol":"","prdy_vrss_vol_rate":"","prdy_vrss":"0","prdy_vrss_sign":"3","prdy_ctrt":"0.00","hts_frgn_ehrt":"","frgn_ntby_qty":"","flng_cls_code":"02","acml_prtt_rate":""}
+    ],"rt_cd":"0","msg_cd":"MCA00000","msg1":"정상처리 되었습니다."}"#;
+
+    #[test]
+    fn daily_price_numeric_fields() {
+        let response: DailyPriceResponse = serde_json::from_str(DAILY_PRICE).unwrap();
+        let rows = response.output().as_ref().unwrap();
+        let first = &rows[0];
+        assert_eq!(
+            first.stck_bsop_date().get(),
+            NaiveDate::from_ymd_opt(2024, 1, 5)
+        );
+        assert_eq!(first.stck_clpr(), Some(71500));
+        assert_eq!(first.acml_vol(), Some(14719459));
+        assert_eq!(first.prdy_vrss(), Some(-500));
+        assert_eq!(first.prdy_ctrt(), Some(-0.69));
+        assert_eq!(first.frgn_ntby_qty(), Some(-1040774));
+        assert_eq!(*first.prdy_vrss_sign(), PriceSign::Decrease);
+
+        // 빈 문자열은 None
+        let second = &rows[1];
+        assert_eq!(second.stck_oprc(), None);
+        assert_eq!(second.acml_vol(), None);
+        assert_eq!(second.hts_frgn_ehrt(), None);
+        assert_eq!(second.stck_clpr(), Some(72000));
+        assert_eq!(second.flng_cls_code().to_string(), "02");
+    }
+
+    #[test]
+    fn malformed_number_fails_to_deserialize() {
+        let json = DAILY_PRICE.replacen(r#""stck_clpr":"71500""#, r#""stck_clpr":"71,500""#, 1);
+        assert!(serde_json::from_str::<DailyPriceResponse>(&json).is_err());
+    }
+
+    #[test]
+    fn periodic_price_json_round_trip() {
+        let json = r#"{"output2":[
+            {"stck_bsop_date":"20240105","stck_oprc":"71200","stck_hgpr":"71800","stck_lwpr":"71000","stck_clpr":"71500","acml_vol":"14719459","acml_tr_pbmn":"1052049975600","flng_cls_code":"00","prtt_rate":"0.00","mod_yn":"N","prdy_vrss_sign":"5","prdy_vrss":"-500","revl_issu_reas":""},
+            {"stck_bsop_date":"20240104","stck_oprc":"","stck_hgpr":"","stck_lwpr":"","stck_clpr":"72000","acml_vol":"0","acml_tr_pbmn":""}
+        ],"rt_cd":"0","msg_cd":"MCA00000","msg1":"정상처리 되었습니다."}"#;
+        let response: PeriodicPriceResponse = serde_json::from_str(json).unwrap();
+        let rows = response.output2().as_ref().unwrap();
+        assert_eq!(rows[0].acml_tr_pbmn(), Some(1052049975600));
+        assert_eq!(rows[1].stck_oprc(), None);
+        for row in rows {
+            let again: output::PeriodicPrice = serde_json::from_value(row.to_json()).unwrap();
+            assert_eq!(again.to_json(), row.to_json());
+            assert_eq!(again.stck_clpr(), row.stck_clpr());
+            assert_eq!(again.stck_oprc(), row.stck_oprc());
+        }
+    }
+
+    #[test]
+    fn periodic_price_write_csv_matches_fixture() {
+        let response: PeriodicPriceResponse = serde_json::from_str(include_str!(
+            "../../../../tests/fixtures/periodic_price.json"
+        ))
+        .unwrap();
+        let mut csv = Vec::new();
+        response.write_csv(&mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            include_str!("../../../../tests/fixtures/periodic_price.csv")
+        );
     }
 }
@@ -38,11 +38,11 @@ pub enum TrType {
     Unregister,
 }
 
-impl Into<String> for TrType {
-    fn into(self) -> String {
-        match self {
-            Self::Register => "1",
-            Self::Unregister => "2",
+impl From<TrType> for String {
+    fn from(value: TrType) -> String {
+        match value {
+            TrType::Register => "1",
+            TrType::Unregister => "2",
         }
         .to_string()
     }
@@ -105,9 +105,9 @@ impl Subscribe {
             self.header().app_key(),
             self.header().appsecret(),
             self.header().personalseckey(),
-            Into::<String>::into(self.header().custtype().to_owned()),
+            self.header().custtype(),
             Into::<String>::into(self.header().tr_type().to_owned()),
-            Into::<String>::into(self.body().tr_id().to_owned()),
+            self.body().tr_id(),
             self.body().tr_key(),
         )
     }
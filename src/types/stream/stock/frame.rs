@@ -0,0 +1,124 @@
+use crate::types::TrId;
+use crate::Error;
+
+/// 레코드 하나의 필드 수(평문 프레임만 확인)
+fn record_fields(tr_id: &TrId) -> Option<usize> {
+    match tr_id {
+        TrId::RealtimeExec => Some(46),
+        TrId::RealtimeOrdb => Some(59),
+        _ => None,
+    }
+}
+
+/// `암호화여부|tr_id|데이터건수|레코드^레코드^...` 형식의 프레임을 레코드 단위로 나눔
+/// 각 레코드는 데이터건수가 001인 프레임으로 다시 구성하여 Exec::parse 등에 그대로 넘길 수 있음
+/// JSON(PINGPONG, 구독 응답)과 암호화된 프레임은 나누지 않고 그대로 반환
+/// 데이터건수나 필드 수가 맞지 않으면 Error::MalformedFrame
+pub fn split_records(s: &str) -> Result<Vec<String>, Error> {
+    if s.starts_with('{') {
+        return Ok(vec![s.to_string()]);
+    }
+    let mut parts = s.splitn(4, '|');
+    let (encrypted, tr_id, count, data) =
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(e), Some(t), Some(c), Some(d)) => (e, t, c, d),
+            _ => return Err(Error::MalformedFrame(format!("missing header: {}", s))),
+        };
+    if encrypted == "1" {
+        return Ok(vec![s.to_string()]);
+    }
+    let count: usize = count
+        .parse()
+        .map_err(|_| Error::MalformedFrame(format!("invalid record count: {}", count)))?;
+    if count == 0 {
+        return Err(Error::MalformedFrame(format!("empty frame: {}", tr_id)));
+    }
+    let fields = data.split('^').collect::<Vec<&str>>();
+    let per_record = match record_fields(&TrId::from(tr_id)) {
+        Some(n) => n,
+        None if count == 1 => return Ok(vec![s.to_string()]),
+        None if fields.len() % count == 0 => fields.len() / count,
+        None => {
+            return Err(Error::MalformedFrame(format!(
+                "{} fields do not split into {} records of {}",
+                fields.len(),
+                count,
+                tr_id
+            )))
+        }
+    };
+    if fields.len() != per_record * count {
+        return Err(Error::MalformedFrame(format!(
+            "{}: expected {} records of {} fields, got {} fields",
+            tr_id,
+            count,
+            per_record,
+            fields.len()
+        )));
+    }
+    Ok(fields
+        .chunks(per_record)
+        .map(|record| format!("{}|{}|001|{}", encrypted, tr_id, record.join("^")))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 실시간체결가(H0STCNT0) 레코드 하나(46개 필드)
+    fn exec_record(shortcode: &str, price: u32) -> String {
+        format!(
+            "{}^093354^{}^5^-100^-0.14^72023.83^72100^72400^71700^71900^71800^1^3052507^219853241700^5105^6937^1832^84.90^1366314^1159996^1^0.39^20.28^090020^5^-200^090820^5^-500^092619^2^200^20230612^20^N^65945^216924^1118750^2199206^0.05^2424142^125.92^0^^72100",
+            shortcode, price
+        )
+    }
+
+    #[test]
+    fn splits_multi_record_exec_frame() {
+        let records = [
+            exec_record("005930", 71900),
+            exec_record("000660", 120500),
+            exec_record("035420", 210000),
+        ];
+        let frame = format!("0|H0STCNT0|003|{}", records.join("^"));
+        let split = split_records(&frame).unwrap();
+        assert_eq!(split.len(), 3);
+        for (record, expected) in split.iter().zip(records.iter()) {
+            assert_eq!(record, &format!("0|H0STCNT0|001|{}", expected));
+        }
+    }
+
+    #[test]
+    fn single_record_frame_is_unchanged() {
+        let frame = format!("0|H0STCNT0|001|{}", exec_record("005930", 71900));
+        assert_eq!(split_records(&frame).unwrap(), [frame]);
+    }
+
+    #[test]
+    fn record_count_mismatch_is_malformed() {
+        let two = [exec_record("005930", 71900), exec_record("000660", 120500)].join("^");
+        for frame in [
+            format!("0|H0STCNT0|003|{}", two),
+            format!("0|H0STCNT0|001|{}", two),
+            format!("0|H0STCNT0|002|{}^extra", two),
+            format!("0|H0STCNT0|000|{}", two),
+            format!("0|H0STCNT0|abc|{}", two),
+            "0|H0STCNT0".to_string(),
+        ] {
+            assert!(
+                matches!(split_records(&frame), Err(Error::MalformedFrame(_))),
+                "{}",
+                frame
+            );
+        }
+    }
+
+    #[test]
+    fn json_and_encrypted_frames_are_not_split() {
+        let pingpong = r#"{"header":{"tr_id":"PINGPONG","datetime":"20230612093354"}}"#;
+        assert_eq!(split_records(pingpong).unwrap(), [pingpong]);
+        let encrypted = "1|H0STCNI0|002|ZW5jcnlwdGVk";
+        assert_eq!(split_records(encrypted).unwrap(), [encrypted]);
+    }
+}
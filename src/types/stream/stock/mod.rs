@@ -1,4 +1,5 @@
 pub mod exec;
+pub mod frame;
 pub mod my_exec;
 pub mod ordb;
 
@@ -7,7 +8,8 @@ use crate::{
     Error,
 };
 pub use exec::Exec;
-pub use my_exec::MyExec;
+pub use frame::split_records;
+pub use my_exec::{ExecutionNotice, MyExec};
 pub use ordb::Ordb;
 
 #[derive(Debug, Clone)]
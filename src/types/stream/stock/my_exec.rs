@@ -29,31 +29,19 @@ impl MyExec {
             let header_str = &components[0..3];
             let encrypted = header_str[0] == "1";
             let mut decrypted = [0u8; BUF_SIZE];
-            let base64_decoded = match base64::prelude::BASE64_STANDARD
+            let base64_decoded = base64::prelude::BASE64_STANDARD
                 .decode(components[3].to_string().into_bytes())
-            {
-                Ok(result) => result,
-                Err(e) => {
-                    error!("base64 decode failed: {:?}", e);
-                    return Err(Error::AesInvalidLength);
-                }
-            };
+                .map_err(|e| Error::DecryptFailed(format!("base64 decode: {}", e)))?;
             if encrypted {
-                let decryptor = match Aes256CbcDec::new_from_slices(key.as_bytes(), iv.as_bytes()) {
-                    Ok(decryptor) => decryptor,
-                    Err(e) => {
-                        error!("AES cipher error: {:?}", e);
-                        return Err(Error::AesInvalidLength);
-                    }
-                };
-                if let Err(e) =
-                    decryptor.decrypt_padded_b2b_mut::<ZeroPadding>(&base64_decoded, &mut decrypted)
-                {
-                    error!("AES decrypt error: {:?}", e);
-                    return Err(Error::AesDecryptError(e.to_string()));
-                }
+                let decryptor = Aes256CbcDec::new_from_slices(key.as_bytes(), iv.as_bytes())
+                    .map_err(|e| Error::DecryptFailed(format!("AES key/iv: {}", e)))?;
+                decryptor
+                    .decrypt_padded_b2b_mut::<ZeroPadding>(&base64_decoded, &mut decrypted)
+                    .map_err(|e| Error::DecryptFailed(format!("AES decrypt: {}", e)))?;
             }
-            let decrypted = std::str::from_utf8(decrypted.as_slice())?;
+            // 잘못된 key/iv로 복호화하면 UTF-8이 아닌 값이 나옴
+            let decrypted = std::str::from_utf8(decrypted.as_slice())
+                .map_err(|e| Error::DecryptFailed(format!("UTF-8: {}", e)))?;
             let splits = decrypted.split('^').collect::<Vec<&str>>();
             let business_operation_date = Time::now().date();
             let exec_time = Time::parse(&(business_operation_date + splits[11]), "%Y%m%d%H%M%S")?;
@@ -65,7 +53,7 @@ impl MyExec {
                 cust_id: splits[0].to_string(),
                 acnt_no: splits[1].to_string(),
                 order_no: splits[2].parse()?,
-                origin_order_no: splits[3].parse().unwrap_or_else(|_| 0u64),
+                origin_order_no: splits[3].parse().unwrap_or(0u64),
                 ask_bid_class: splits[4].into(),
                 correction_class: splits[5].into(),
                 order_kind: splits[6].into(),
@@ -75,17 +63,14 @@ impl MyExec {
                 exec_price: splits[10].parse()?,
                 exec_time,
                 is_refused: parse_bool(splits[12]),
-                is_executed: parse_bool(splits[13]),
+                is_executed: splits[13] == "2", // 1: 주문·정정·취소·거부, 2: 체결
                 is_accepted: parse_bool(splits[14]),
                 branch_no: splits[15].to_string(),
                 order_qty: splits[16].parse()?,
                 account_name: splits[17].to_string(),
                 stock_name: splits[18].to_string(),
                 credit_class: splits[19].to_string(),
-                credit_loan_date: match Time::parse(splits[20], "%Y%m%d") {
-                    Ok(d) => Some(d),
-                    Err(_) => None,
-                },
+                credit_loan_date: Time::parse(splits[20], "%Y%m%d").ok(),
                 stock_name_40: splits[21].to_string(),
             });
             Ok(Self { header, body })
@@ -99,6 +84,44 @@ impl MyExec {
     pub fn body(&self) -> &Option<Body> {
         &self.body
     }
+
+    /// 복호화한 체결통보(구독 응답 등 본문이 없는 메시지는 None)
+    pub fn execution_notice(&self) -> Option<ExecutionNotice> {
+        self.body.as_ref().map(ExecutionNotice::from)
+    }
+}
+
+/// 체결통보 한 건
+/// 같은 TR로 주문 접수·정정·취소·거부 통보도 오므로 is_fill로 구분
+#[derive(Debug, Clone)]
+pub struct ExecutionNotice {
+    pub order_no: u64,        // 주문번호
+    pub origin_order_no: u64, // 원주문번호
+    pub shortcode: String,    // 종목코드
+    pub side: Direction,      // 매도매수구분
+    pub filled_qty: u64,      // 체결 수량
+    pub filled_price: u32,    // 체결단가
+    pub order_qty: u64,       // 주문수량
+    pub is_fill: bool,        // 체결 통보 여부(false면 접수·정정·취소·거부 통보)
+    pub is_refused: bool,     // 거부여부
+    pub time: Time,           // 체결 시간
+}
+
+impl From<&Body> for ExecutionNotice {
+    fn from(body: &Body) -> Self {
+        Self {
+            order_no: body.order_no,
+            origin_order_no: body.origin_order_no,
+            shortcode: body.shortcode.clone(),
+            side: body.ask_bid_class.clone(),
+            filled_qty: body.exec_qty,
+            filled_price: body.exec_price,
+            order_qty: body.order_qty,
+            is_fill: body.is_executed,
+            is_refused: body.is_refused,
+            time: body.exec_time.clone(),
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -126,3 +149,97 @@ pub struct Body {
     pub credit_loan_date: Option<Time>,    // CRDT_LOAN_DATE(신용대출일자)
     pub stock_name_40: String,             // CNTG_ISNM40(체결종목명40)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aes::cipher::BlockEncryptMut;
+
+    const KEY: &str = "0123456789abcdef0123456789abcdef";
+    const IV: &str = "fedcba9876543210";
+
+    /// 체결통보 필드를 KEY/IV로 암호화한 레코드
+    fn encrypted_record(fields: &[&str]) -> String {
+        let plain = fields.join("^");
+        let mut buf = [0u8; BUF_SIZE];
+        let encrypted =
+            cbc::Encryptor::<aes::Aes256>::new_from_slices(KEY.as_bytes(), IV.as_bytes())
+                .unwrap()
+                .encrypt_padded_b2b_mut::<ZeroPadding>(plain.as_bytes(), &mut buf)
+                .unwrap();
+        format!(
+            "1|H0STCNI0|001|{}",
+            base64::prelude::BASE64_STANDARD.encode(encrypted)
+        )
+    }
+
+    fn fill_fields() -> Vec<&'static str> {
+        vec![
+            "hts_id",
+            "12345678",
+            "0000012345",
+            "0000000000",
+            "02",
+            "0",
+            "00",
+            "0",
+            "005930",
+            "10",
+            "71000",
+            "093000",
+            "0",
+            "2",
+            "2",
+            "00950",
+            "10",
+            "홍길동",
+            "삼성전자",
+            "10",
+            "",
+            "삼성전자",
+            "",
+        ]
+    }
+
+    #[test]
+    fn decrypts_execution_notice() {
+        let exec = MyExec::parse(encrypted_record(&fill_fields()), IV.into(), KEY.into()).unwrap();
+        let notice = exec.execution_notice().unwrap();
+        assert_eq!(notice.order_no, 12345);
+        assert_eq!(notice.shortcode, "005930");
+        assert_eq!(notice.side, Direction::Bid);
+        assert_eq!(notice.filled_qty, 10);
+        assert_eq!(notice.filled_price, 71000);
+        assert_eq!(notice.order_qty, 10);
+        assert!(notice.is_fill);
+        assert!(!notice.is_refused);
+    }
+
+    #[test]
+    fn order_acceptance_is_not_a_fill() {
+        let mut fields = fill_fields();
+        fields[13] = "1";
+        let exec = MyExec::parse(encrypted_record(&fields), IV.into(), KEY.into()).unwrap();
+        assert!(!exec.execution_notice().unwrap().is_fill);
+    }
+
+    #[test]
+    fn cipher_failures_are_decrypt_failed() {
+        let record = encrypted_record(&fill_fields());
+        let wrong_key = "00000000000000000000000000000000".to_string();
+        let short_key = "short".to_string();
+        let invalid_base64 = "1|H0STCNI0|001|not base64!".to_string();
+        for (record, key) in [
+            (record.clone(), wrong_key),
+            (record, short_key),
+            (invalid_base64, KEY.to_string()),
+        ] {
+            let result = MyExec::parse(record, IV.into(), key);
+            assert!(
+                matches!(result, Err(Error::DecryptFailed(_))),
+                "{:?}",
+                result
+            );
+        }
+    }
+}
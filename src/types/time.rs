@@ -1,16 +1,14 @@
+use crate::types::de::{parse_hhmmss, parse_yyyymmdd};
 use crate::Error;
-use chrono::{DateTime, LocalResult};
-use std::time::{SystemTime, UNIX_EPOCH};
+use chrono::{DateTime, Datelike, LocalResult, NaiveDate, NaiveTime, Weekday};
+use serde::{Deserialize, Deserializer};
 
 #[derive(Debug, Clone)]
 pub struct Time(DateTime<chrono_tz::Tz>);
 
 impl Time {
     pub fn now() -> Self {
-        let now = SystemTime::now();
-        let millis = now.duration_since(UNIX_EPOCH).unwrap().as_millis();
-        let naive_dt = chrono::NaiveDateTime::from_timestamp_millis(millis as i64).unwrap();
-        Self(naive_dt.and_local_timezone(chrono_tz::Asia::Seoul).unwrap())
+        Self(chrono::Utc::now().with_timezone(&chrono_tz::Asia::Seoul))
     }
     pub fn parse(s: &str, fmt: &str) -> Result<Self, Error> {
         let naive_dt = chrono::NaiveDateTime::parse_from_str(s, fmt)?;
@@ -21,9 +19,210 @@ impl Time {
         }
     }
     pub fn inner(&self) -> DateTime<chrono_tz::Tz> {
-        self.0.clone()
+        self.0
     }
     pub fn date(&self) -> String {
         format!("{}", self.0.format("%Y%m%d"))
     }
 }
+
+/// 조회 기간(시작일 <= 종료일)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateRange {
+    start: NaiveDate,
+    end: NaiveDate,
+}
+
+impl DateRange {
+    /// 시작일이 종료일보다 늦으면 Error::InvalidDateRange
+    pub fn new(start: NaiveDate, end: NaiveDate) -> Result<Self, Error> {
+        if start > end {
+            return Err(Error::InvalidDateRange("start date is after end date"));
+        }
+        Ok(Self { start, end })
+    }
+
+    pub fn start(&self) -> NaiveDate {
+        self.start
+    }
+
+    pub fn end(&self) -> NaiveDate {
+        self.end
+    }
+
+    /// 시작일(YYYYMMDD)
+    pub fn start_str(&self) -> String {
+        format!("{}", self.start.format("%Y%m%d"))
+    }
+
+    /// 종료일(YYYYMMDD)
+    pub fn end_str(&self) -> String {
+        format!("{}", self.end.format("%Y%m%d"))
+    }
+
+    /// 기간 내 평일 수(공휴일은 고려하지 않음)
+    pub fn weekdays(&self) -> usize {
+        self.start
+            .iter_days()
+            .take_while(|d| *d <= self.end)
+            .filter(|d| !matches!(d.weekday(), Weekday::Sat | Weekday::Sun))
+            .count()
+    }
+
+    /// 기간에 걸친 주 수
+    pub fn weeks(&self) -> usize {
+        (self.end - self.start).num_days() as usize / 7 + 1
+    }
+
+    /// 기간에 걸친 월 수
+    pub fn months(&self) -> usize {
+        ((self.end.year() - self.start.year()) * 12 + self.end.month() as i32
+            - self.start.month() as i32) as usize
+            + 1
+    }
+
+    /// 기간에 걸친 연 수
+    pub fn years(&self) -> usize {
+        (self.end.year() - self.start.year()) as usize + 1
+    }
+}
+
+/// KIS 응답의 일자(YYYYMMDD) 필드
+/// 빈 문자열이나 잘못된 형식은 get()이 None이며, Display는 응답 원문을 그대로 출력
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KisDate {
+    raw: String,
+    date: Option<NaiveDate>,
+}
+
+impl KisDate {
+    pub fn get(&self) -> Option<NaiveDate> {
+        self.date
+    }
+
+    /// 응답 원문
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl From<&str> for KisDate {
+    fn from(s: &str) -> Self {
+        Self {
+            raw: s.to_string(),
+            date: parse_yyyymmdd(s),
+        }
+    }
+}
+
+impl std::fmt::Display for KisDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for KisDate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
+
+/// KIS 응답의 시각(HHMMSS) 필드
+/// 빈 문자열, 잘못된 형식, 값이 없음을 뜻하는 "000000"은 get()이 None이며, Display는 응답 원문을 그대로 출력
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KisTime {
+    raw: String,
+    time: Option<NaiveTime>,
+}
+
+impl KisTime {
+    pub fn get(&self) -> Option<NaiveTime> {
+        self.time
+    }
+
+    /// 응답 원문
+    pub fn as_str(&self) -> &str {
+        &self.raw
+    }
+}
+
+impl From<&str> for KisTime {
+    fn from(s: &str) -> Self {
+        let trimmed = s.trim();
+        let time = match trimmed {
+            "000000" => None,
+            _ => parse_hhmmss(trimmed),
+        };
+        Self {
+            raw: s.to_string(),
+            time,
+        }
+    }
+}
+
+impl std::fmt::Display for KisTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.raw)
+    }
+}
+
+impl<'de> Deserialize<'de> for KisTime {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kis_date_parses_and_keeps_raw() {
+        let date: KisDate = serde_json::from_str(r#""20240115""#).unwrap();
+        assert_eq!(date.get(), NaiveDate::from_ymd_opt(2024, 1, 15));
+        assert_eq!(date.to_string(), "20240115");
+    }
+
+    #[test]
+    fn kis_date_malformed_and_empty() {
+        for raw in [
+            "",
+            " ",
+            "2024011",
+            "202401150",
+            "20241301",
+            "20240230",
+            "2024-01-15",
+            "abcdefgh",
+        ] {
+            let date = KisDate::from(raw);
+            assert_eq!(date.get(), None, "{:?}", raw);
+            assert_eq!(date.as_str(), raw);
+        }
+        let date: KisDate = serde_json::from_str(r#""""#).unwrap();
+        assert_eq!(date.get(), None);
+        assert!(serde_json::from_str::<KisDate>("20240115").is_err());
+    }
+
+    #[test]
+    fn kis_time_parses_and_keeps_raw() {
+        let time: KisTime = serde_json::from_str(r#""153012""#).unwrap();
+        assert_eq!(time.get(), NaiveTime::from_hms_opt(15, 30, 12));
+        assert_eq!(time.to_string(), "153012");
+        assert_eq!(
+            KisTime::from("090000").get(),
+            NaiveTime::from_hms_opt(9, 0, 0)
+        );
+    }
+
+    #[test]
+    fn kis_time_malformed_empty_and_zero() {
+        for raw in [
+            "", "  ", "000000", "15301", "1530123", "246000", "156000", "15:30:12",
+        ] {
+            let time = KisTime::from(raw);
+            assert_eq!(time.get(), None, "{:?}", raw);
+            assert_eq!(time.to_string(), raw);
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! 요청 parameter 검증
+//! 잘못된 값을 그대로 보내면 KIS가 알아보기 어려운 에러를 반환하므로 요청 전에 확인하고 Error::InvalidParam 반환
+
+#[cfg(feature = "decimal")]
+use crate::types::OverseasExchange;
+use crate::Error;
+
+/// 국내 종목 단축코드
+/// 6자리 숫자 또는 영문 대문자(신규 상장 종목은 0009K0처럼 영문 포함). ETN은 앞에 Q를 붙인 7자리
+pub(crate) fn shortcode(field: &'static str, code: &str) -> Result<(), Error> {
+    let code = code.strip_prefix('Q').unwrap_or(code);
+    if code.len() != 6 {
+        return Err(Error::InvalidParam {
+            field,
+            reason: "must be 6 characters(ETN: Q + 6)",
+        });
+    }
+    if !code
+        .chars()
+        .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase())
+    {
+        return Err(Error::InvalidParam {
+            field,
+            reason: "must be digits or uppercase letters",
+        });
+    }
+    Ok(())
+}
+
+/// 주문번호(주문조직번호, 원주문번호). 숫자 1~10자리
+pub(crate) fn order_no(field: &'static str, no: &str) -> Result<(), Error> {
+    if no.is_empty() || no.len() > 10 || !no.chars().all(|c| c.is_ascii_digit()) {
+        return Err(Error::InvalidParam {
+            field,
+            reason: "must be 1 to 10 digits",
+        });
+    }
+    Ok(())
+}
+
+/// 해외 종목 심볼(거래소별 형식)
+/// 미국: 영문 대문자, 숫자, '.', '/', '-' 1~16자리, 홍콩: 숫자 1~5자리, 상해/심천: 숫자 6자리
+/// 도쿄: 숫자 또는 영문 대문자 4자리(예: 7203, 130A)
+#[cfg(feature = "decimal")]
+pub(crate) fn overseas_symbol(
+    field: &'static str,
+    exchange: &OverseasExchange,
+    symbol: &str,
+) -> Result<(), Error> {
+    let all = |f: fn(char) -> bool| symbol.chars().all(f);
+    let (valid, reason) = match exchange {
+        OverseasExchange::Nasdaq | OverseasExchange::Nyse | OverseasExchange::Amex => (
+            (1..=16).contains(&symbol.len())
+                && all(|c| c.is_ascii_uppercase() || c.is_ascii_digit() || "./-".contains(c)),
+            "must be 1-16 uppercase letters, digits, '.', '/' or '-'",
+        ),
+        OverseasExchange::HongKong => (
+            (1..=5).contains(&symbol.len()) && all(|c| c.is_ascii_digit()),
+            "must be 1-5 digits",
+        ),
+        OverseasExchange::Shanghai | OverseasExchange::Shenzhen => (
+            symbol.len() == 6 && all(|c| c.is_ascii_digit()),
+            "must be 6 digits",
+        ),
+        OverseasExchange::Tokyo => (
+            symbol.len() == 4 && all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()),
+            "must be 4 digits or uppercase letters",
+        ),
+    };
+    if !valid {
+        return Err(Error::InvalidParam { field, reason });
+    }
+    Ok(())
+}
+
+/// YYYYMMDD 형식의 날짜
+pub(crate) fn yyyymmdd(field: &'static str, s: &str) -> Result<(), Error> {
+    if s.len() != 8 || crate::types::de::parse_yyyymmdd(s).is_none() {
+        return Err(Error::InvalidParam {
+            field,
+            reason: "must be a valid YYYYMMDD date",
+        });
+    }
+    Ok(())
+}
+
+/// HHMMSS 형식의 시각
+pub(crate) fn hhmmss(field: &'static str, s: &str) -> Result<(), Error> {
+    if s.len() != 6 || crate::types::de::parse_hhmmss(s).is_none() {
+        return Err(Error::InvalidParam {
+            field,
+            reason: "must be a valid HHMMSS time",
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortcode_accepts_stock_and_etn() {
+        for code in ["005930", "0009K0", "Q500001"] {
+            assert!(shortcode("shortcode", code).is_ok(), "{}", code);
+        }
+    }
+
+    #[test]
+    fn shortcode_rejects_malformed() {
+        let cases = [
+            ("", "must be 6 characters(ETN: Q + 6)"),
+            ("00593", "must be 6 characters(ETN: Q + 6)"),
+            ("0059300", "must be 6 characters(ETN: Q + 6)"),
+            ("Q00593", "must be 6 characters(ETN: Q + 6)"),
+            (" 05930", "must be digits or uppercase letters"),
+            ("0009k0", "must be digits or uppercase letters"),
+            ("00593-", "must be digits or uppercase letters"),
+            ("００５９", "must be 6 characters(ETN: Q + 6)"),
+        ];
+        for (code, expected) in cases {
+            match shortcode("shortcode", code) {
+                Err(Error::InvalidParam { field, reason }) => {
+                    assert_eq!(field, "shortcode");
+                    assert_eq!(reason, expected, "{:?}", code);
+                }
+                other => panic!("{:?}: {:?}", code, other),
+            }
+        }
+    }
+
+    #[test]
+    fn order_no_rejects_malformed() {
+        assert!(order_no("orgn_odno", "0000117057").is_ok());
+        assert!(order_no("krx_fwdg_ord_orgno", "06010").is_ok());
+        for no in ["", "00001170570", "117 57", "12a"] {
+            assert!(
+                matches!(order_no("orgn_odno", no), Err(Error::InvalidParam { .. })),
+                "{:?}",
+                no
+            );
+        }
+    }
+}